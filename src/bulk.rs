@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use crate::symbol::Symbol;
+use crate::symbol_parser::ParseError;
+
+// Distinct symbols remembered before the memo starts over, so a long stream of
+// unique symbols cannot grow it without bound
+const MEMO_CAPACITY: usize = 1 << 16;
+
+// Below this many symbols per thread, spawning costs more than it saves
+#[cfg(not(target_arch = "wasm32"))]
+const MIN_SYMBOLS_PER_THREAD: usize = 4096;
+
+#[derive(Default)]
+struct Memo {
+    parsed: HashMap<String, Result<Symbol, ParseError>>,
+}
+
+impl Memo {
+    fn parse(&mut self, raw_symbol: &str) -> Result<Symbol, ParseError> {
+        if let Some(result) = self.parsed.get(raw_symbol) {
+            return result.clone();
+        }
+        if self.parsed.len() >= MEMO_CAPACITY {
+            self.parsed.clear();
+        }
+        let result = Symbol::parse(raw_symbol);
+        self.parsed.insert(raw_symbol.to_owned(), result.clone());
+        result
+    }
+}
+
+// One result per input, in input order. Tick data repeats the same few symbols over
+// and over, so each distinct string is only parsed once.
+pub fn parse_many<I>(symbols: I) -> impl Iterator<Item = Result<Symbol, ParseError>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut memo = Memo::default();
+    symbols.into_iter().map(move |raw_symbol| memo.parse(raw_symbol.as_ref()))
+}
+
+// Same as parse_many, split across the available cores. Results stay in input order.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn parse_many_parallel<S>(symbols: &[S]) -> Vec<Result<Symbol, ParseError>>
+where
+    S: AsRef<str> + Sync,
+{
+    let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+    let threads = cores.min(symbols.len() / MIN_SYMBOLS_PER_THREAD).max(1);
+    if threads == 1 {
+        return parse_many(symbols).collect();
+    }
+
+    let chunk_size = symbols.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = symbols
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || parse_many(chunk).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("symbol parser thread panicked"))
+            .collect()
+    })
+}
@@ -0,0 +1,129 @@
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+use lru::LruCache;
+use serde::Serialize;
+use tsify::Tsify;
+
+use crate::symbol::Symbol;
+use crate::symbol_parser::ParseError;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Tsify)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+impl CacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+struct CacheState {
+    // Failures are cached too, a bad symbol in a feed repeats as often as a good one
+    entries: LruCache<String, Result<Arc<Symbol>, ParseError>>,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl CacheState {
+    fn new(capacity: usize) -> Self {
+        CacheState {
+            entries: LruCache::new(NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)),
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    fn parse(&mut self, raw_symbol: &str) -> Result<Arc<Symbol>, ParseError> {
+        if let Some(result) = self.entries.get(raw_symbol) {
+            self.hits += 1;
+            return result.clone();
+        }
+        self.misses += 1;
+        let result = Symbol::parse(raw_symbol).map(Arc::new);
+        if self.entries.push(raw_symbol.to_owned(), result.clone()).is_some() {
+            self.evictions += 1;
+        }
+        result
+    }
+
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            len: self.entries.len(),
+            capacity: self.entries.cap().get(),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+        self.evictions = 0;
+    }
+}
+
+// Parsed symbols keyed by their input, evicting the least recently used once
+// `capacity` distinct inputs are held. Shared across threads behind a mutex.
+pub struct SymbolCache {
+    state: Mutex<CacheState>,
+}
+
+impl SymbolCache {
+    pub fn new(capacity: usize) -> Self {
+        SymbolCache {
+            state: Mutex::new(CacheState::new(capacity)),
+        }
+    }
+
+    pub fn parse(&self, raw_symbol: &str) -> Result<Arc<Symbol>, ParseError> {
+        self.state.lock().unwrap().parse(raw_symbol)
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.state.lock().unwrap().stats()
+    }
+
+    // Drops every entry and resets the statistics
+    pub fn clear(&self) {
+        self.state.lock().unwrap().clear();
+    }
+}
+
+// Same as SymbolCache without the lock, for single-threaded callers. JS gets it as the
+// SymbolCache class, see js.rs
+pub struct LocalSymbolCache {
+    state: CacheState,
+}
+
+impl LocalSymbolCache {
+    pub fn new(capacity: usize) -> Self {
+        LocalSymbolCache {
+            state: CacheState::new(capacity),
+        }
+    }
+
+    pub fn parse(&mut self, raw_symbol: &str) -> Result<Arc<Symbol>, ParseError> {
+        self.state.parse(raw_symbol)
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.state.stats()
+    }
+
+    pub fn clear(&mut self) {
+        self.state.clear();
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::prelude::*;
+use chrono::Duration;
+
+// US equity and index option exchange calendar (NYSE / Cboe full-day closures).
+// Early closes don't move expirations, so they are not modelled here, and neither
+// are one-off closures such as national days of mourning.
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    let mut date = last_day_of_month(year, month)?;
+    while date.weekday() != weekday {
+        date = date.pred_opt()?;
+    }
+    Some(date)
+}
+
+fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()
+}
+
+// Holidays falling on a Saturday are observed on the Friday before, on a Sunday
+// the Monday after.
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date - Duration::days(1),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+// Anonymous Gregorian algorithm (Meeus/Jones/Butcher)
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+pub fn holidays(year: i32) -> Vec<NaiveDate> {
+    let mut days = Vec::with_capacity(10);
+
+    // New Year's Day on a Saturday is not observed on the prior Friday
+    if let Some(new_year) = NaiveDate::from_ymd_opt(year, 1, 1) {
+        if new_year.weekday() != Weekday::Sat {
+            days.push(observed(new_year));
+        }
+    }
+    days.extend(nth_weekday(year, 1, Weekday::Mon, 3));
+    days.extend(nth_weekday(year, 2, Weekday::Mon, 3));
+    days.extend(easter_sunday(year).map(|easter| easter - Duration::days(2)));
+    days.extend(last_weekday(year, 5, Weekday::Mon));
+    if year >= 2022 {
+        days.extend(NaiveDate::from_ymd_opt(year, 6, 19).map(observed));
+    }
+    days.extend(NaiveDate::from_ymd_opt(year, 7, 4).map(observed));
+    days.extend(nth_weekday(year, 9, Weekday::Mon, 1));
+    days.extend(nth_weekday(year, 11, Weekday::Thu, 4));
+    days.extend(NaiveDate::from_ymd_opt(year, 12, 25).map(observed));
+
+    days
+}
+
+pub fn is_holiday(date: NaiveDate) -> bool {
+    holidays(date.year()).contains(&date)
+}
+
+pub fn is_trading_day(date: NaiveDate) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !is_holiday(date)
+}
+
+// The given date if the market is open on it, otherwise the closest trading day before it
+pub fn roll_back(date: NaiveDate) -> NaiveDate {
+    let mut date = date;
+    while !is_trading_day(date) {
+        match date.pred_opt() {
+            Some(previous) => date = previous,
+            None => break,
+        }
+    }
+    date
+}
+
+pub fn third_friday(year: i32, month: u32) -> Option<NaiveDate> {
+    nth_weekday(year, month, Weekday::Fri, 3)
+}
+
+// Standard monthly expiration: the third Friday, or the trading day before it
+// when the exchange is closed (e.g. Good Friday)
+pub fn monthly_expiration(year: i32, month: u32) -> Option<NaiveDate> {
+    third_friday(year, month).map(roll_back)
+}
+
+// Weekly expiration for the week containing the date: that week's Friday, or the
+// trading day before it when the exchange is closed
+pub fn weekly_expiration(date: NaiveDate) -> NaiveDate {
+    let days_to_friday = Weekday::Fri.num_days_from_monday() as i64 - date.weekday().num_days_from_monday() as i64;
+    roll_back(date + Duration::days(days_to_friday))
+}
+
+pub fn last_trading_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    last_day_of_month(year, month).map(roll_back)
+}
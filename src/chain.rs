@@ -0,0 +1,296 @@
+use std::fmt;
+
+use chrono::prelude::*;
+use chrono::{Days, Duration, Months};
+use rust_decimal::prelude::*;
+use serde::Serialize;
+
+use crate::calendar;
+use crate::format::Symbology;
+use crate::registry::{default_registry, FutureOptionExpiration, FutureProduct};
+use crate::symbol::{ExpirationSeries, SettlementTime, Symbol, SymbolRef, FUTURE_MONTHS};
+
+// Expirations listed up to this many days out unless the builder says otherwise
+pub const DEFAULT_DAYS: i64 = 60;
+
+// Top of the strike range when none is given, above the index and futures levels the
+// registry's roots trade at
+pub const DEFAULT_HIGHEST_STRIKE: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+// Strike spacing by price band: below each band's bound its increment applies, above
+// the last one `above` does
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrikeIncrements {
+    pub bands: Vec<(Decimal, Decimal)>,
+    pub above: Decimal,
+}
+
+impl StrikeIncrements {
+    // Standard equity option strikes: 2.50 below $25, 5 up to $200, 10 above
+    pub fn standard() -> Self {
+        StrikeIncrements {
+            bands: vec![(Decimal::from(25), Decimal::new(25, 1)), (Decimal::from(200), Decimal::from(5))],
+            above: Decimal::TEN,
+        }
+    }
+
+    // The same increment at every price, e.g. $1 strikes on ETFs like SPY
+    pub fn uniform(increment: Decimal) -> Self {
+        StrikeIncrements { bands: Vec::new(), above: increment }
+    }
+
+    pub fn increment(&self, strike: Decimal) -> Decimal {
+        self.bands
+            .iter()
+            .find(|(bound, _)| strike < *bound)
+            .map_or(self.above, |(_, increment)| *increment)
+    }
+
+    // Strike range of a chain built without one: from the lowest listed strike up to
+    // DEFAULT_HIGHEST_STRIKE
+    pub fn window(&self) -> (Decimal, Decimal) {
+        (self.increment(Decimal::ZERO), DEFAULT_HIGHEST_STRIKE)
+    }
+
+    // The listed strikes from low to high in each band, as the first strike, the
+    // increment and how many, so a chain can be sized before any strike is generated.
+    // None when there are too many to count.
+    fn ranges(&self, low: Decimal, high: Decimal) -> Option<Vec<StrikeRange>> {
+        let mut ranges = Vec::new();
+        let mut bottom = Decimal::ZERO;
+        let bands = self.bands.iter().map(|(bound, increment)| (Some(*bound), *increment));
+        for (bound, increment) in bands.chain([(None, self.above)]) {
+            let first = round_up(low.max(bottom), increment)?;
+            // The band's last multiple of its increment below its bound
+            let top = match bound {
+                Some(bound) => high.min(round_up(bound, increment)? - increment),
+                None => high,
+            };
+            let last = round_up(top, increment)?;
+            let last = if last > top { last - increment } else { last };
+            if first <= last {
+                let count = (last - first).checked_div(increment)?.to_usize()?.checked_add(1)?;
+                ranges.push(StrikeRange { first, increment, count });
+            }
+            bottom = bottom.max(bound.unwrap_or(bottom));
+        }
+        Some(ranges)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StrikeRange {
+    first: Decimal,
+    increment: Decimal,
+    count: usize,
+}
+
+impl StrikeRange {
+    fn strikes(self) -> impl Iterator<Item = Decimal> {
+        (0..self.count).map(move |index| (self.first + self.increment * Decimal::from(index)).normalize())
+    }
+}
+
+impl Default for StrikeIncrements {
+    fn default() -> Self {
+        StrikeIncrements::standard()
+    }
+}
+
+fn round_up(value: Decimal, increment: Decimal) -> Option<Decimal> {
+    value.checked_div(increment)?.ceil().checked_mul(increment)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainError {
+    pub root: String,
+    pub message: String,
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not build the option chain for {:?}: {}", self.root, self.message)
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+// The options listed on a root from a date, puts and calls at every strike of every
+// expiration, ordered the way Symbol sorts:
+//
+//   ChainBuilder::new("SPY", date).strikes(500.into(), 700.into()).increments(StrikeIncrements::uniform(1.into()))
+//
+// Equity and index roots list an option on each trading day that is one of the wanted
+// expiration series. Future roots in the registry list options on each listed contract
+// month whose options expire in the window, ESU25 C6000, by the product's
+// FutureOptionExpiration; a future without one has no chain.
+#[derive(Debug, Clone)]
+pub struct ChainBuilder {
+    root: String,
+    from: NaiveDate,
+    // Err when days() was given a window past the last date chrono represents
+    until: Result<NaiveDate, String>,
+    strikes: Option<(Decimal, Decimal)>,
+    increments: StrikeIncrements,
+    series: Option<Vec<ExpirationSeries>>,
+    limit: Option<usize>,
+}
+
+impl ChainBuilder {
+    pub fn new(root: &str, from: NaiveDate) -> Self {
+        ChainBuilder {
+            root: root.to_owned(),
+            from,
+            until: Ok(from.checked_add_days(Days::new(DEFAULT_DAYS as u64)).unwrap_or(NaiveDate::MAX)),
+            strikes: None,
+            increments: StrikeIncrements::standard(),
+            series: None,
+            limit: None,
+        }
+    }
+
+    pub fn days(mut self, days: i64) -> Self {
+        let until = Duration::try_days(days).and_then(|duration| self.from.checked_add_signed(duration));
+        self.until = until.ok_or_else(|| format!("{days} days from {} is out of range", self.from));
+        self
+    }
+
+    // Last expiration date included
+    pub fn until(mut self, until: NaiveDate) -> Self {
+        self.until = Ok(until);
+        self
+    }
+
+    // Strike range, inclusive. StrikeIncrements::window() when not given.
+    pub fn strikes(mut self, low: Decimal, high: Decimal) -> Self {
+        self.strikes = Some((low, high));
+        self
+    }
+
+    pub fn increments(mut self, increments: StrikeIncrements) -> Self {
+        self.increments = increments;
+        self
+    }
+
+    // Expiration series to list. By default AM-settled index roots such as SPX list
+    // only monthlies, other roots every series but dailies.
+    pub fn series(mut self, series: &[ExpirationSeries]) -> Self {
+        self.series = Some(series.to_vec());
+        self
+    }
+
+    // Fails rather than build a chain with more symbols than this, before generating any
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn build(&self) -> Result<Vec<Symbol>, ChainError> {
+        let error = |message: String| ChainError { root: self.root.to_owned(), message };
+        let until = self.until.to_owned().map_err(error)?;
+        let (low, high) = self.strikes.unwrap_or_else(|| self.increments.window());
+        if low.is_sign_negative() || low > high {
+            return Err(error(format!("{low} to {high} is not a strike range")));
+        }
+        let mut increments = self.increments.bands.iter().map(|(_, increment)| increment).chain([&self.increments.above]);
+        if let Some(increment) = increments.find(|increment| **increment <= Decimal::ZERO) {
+            return Err(error(format!("strike increment {increment} is not positive")));
+        }
+
+        let too_many = || error(format!("{low} to {high} has too many strikes"));
+        let ranges = self.increments.ranges(low, high).ok_or_else(too_many)?;
+        let per_contract = ranges
+            .iter()
+            .try_fold(0usize, |count, range| count.checked_add(range.count))
+            .and_then(|count| count.checked_mul(2))
+            .ok_or_else(too_many)?;
+        // Walk the window only until the limit is passed, so over the limit the count is
+        // a lower bound
+        let max_contracts = match self.limit {
+            Some(limit) => limit.checked_div(per_contract).map_or(0, |contracts| contracts + 1),
+            None => usize::MAX,
+        };
+        let future = default_registry().future_product(&self.root).cloned();
+        let contracts = match future {
+            Some(future) => {
+                let Some(expiration) = future.option_expiration else {
+                    return Err(error(format!("the expiration of options on {} futures is not known", self.root)));
+                };
+                self.future_months(&future, expiration, until, max_contracts)
+            }
+            None => self.expirations(until, max_contracts),
+        };
+        let len = contracts.len().saturating_mul(per_contract);
+        if let Some(limit) = self.limit.filter(|limit| len > *limit) {
+            return Err(error(format!("at least {len} symbols is more than the limit of {limit}")));
+        }
+
+        let mut symbols = Vec::with_capacity(self.limit.map_or(0, |_| len));
+        for contract in &contracts {
+            for strike in ranges.iter().flat_map(|range| range.strikes()) {
+                for put_call in ['P', 'C'] {
+                    let raw_symbol = format!("{contract}{put_call}{strike}");
+                    let symbol = SymbolRef::parse(&raw_symbol).map_err(|err| error(err.to_string()))?;
+                    symbols.push(symbol.to_owned());
+                }
+            }
+        }
+        Ok(symbols)
+    }
+
+    // The chain written in the native symbology
+    pub fn canonical_symbols(&self) -> Result<Vec<String>, ChainError> {
+        self.build()?
+            .iter()
+            .map(|symbol| {
+                Symbology::Native.format(symbol).map_err(|err| ChainError {
+                    root: self.root.to_owned(),
+                    message: err.message,
+                })
+            })
+            .collect()
+    }
+
+    // "SPY 250620" for every expiration in the window, ready for the put/call and strike
+    fn expirations(&self, until: NaiveDate, max: usize) -> Vec<String> {
+        let registry = default_registry();
+        let series = self.series.to_owned().unwrap_or_else(|| match registry.option_product(&self.root) {
+            Some(product) if !product.weeklys_root && product.terms.settlement_time == SettlementTime::Am => {
+                vec![ExpirationSeries::Monthly]
+            }
+            _ => vec![
+                ExpirationSeries::Monthly,
+                ExpirationSeries::Weekly,
+                ExpirationSeries::Quarterly,
+                ExpirationSeries::EndOfMonth,
+            ],
+        });
+        self.from
+            .iter_days()
+            .take_while(|date| *date <= until)
+            .filter(|date| calendar::is_trading_day(*date))
+            .filter(|date| series.contains(&registry.classify_expiration(&self.root, *date)))
+            .take(max)
+            .map(|date| format!("{} {}", self.root, date.format("%y%m%d")))
+            .collect()
+    }
+
+    // "ESU25 " for every listed contract month whose options expire in the window
+    fn future_months(&self, future: &FutureProduct, expiration: FutureOptionExpiration, until: NaiveDate, max: usize) -> Vec<String> {
+        let mut months = Vec::new();
+        let mut month = self.from.with_day(1);
+        // Options can expire in the month before their contract month
+        while let Some(first) = month.filter(|first| first.checked_sub_months(Months::new(1)).is_some_and(|before| before <= until)) {
+            let code = FUTURE_MONTHS[first.month0() as usize];
+            let expires = expiration.expiration(first.year(), first.month());
+            if months.len() == max {
+                break;
+            }
+            if future.is_listed_month(code) && expires.is_some_and(|date| self.from <= date && date <= until) {
+                months.push(format!("{}{code}{:02} ", self.root, first.year() % 100));
+            }
+            month = first.checked_add_months(Months::new(1));
+        }
+        months
+    }
+}
@@ -0,0 +1,292 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use clap::{Args, ValueEnum};
+use rust_decimal::Decimal;
+
+use symbols::cache::SymbolCache;
+use symbols::symbol::{PutOrCall, Symbol};
+use symbols::symbol_parser::ParseError;
+
+// Distinct symbols kept parsed while streaming. Bounded, so memory stays flat however
+// large the file is.
+const CACHE_CAPACITY: usize = 1 << 16;
+
+pub const COLUMNS: [&str; 6] = ["root", "type", "expiration", "strike", "put_call", "parse_error"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FileFormat {
+    Csv,
+    #[cfg(feature = "parquet")]
+    Parquet,
+    // Arrow IPC file, also known as Feather v2
+    #[cfg(feature = "parquet")]
+    Arrow,
+}
+
+impl FileFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "csv" => Some(FileFormat::Csv),
+            #[cfg(feature = "parquet")]
+            "parquet" => Some(FileFormat::Parquet),
+            #[cfg(feature = "parquet")]
+            "arrow" | "ipc" | "feather" => Some(FileFormat::Arrow),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct EnrichArgs {
+    /// File to read, stdin when omitted
+    input: Option<PathBuf>,
+    /// File to write, stdout when omitted
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+    /// Column holding the symbols
+    #[arg(long, short, default_value = "symbol")]
+    column: String,
+    /// Prepended to the names of the added columns, which must differ from the input's
+    #[arg(long, default_value = "")]
+    prefix: String,
+    /// Format of the input, from its extension when omitted, and of the output
+    #[arg(long, value_enum)]
+    format: Option<FileFormat>,
+}
+
+// The added columns of one row. Errors leave every column but parse_error empty.
+pub struct Enrichment {
+    pub root: Option<String>,
+    pub symbol_type: Option<String>,
+    pub expiration: Option<chrono::NaiveDate>,
+    pub strike: Option<Decimal>,
+    pub put_call: Option<&'static str>,
+    pub error: Option<String>,
+}
+
+impl Enrichment {
+    pub fn new(result: Result<&Symbol, &ParseError>) -> Self {
+        match result {
+            Ok(symbol) => {
+                let option = symbol.option_contract.as_ref();
+                Enrichment {
+                    root: Some(symbol.root().to_owned()),
+                    symbol_type: Some(format!("{:?}", symbol.symbol_type)),
+                    expiration: symbol.expiration(),
+                    strike: option.map(|option| option.strike_price.normalize()),
+                    put_call: option.map(|option| match option.put_call {
+                        PutOrCall::Put => "P",
+                        PutOrCall::Call => "C",
+                    }),
+                    error: None,
+                }
+            }
+            Err(err) => Enrichment {
+                root: None,
+                symbol_type: None,
+                expiration: None,
+                strike: None,
+                put_call: None,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+
+    fn cells(&self) -> [String; 6] {
+        [
+            self.root.to_owned().unwrap_or_default(),
+            self.symbol_type.to_owned().unwrap_or_default(),
+            self.expiration.map(|date| date.to_string()).unwrap_or_default(),
+            self.strike.map(|strike| strike.to_string()).unwrap_or_default(),
+            self.put_call.unwrap_or_default().to_owned(),
+            self.error.to_owned().unwrap_or_default(),
+        ]
+    }
+}
+
+// The names of the added columns, which must not repeat one of the input's
+pub fn column_names<'a>(prefix: &str, headers: impl IntoIterator<Item = &'a str>) -> io::Result<Vec<String>> {
+    let names: Vec<String> = COLUMNS.iter().map(|column| format!("{prefix}{column}")).collect();
+    match headers.into_iter().find(|header| names.iter().any(|name| name == header)) {
+        Some(header) => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("the input already has a column named {header:?}, use --prefix to rename the added columns"),
+        )),
+        None => Ok(names),
+    }
+}
+
+pub fn missing_column(column: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, format!("no column named {column:?}"))
+}
+
+pub fn enrich(args: EnrichArgs) -> io::Result<()> {
+    let format = match (args.format, &args.input) {
+        (Some(format), _) => format,
+        (None, Some(path)) => FileFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, format!("cannot tell the format of {}, pass --format", path.display()))
+        })?,
+        (None, None) => FileFormat::Csv,
+    };
+    let output: Box<dyn Write + Send> = match &args.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    let cache = SymbolCache::new(CACHE_CAPACITY);
+
+    match format {
+        FileFormat::Csv => {
+            let input: Box<dyn Read> = match &args.input {
+                Some(path) => Box::new(BufReader::new(File::open(path)?)),
+                None => Box::new(io::stdin().lock()),
+            };
+            enrich_csv(input, output, &args.column, &args.prefix, &cache)
+        }
+        #[cfg(feature = "parquet")]
+        FileFormat::Parquet | FileFormat::Arrow => {
+            let path = args.input.as_ref().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "columnar input must be read from a file, not stdin")
+            })?;
+            let columns = columnar::Enricher { column: &args.column, prefix: &args.prefix, cache: &cache };
+            match format {
+                FileFormat::Parquet => columns.parquet(File::open(path)?, output),
+                _ => columns.arrow(File::open(path)?, output),
+            }
+            .map_err(io::Error::other)
+        }
+    }
+}
+
+// Streams one record at a time, so only the current row and the cache are held
+fn enrich_csv(input: impl Read, output: impl Write, column: &str, prefix: &str, cache: &SymbolCache) -> io::Result<()> {
+    let mut reader = csv::Reader::from_reader(input);
+    let headers = reader.headers()?.clone();
+    let index = headers.iter().position(|header| header == column).ok_or_else(|| missing_column(column))?;
+
+    let mut writer = csv::Writer::from_writer(output);
+    let added = column_names(prefix, headers.iter())?;
+    writer.write_record(headers.iter().chain(added.iter().map(String::as_str)))?;
+
+    let mut record = csv::StringRecord::new();
+    while reader.read_record(&mut record)? {
+        let result = cache.parse(&record[index]);
+        let cells = Enrichment::new(result.as_deref()).cells();
+        writer.write_record(record.iter().chain(cells.iter().map(String::as_str)))?;
+    }
+    writer.flush()
+}
+
+#[cfg(feature = "parquet")]
+mod columnar {
+    use std::fs::File;
+    use std::io::Write;
+    use std::sync::Arc;
+
+    use arrow::array::{Array, ArrayRef, Date32Builder, Decimal128Builder, RecordBatch, RecordBatchReader, StringArray, StringBuilder};
+    use arrow::compute::cast;
+    use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+    use arrow::error::ArrowError;
+    use arrow::ipc::reader::FileReader;
+    use arrow::ipc::writer::FileWriter;
+    use chrono::NaiveDate;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::arrow::ArrowWriter;
+
+    use symbols::cache::SymbolCache;
+    use symbols::columnar::{scaled, STRIKE_SCALE};
+
+    use super::{column_names, missing_column, Enrichment};
+
+    const BATCH_SIZE: usize = 8192;
+
+    pub struct Enricher<'a> {
+        pub column: &'a str,
+        pub prefix: &'a str,
+        pub cache: &'a SymbolCache,
+    }
+
+    impl Enricher<'_> {
+        pub fn parquet(&self, input: File, output: impl Write + Send) -> Result<(), ArrowError> {
+            let reader = ParquetRecordBatchReaderBuilder::try_new(input)?.with_batch_size(BATCH_SIZE).build()?;
+            let schema = self.schema(&reader.schema())?;
+            let mut writer = ArrowWriter::try_new(output, schema.clone(), None)?;
+            for batch in reader {
+                writer.write(&self.batch(&schema, batch?)?)?;
+            }
+            writer.close()?;
+            Ok(())
+        }
+
+        pub fn arrow(&self, input: File, output: impl Write) -> Result<(), ArrowError> {
+            let reader = FileReader::try_new(input, None)?;
+            let schema = self.schema(&reader.schema())?;
+            let mut writer = FileWriter::try_new(output, &schema)?;
+            for batch in reader {
+                writer.write(&self.batch(&schema, batch?)?)?;
+            }
+            writer.finish()
+        }
+
+        fn schema(&self, input: &SchemaRef) -> Result<SchemaRef, ArrowError> {
+            input.index_of(self.column).map_err(|_| ArrowError::ExternalError(Box::new(missing_column(self.column))))?;
+            let names = column_names(self.prefix, input.fields().iter().map(|field| field.name().as_str()))
+                .map_err(|err| ArrowError::ExternalError(Box::new(err)))?;
+            let types = [
+                DataType::Utf8,
+                DataType::Utf8,
+                DataType::Date32,
+                DataType::Decimal128(38, STRIKE_SCALE),
+                DataType::Utf8,
+                DataType::Utf8,
+            ];
+            let mut fields: Vec<Field> = input.fields().iter().map(|field| field.as_ref().clone()).collect();
+            fields.extend(names.into_iter().zip(types).map(|(name, data_type)| Field::new(name, data_type, true)));
+            Ok(Arc::new(Schema::new_with_metadata(fields, input.metadata().clone())))
+        }
+
+        fn batch(&self, schema: &SchemaRef, batch: RecordBatch) -> Result<RecordBatch, ArrowError> {
+            let symbols = cast(batch.column_by_name(self.column).expect("column checked against the schema"), &DataType::Utf8)?;
+            let symbols = symbols.as_any().downcast_ref::<StringArray>().expect("cast to Utf8");
+
+            let rows = symbols.len();
+            let mut root = StringBuilder::with_capacity(rows, rows * 4);
+            let mut symbol_type = StringBuilder::with_capacity(rows, rows * 8);
+            let mut expiration = Date32Builder::with_capacity(rows);
+            let mut strike = Decimal128Builder::with_capacity(rows).with_precision_and_scale(38, STRIKE_SCALE)?;
+            let mut put_call = StringBuilder::with_capacity(rows, rows);
+            let mut error = StringBuilder::new();
+
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            for raw_symbol in symbols {
+                let Some(raw_symbol) = raw_symbol else {
+                    root.append_null();
+                    symbol_type.append_null();
+                    expiration.append_null();
+                    strike.append_null();
+                    put_call.append_null();
+                    error.append_null();
+                    continue;
+                };
+                let result = self.cache.parse(raw_symbol);
+                let row = Enrichment::new(result.as_deref());
+                root.append_option(row.root);
+                symbol_type.append_option(row.symbol_type);
+                expiration.append_option(row.expiration.map(|date| (date - epoch).num_days() as i32));
+                strike.append_option(row.strike.map(scaled));
+                put_call.append_option(row.put_call);
+                error.append_option(row.error);
+            }
+
+            let mut columns: Vec<ArrayRef> = batch.columns().to_vec();
+            columns.push(Arc::new(root.finish()));
+            columns.push(Arc::new(symbol_type.finish()));
+            columns.push(Arc::new(expiration.finish()));
+            columns.push(Arc::new(strike.finish()));
+            columns.push(Arc::new(put_call.finish()));
+            columns.push(Arc::new(error.finish()));
+            RecordBatch::try_new(schema.clone(), columns)
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use serde::Serialize;
+
+use symbols::symbol_parser::{parse_symbol, FutureSymbol, ParseResult};
+
+use super::output::Row;
+
+// One token of a parsed symbol, or the error when it did not parse. Offsets are in
+// characters, like ParseError positions.
+#[derive(Debug, Serialize)]
+pub struct ExplainRow {
+    pub input: String,
+    pub token: Option<&'static str>,
+    pub text: Option<String>,
+    pub start: Option<usize>,
+    pub end: Option<usize>,
+    pub error: Option<String>,
+}
+
+impl Row for ExplainRow {
+    fn columns() -> &'static [&'static str] {
+        &["input", "token", "text", "start", "end", "error"]
+    }
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.input.to_owned(),
+            self.token.unwrap_or_default().to_owned(),
+            self.text.to_owned().unwrap_or_default(),
+            self.start.map(|start| start.to_string()).unwrap_or_default(),
+            self.end.map(|end| end.to_string()).unwrap_or_default(),
+            self.error.to_owned().unwrap_or_default(),
+        ]
+    }
+}
+
+struct Tokens<'a> {
+    input: &'a str,
+    rows: Vec<ExplainRow>,
+}
+
+impl<'a> Tokens<'a> {
+    // Every token the parser returns is a slice of the input, so its position is
+    // its offset from the start of the input
+    fn offset(&self, token: &str) -> usize {
+        token.as_ptr() as usize - self.input.as_ptr() as usize
+    }
+
+    fn push_at(&mut self, kind: &'static str, byte_start: usize, text: &str) {
+        let start = self.input[..byte_start].chars().count();
+        self.rows.push(ExplainRow {
+            input: self.input.to_owned(),
+            token: Some(kind),
+            text: Some(text.to_owned()),
+            start: Some(start),
+            end: Some(start + text.chars().count()),
+            error: None,
+        });
+    }
+
+    fn push(&mut self, kind: &'static str, token: Option<&'a str>) {
+        if let Some(token) = token {
+            self.push_at(kind, self.offset(token), token);
+        }
+    }
+
+    // The put/call marker is not kept as a slice; it is whatever sits between the
+    // preceding token and the strike
+    fn push_put_call(&mut self, after: &str, strike: &str) {
+        let start = self.offset(after) + after.len();
+        let gap = &self.input[start..self.offset(strike)];
+        let leading = gap.len() - gap.trim_start().len();
+        self.push_at("put_call", start + leading, gap.trim());
+    }
+
+    fn push_future(&mut self, future: &FutureSymbol<&'a str>) {
+        if future.continuous {
+            self.push("continuous", future.original_symbol.get(..1));
+        }
+        self.push("root", Some(future.root_symbol));
+        self.push("month", future.month);
+        self.push("year", future.year);
+        self.push("modifier", future.symbol_modifier);
+    }
+}
+
+pub fn explain(input: &str) -> Vec<ExplainRow> {
+    let parsed = match parse_symbol(input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            return vec![ExplainRow {
+                input: input.to_owned(),
+                token: None,
+                text: None,
+                start: err.position,
+                end: None,
+                error: Some(err.to_string()),
+            }]
+        }
+    };
+
+    let mut tokens = Tokens { input, rows: Vec::new() };
+    match parsed {
+        ParseResult::UnknownSym(symbol) => {
+            tokens.push("root", Some(symbol.root_symbol));
+            tokens.push("modifier", symbol.symbol_modifier);
+        }
+        ParseResult::FutureSym(future) => tokens.push_future(&future),
+        ParseResult::StockOptionsSym(option) => {
+            tokens.push("root", Some(option.root_symbol));
+            tokens.push("date", Some(option.date));
+            tokens.push_put_call(option.date, option.strike_price);
+            tokens.push("strike", Some(option.strike_price));
+        }
+        ParseResult::FutureOptionsSym(option) => {
+            tokens.push_future(&option.future_symbol);
+            tokens.push("modifier", option.symbol_modifier);
+            let before = option.symbol_modifier.unwrap_or(option.future_symbol.original_symbol);
+            tokens.push_put_call(before, option.strike_code);
+            tokens.push("strike", Some(option.strike_code));
+        }
+        ParseResult::Unused => {}
+    }
+    tokens.rows
+}
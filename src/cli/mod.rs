@@ -0,0 +1,300 @@
+mod enrich;
+mod explain;
+mod output;
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use clap::{Args, Parser, Subcommand};
+use serde::Serialize;
+
+use symbols::bulk::parse_many;
+use symbols::format::{translate, SymbolRecord, Symbology};
+use symbols::normalize::{normalize, Normalization, Normalized};
+use symbols::registry::default_registry;
+use symbols::server::{self, Service};
+use symbols::suggest::suggest;
+use symbols::symbol::{Symbol, SymbolType};
+
+use explain::{explain, ExplainRow};
+use output::{write_rows, OutputFormat, Row};
+
+#[derive(Parser)]
+#[command(name = "symbols", version, about = "Parse, validate and translate financial instrument symbols")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Parse symbols into their fields
+    Parse {
+        #[command(flatten)]
+        input: Input,
+        /// Clean up case, whitespace and full-width characters before parsing, listing the
+        /// changes made in a normalizations column
+        #[arg(long)]
+        lenient: bool,
+    },
+    /// Rewrite symbols from one symbology into another
+    Translate {
+        #[command(flatten)]
+        input: Input,
+        #[arg(long, default_value = "native", value_parser = parse_symbology)]
+        from: Symbology,
+        #[arg(long, value_parser = parse_symbology)]
+        to: Symbology,
+    },
+    /// Check symbols, exiting with status 1 when any of them fail. Unknown symbols that
+    /// look like a mistyped one and futures in months their root does not list fail too.
+    Validate {
+        #[command(flatten)]
+        input: Input,
+        /// Fail every symbol that only parses as an unknown symbol
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Add parsed columns to every row of a CSV, Parquet or Arrow file
+    Enrich(enrich::EnrichArgs),
+    /// Serve parsing over HTTP on a local address
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        addr: String,
+    },
+    /// Show how each symbol breaks down into tokens
+    Explain {
+        #[command(flatten)]
+        input: Input,
+    },
+}
+
+#[derive(Args)]
+struct Input {
+    /// Symbols to read, one per line from stdin when none are given
+    symbols: Vec<String>,
+    #[arg(long, short, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+}
+
+impl Input {
+    fn symbols(&self) -> io::Result<Vec<String>> {
+        if !self.symbols.is_empty() {
+            return Ok(self.symbols.to_owned());
+        }
+        let mut symbols = Vec::new();
+        for line in io::stdin().lock().lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                symbols.push(line.trim_end_matches('\r').to_owned());
+            }
+        }
+        Ok(symbols)
+    }
+}
+
+fn parse_symbology(name: &str) -> Result<Symbology, String> {
+    let names: Vec<&str> = Symbology::ALL.iter().map(Symbology::name).collect();
+    name.parse().map_err(|err| format!("{err}, expected one of {}", names.join(", ")))
+}
+
+// A row of parse --lenient: the record of the input, which keeps what was typed, and
+// the rewrites made to it before parsing
+#[derive(Debug, Serialize)]
+struct LenientRow {
+    #[serde(flatten)]
+    record: SymbolRecord,
+    normalizations: Vec<Normalization>,
+}
+
+const LENIENT_COLUMNS: [&str; SymbolRecord::COLUMNS.len() + 1] = {
+    let mut columns = [""; SymbolRecord::COLUMNS.len() + 1];
+    let mut index = 0;
+    while index < SymbolRecord::COLUMNS.len() {
+        columns[index] = SymbolRecord::COLUMNS[index];
+        index += 1;
+    }
+    columns[index] = "normalizations";
+    columns
+};
+
+impl Row for LenientRow {
+    fn columns() -> &'static [&'static str] {
+        &LENIENT_COLUMNS
+    }
+
+    fn cells(&self) -> Vec<String> {
+        let mut cells = self.record.cells();
+        let normalizations: Vec<String> = self.normalizations.iter().map(|normalization| format!("{normalization:?}")).collect();
+        cells.push(normalizations.join(" "));
+        cells
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TranslateRow {
+    input: String,
+    output: Option<String>,
+    error: Option<String>,
+}
+
+impl Row for TranslateRow {
+    fn columns() -> &'static [&'static str] {
+        &["input", "output", "error"]
+    }
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.input.to_owned(),
+            self.output.to_owned().unwrap_or_default(),
+            self.error.to_owned().unwrap_or_default(),
+        ]
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ValidateRow {
+    input: String,
+    valid: bool,
+    symbol_type: Option<SymbolType>,
+    position: Option<usize>,
+    error: Option<String>,
+    suggestion: Option<String>,
+}
+
+impl Row for ValidateRow {
+    fn columns() -> &'static [&'static str] {
+        &["input", "valid", "symbol_type", "position", "error", "suggestion"]
+    }
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.input.to_owned(),
+            self.valid.to_string(),
+            self.symbol_type.map(|symbol_type| format!("{symbol_type:?}")).unwrap_or_default(),
+            self.position.map(|position| position.to_string()).unwrap_or_default(),
+            self.error.to_owned().unwrap_or_default(),
+            self.suggestion.to_owned().unwrap_or_default(),
+        ]
+    }
+}
+
+fn validate(input: String, strict: bool) -> ValidateRow {
+    let registry = default_registry();
+    let suggestion = || suggest(&input, Some(&registry)).into_iter().next().map(|suggestion| suggestion.symbol);
+    let (symbol_type, err, suggestion) = match Symbol::parse(&input) {
+        Ok(symbol) if symbol.symbol_type == SymbolType::Unknown => {
+            // Anything is an unknown symbol, so one that is close to a valid symbol is
+            // more likely a typo than a ticker
+            let suggestion = suggestion();
+            let err = (strict || suggestion.is_some()).then(|| (None, "not a recognized symbol".to_owned()));
+            (Some(symbol.symbol_type), err, suggestion)
+        }
+        Ok(symbol) => {
+            let future = symbol.future_contract.as_ref();
+            let unlisted = future.and_then(|future| future.month.as_deref()).filter(|month| {
+                registry.future_product(&symbol.underlying_symbol).is_some_and(|product| !product.is_listed_month(month))
+            });
+            match unlisted {
+                Some(month) => {
+                    let err = (None, format!("{} does not list {month} contracts", symbol.underlying_symbol));
+                    (Some(symbol.symbol_type), Some(err), suggestion())
+                }
+                None => (Some(symbol.symbol_type), None, None),
+            }
+        }
+        Err(err) => (None, Some((err.position, err.message)), suggestion()),
+    };
+    let (position, error) = err.unzip();
+    ValidateRow {
+        input,
+        valid: error.is_none(),
+        symbol_type,
+        position: position.flatten(),
+        error,
+        suggestion,
+    }
+}
+
+pub fn run() -> ExitCode {
+    let cli = Cli::parse();
+    match execute(cli.command) {
+        Ok(code) => code,
+        // Closed pipes (symbols ... | head) are not worth reporting
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("symbols: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn execute(command: Command) -> io::Result<ExitCode> {
+    let mut out = io::stdout().lock();
+    match command {
+        Command::Parse { input, lenient: false } => {
+            let symbols = input.symbols()?;
+            let rows: Vec<SymbolRecord> = symbols
+                .iter()
+                .zip(parse_many(&symbols))
+                .map(|(symbol, result)| SymbolRecord::new(symbol, &result))
+                .collect();
+            write_rows(&mut out, input.format, &rows)?;
+        }
+        Command::Parse { input, lenient: true } => {
+            let symbols = input.symbols()?;
+            let normalized: Vec<Normalized> = symbols.iter().map(|symbol| normalize(symbol)).collect();
+            let rows: Vec<LenientRow> = symbols
+                .iter()
+                .zip(&normalized)
+                .zip(parse_many(normalized.iter().map(|normalized| &normalized.symbol)))
+                .map(|((symbol, normalized), result)| LenientRow {
+                    record: SymbolRecord::new(symbol, &result),
+                    normalizations: normalized.applied.to_owned(),
+                })
+                .collect();
+            write_rows(&mut out, input.format, &rows)?;
+        }
+        Command::Translate { input, from, to } => {
+            let rows: Vec<TranslateRow> = input
+                .symbols()?
+                .into_iter()
+                .map(|symbol| {
+                    let result = translate(&symbol, from, to);
+                    TranslateRow {
+                        input: symbol,
+                        error: result.as_ref().err().map(|err| err.to_string()),
+                        output: result.ok(),
+                    }
+                })
+                .collect();
+            write_rows(&mut out, input.format, &rows)?;
+        }
+        Command::Validate { input, strict } => {
+            let rows: Vec<ValidateRow> = input.symbols()?.into_iter().map(|symbol| validate(symbol, strict)).collect();
+            write_rows(&mut out, input.format, &rows)?;
+            out.flush()?;
+            if rows.iter().any(|row| !row.valid) {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Enrich(args) => {
+            drop(out);
+            enrich::enrich(args)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        Command::Serve { addr } => {
+            drop(out);
+            eprintln!("symbols: listening on http://{addr}");
+            server::serve(&addr, Arc::new(Service::new()))?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        Command::Explain { input } => {
+            let rows: Vec<ExplainRow> = input.symbols()?.iter().flat_map(|symbol| explain(symbol)).collect();
+            write_rows(&mut out, input.format, &rows)?;
+        }
+    }
+    out.flush()?;
+    Ok(ExitCode::SUCCESS)
+}
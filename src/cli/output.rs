@@ -0,0 +1,78 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use symbols::format::SymbolRecord;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Ndjson,
+    Csv,
+    Table,
+}
+
+// A row the tabular formats can lay out as cells under fixed columns
+pub trait Row: Serialize {
+    fn columns() -> &'static [&'static str];
+    fn cells(&self) -> Vec<String>;
+}
+
+impl Row for SymbolRecord {
+    fn columns() -> &'static [&'static str] {
+        &SymbolRecord::COLUMNS
+    }
+
+    fn cells(&self) -> Vec<String> {
+        SymbolRecord::cells(self)
+    }
+}
+
+pub fn write_rows<R: Row>(out: &mut impl Write, format: OutputFormat, rows: &[R]) -> io::Result<()> {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, rows)?;
+            writeln!(out)
+        }
+        OutputFormat::Ndjson => {
+            for row in rows {
+                serde_json::to_writer(&mut *out, row)?;
+                writeln!(out)?;
+            }
+            Ok(())
+        }
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            writer.write_record(R::columns())?;
+            for row in rows {
+                writer.write_record(row.cells())?;
+            }
+            writer.flush()
+        }
+        OutputFormat::Table => write_table(out, rows),
+    }
+}
+
+fn write_table<R: Row>(out: &mut impl Write, rows: &[R]) -> io::Result<()> {
+    let cells: Vec<Vec<String>> = rows.iter().map(Row::cells).collect();
+    let mut widths: Vec<usize> = R::columns().iter().map(|column| column.chars().count()).collect();
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |out: &mut dyn Write, row: &[String]| -> io::Result<()> {
+        let padded: Vec<String> = row.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect();
+        writeln!(out, "{}", padded.join("  ").trim_end())
+    };
+    let header: Vec<String> = R::columns().iter().map(|column| column.to_string()).collect();
+    line(out, &header)?;
+    let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    line(out, &rule)?;
+    for row in &cells {
+        line(out, row)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, Date32Builder, Decimal128Builder, StringArray, StringBuilder, StructArray};
+use arrow::buffer::NullBuffer;
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Fields};
+use arrow::error::ArrowError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::registry::default_registry;
+use crate::symbol::{PutOrCall, SymbolRef};
+
+// Strikes are quoted to a tenth of a cent at most, as in OCC symbols
+pub const STRIKE_SCALE: i8 = 3;
+
+// Fields of the struct parse_array returns
+pub fn fields() -> Fields {
+    Fields::from(vec![
+        Field::new("symbol_type", DataType::Utf8, true),
+        Field::new("underlying", DataType::Utf8, true),
+        Field::new("expiry", DataType::Date32, true),
+        Field::new("strike", DataType::Decimal128(38, STRIKE_SCALE), true),
+        Field::new("put_call", DataType::Utf8, true),
+    ])
+}
+
+// One struct per symbol, null where the symbol is null or does not parse. Strikes and
+// put/call are null for futures and stocks, expiry for stocks. The underlying is
+// resolved through the default registry as Symbol::parse does, SPX for SPXW.
+pub fn parse_array(symbols: &StringArray) -> StructArray {
+    let rows = symbols.len();
+    let mut symbol_type = StringBuilder::with_capacity(rows, rows * 8);
+    let mut underlying = StringBuilder::with_capacity(rows, rows * 4);
+    let mut expiry = Date32Builder::with_capacity(rows);
+    let mut strike = Decimal128Builder::with_capacity(rows)
+        .with_precision_and_scale(38, STRIKE_SCALE)
+        .expect("38 digits fit Decimal128");
+    let mut put_call = StringBuilder::with_capacity(rows, rows);
+    let mut valid = Vec::with_capacity(rows);
+
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let registry = default_registry();
+    for raw_symbol in symbols {
+        let Some(symbol) = raw_symbol.and_then(|raw_symbol| SymbolRef::parse(raw_symbol).ok()) else {
+            symbol_type.append_null();
+            underlying.append_null();
+            expiry.append_null();
+            strike.append_null();
+            put_call.append_null();
+            valid.push(false);
+            continue;
+        };
+        let option = symbol.option_contract;
+        let expiration = option
+            .and_then(|option| option.date)
+            .or_else(|| symbol.future_contract.and_then(|future| future.expiration));
+        symbol_type.append_value(format!("{:?}", symbol.symbol_type));
+        underlying.append_value(symbol.resolved_underlying(&registry));
+        expiry.append_option(expiration.map(|date| (date - epoch).num_days() as i32));
+        strike.append_option(option.map(|option| scaled(option.strike_price)));
+        put_call.append_option(option.map(|option| match option.put_call {
+            PutOrCall::Put => "P",
+            PutOrCall::Call => "C",
+        }));
+        valid.push(true);
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(symbol_type.finish()),
+        Arc::new(underlying.finish()),
+        Arc::new(expiry.finish()),
+        Arc::new(strike.finish()),
+        Arc::new(put_call.finish()),
+    ];
+    StructArray::new(fields(), columns, Some(NullBuffer::from(valid)))
+}
+
+// parse_array for any string column, Utf8View and LargeUtf8 included
+pub fn parse_column(symbols: &dyn Array) -> Result<StructArray, ArrowError> {
+    let symbols = cast(symbols, &DataType::Utf8)?;
+    Ok(parse_array(symbols.as_any().downcast_ref::<StringArray>().expect("cast to Utf8")))
+}
+
+pub fn scaled(strike: Decimal) -> i128 {
+    let mut strike = strike.round_dp(STRIKE_SCALE as u32);
+    strike.rescale(STRIKE_SCALE as u32);
+    strike.mantissa()
+}
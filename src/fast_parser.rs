@@ -0,0 +1,400 @@
+use crate::symbol_parser::{
+    FutureOptionsSymbol, FutureSymbol, ParseError, ParseResult, ParseResultRef, PutCall, StockOptionsSymbol,
+    UnknownSymbol,
+};
+
+// Hand-written equivalent of symbols.pest that borrows every token from the input.
+//
+// The grammar runs over the reversed symbol, because the fixed-shape tail (strike,
+// put/call, date, month and year) decides how the free-form root is read. This parser
+// reads the symbol once from the front instead. It splits it into words of letters,
+// digits and spaces between the other characters, and keeps the last few words along
+// with the runs of digits or letters each word ends in. Every rule of the grammar starts
+// at the end of a word or inside one of those runs, so the rules are matched against
+// that summary instead of the input. A position the summary doesn't keep is found by
+// walking forward from the start of its run.
+//
+// Each function mirrors the rule of the same name, including pest's implicit whitespace:
+// spaces can separate any two characters except those of a literal like CALL, and a
+// repetition of a single item keeps the spaces pest skips after it, which are the
+// spaces before it here. Errors are reported where pest reports them, at the leftmost
+// character a named rule failed to match at.
+
+// An option's modifier, its future's modifier and the future itself, plus the word
+// before a continuous future's @
+const WORDS: usize = 4;
+// A strike, put/call, year and month
+const RUNS: usize = 4;
+
+// Consecutive digits, or consecutive letters, of a word
+#[derive(Debug, Clone, Copy, Default)]
+struct Run {
+    digits: bool,
+    // Index of the run's first character among the word's letters and digits
+    start: usize,
+    len: usize,
+    // Byte offsets of its first, second to last and last characters
+    first: usize,
+    penultimate: usize,
+    last: usize,
+}
+
+// The letters, digits and spaces between two other characters
+#[derive(Debug, Clone, Copy, Default)]
+struct Word {
+    // Byte offset after the separator before the word
+    start: usize,
+    // Offset and first byte of that separator, None for the first word
+    separator: Option<(usize, u8)>,
+    alphanumerics: usize,
+    // The last runs of the word, the last first
+    runs: [Run; RUNS],
+}
+
+impl Word {
+    fn push(&mut self, at: usize, digits: bool) {
+        let run = &mut self.runs[0];
+        if self.alphanumerics > 0 && run.digits == digits {
+            run.penultimate = run.last;
+            run.last = at;
+            run.len += 1;
+        } else {
+            if self.alphanumerics > 0 {
+                self.runs.copy_within(0..RUNS - 1, 1);
+            }
+            self.runs[0] = Run { digits, start: self.alphanumerics, len: 1, first: at, penultimate: at, last: at };
+        }
+        self.alphanumerics += 1;
+    }
+}
+
+// Where a rule starts reading: the first `len` letters and digits of a word, everything
+// after them having been matched already
+#[derive(Debug, Clone, Copy)]
+struct Region {
+    word: usize,
+    len: usize,
+}
+
+impl Region {
+    fn take(self, count: usize) -> Region {
+        Region { word: self.word, len: self.len - count }
+    }
+
+    // Whether a rule starting here reads further, which for pest is further along the
+    // reversed symbol
+    fn is_further_than(self, other: Region) -> bool {
+        self.word > other.word || (self.word == other.word && self.len < other.len)
+    }
+}
+
+struct Scanner<'a> {
+    input: &'a str,
+    // The last words, the last first
+    words: [Word; WORDS],
+    // Where the furthest failed rule started, reported as the failure position
+    furthest: Option<Region>,
+}
+
+type Span = (usize, usize);
+
+impl<'a> Scanner<'a> {
+    fn new(input: &'a str) -> Self {
+        let mut words = [Word::default(); WORDS];
+        for (at, byte) in input.bytes().enumerate() {
+            if byte.is_ascii_alphanumeric() {
+                words[0].push(at, byte.is_ascii_digit());
+            } else if byte & 0xC0 == 0x80 {
+                // The rest of a separator outside ASCII
+                words[0].start = at + 1;
+            } else if byte != b' ' {
+                words.copy_within(0..WORDS - 1, 1);
+                words[0] = Word { start: at + 1, separator: Some((at, byte)), ..Word::default() };
+            }
+        }
+        Scanner { input, words, furthest: None }
+    }
+
+    fn fail<T>(&mut self, at: Region) -> Option<T> {
+        if self.furthest.is_none_or(|furthest| at.is_further_than(furthest)) {
+            self.furthest = Some(at);
+        }
+        None
+    }
+
+    fn whole(&self, word: usize) -> Region {
+        Region { word, len: self.words[word].alphanumerics }
+    }
+
+    // Byte offset of the word's letter or digit `index`
+    fn position(&self, word: usize, index: usize) -> usize {
+        let word = &self.words[word];
+        match word.runs.iter().find(|run| run.len > 0 && run.start <= index) {
+            Some(run) if index + 1 == run.start + run.len => run.last,
+            Some(run) if index + 2 == run.start + run.len => run.penultimate,
+            Some(run) => self.walk(run.first, index - run.start),
+            None => self.walk(word.start, index),
+        }
+    }
+
+    // Offset of the nth letter or digit from `from`
+    fn walk(&self, from: usize, nth: usize) -> usize {
+        let bytes = &self.input.as_bytes()[from..];
+        let found = bytes.iter().enumerate().filter(|(_, byte)| byte.is_ascii_alphanumeric()).nth(nth);
+        found.map_or(self.input.len(), |(offset, _)| from + offset)
+    }
+
+    // Where a rule starting here begins: after its last character, or after the
+    // separator before the word when nothing is left of it
+    fn end_of(&self, at: Region) -> usize {
+        match at.len.checked_sub(1) {
+            Some(last) => self.position(at.word, last) + 1,
+            None => self.words[at.word].start,
+        }
+    }
+
+    fn last_byte(&self, at: Region) -> Option<u8> {
+        at.len.checked_sub(1).map(|last| self.input.as_bytes()[self.position(at.word, last)])
+    }
+
+    // How many digits, or letters, the region ends in. Rules only ask this of regions
+    // that end in the runs a word keeps.
+    fn trailing(&self, at: Region, digits: bool) -> usize {
+        let runs = &self.words[at.word].runs;
+        match runs.iter().find(|run| run.len > 0 && run.start < at.len) {
+            Some(run) if run.digits == digits => at.len - run.start,
+            _ => 0,
+        }
+    }
+
+    fn slice(&self, (start, end): Span) -> &'a str {
+        &self.input[start..end]
+    }
+
+    // The last `count` characters of the region. pest expands e+ to e ~ e* and e{1,2}
+    // to e ~ e?, so after a single item the spaces it skips stay in the span.
+    fn repetition(&self, at: Region, count: usize) -> Span {
+        let rest = at.take(count);
+        let start = if count == 1 { self.end_of(rest) } else { self.position(at.word, rest.len) };
+        (start, self.end_of(at))
+    }
+
+    fn root_sym(&mut self, at: Region) -> Option<(Region, Span)> {
+        if at.len == 0 {
+            return self.fail(at);
+        }
+        Some((at.take(at.len), self.repetition(at, at.len)))
+    }
+
+    // The rest of the word and the separator before it
+    fn symbol_modifier(&mut self, at: Region) -> Option<(Region, Span)> {
+        match self.words[at.word].separator {
+            Some((separator, b'.' | b':')) if at.len > 0 => Some((self.whole(at.word + 1), (separator, self.end_of(at)))),
+            _ => self.fail(at),
+        }
+    }
+
+    // The @ before a word whose letters and digits have all been matched
+    fn continuous_modifier(&mut self, at: Region) -> Option<(Region, usize)> {
+        match self.words[at.word].separator {
+            Some((at_sign, b'@')) if at.len == 0 => Some((self.whole(at.word + 1), at_sign)),
+            _ => self.fail(at),
+        }
+    }
+
+    fn future_month(&mut self, at: Region) -> Option<(Region, Span)> {
+        match self.last_byte(at) {
+            Some(month) if b"FGHJKMNQUVXZ".contains(&month) => {
+                let end = self.end_of(at);
+                Some((at.take(1), (end - 1, end)))
+            }
+            _ => self.fail(at),
+        }
+    }
+
+    // ASCII_DIGIT{1, 2}
+    fn year(&mut self, at: Region) -> Option<(Region, Span)> {
+        let digits = self.trailing(at, true).min(2);
+        if digits == 0 {
+            return self.fail(at);
+        }
+        Some((at.take(digits), self.repetition(at, digits)))
+    }
+
+    // put = "P" | "PUT", call = "C" | "LLAC", so a lone P always wins over PUT and CALL
+    // can't have spaces in it
+    fn put_call(&mut self, at: Region) -> Option<(Region, PutCall)> {
+        let end = self.end_of(at);
+        match self.last_byte(at) {
+            Some(b'P') => Some((at.take(1), PutCall::Put)),
+            Some(b'C') => Some((at.take(1), PutCall::Call)),
+            Some(b'L') if self.input.as_bytes()[..end].ends_with(b"CALL") => Some((at.take(4), PutCall::Call)),
+            _ => self.fail(at),
+        }
+    }
+
+    fn strike_code(&mut self, at: Region) -> Option<(Region, Span)> {
+        let digits = self.trailing(at, true);
+        if digits == 0 {
+            return self.fail(at);
+        }
+        Some((at.take(digits), self.repetition(at, digits)))
+    }
+
+    // strike_price_with_decimal | ASCII_DIGIT+, where strike_price_with_decimal is
+    // ASCII_DIGIT* ~ "."? ~ ASCII_DIGIT+. The digits after the point are greedy, so it
+    // only matches with the point.
+    fn strike_price(&mut self, at: Region) -> Option<(Region, Span)> {
+        let end = self.end_of(at);
+        let digits = self.trailing(at, true);
+        if digits == at.len && matches!(self.words[at.word].separator, Some((_, b'.'))) {
+            let integer = self.whole(at.word + 1);
+            let integer_digits = self.trailing(integer, true);
+            if integer_digits > 0 {
+                let (start, _) = self.repetition(integer, integer_digits);
+                return Some((integer.take(integer_digits), (start, end)));
+            }
+        }
+        if digits == 0 {
+            return self.fail(at);
+        }
+        Some((at.take(digits), self.repetition(at, digits)))
+    }
+
+    // ASCII_DIGIT{6}
+    fn date(&mut self, at: Region) -> Option<(Region, Span)> {
+        if self.trailing(at, true) < 6 {
+            return self.fail(at);
+        }
+        let rest = at.take(6);
+        Some((rest, (self.position(at.word, rest.len), self.end_of(at))))
+    }
+
+    // year ~ future_month ~ root_sym, returning the root, month and year spans
+    fn individual_contract(&mut self, at: Region) -> Option<(Region, Span, Span, Span)> {
+        let (month_at, year) = self.year(at)?;
+        let (root_at, month) = self.future_month(month_at)?;
+        let (rest, root) = self.root_sym(root_at)?;
+        Some((rest, root, month, year))
+    }
+
+    // symbol_modifier? ~ ((individual_contract ~ continuous_modifier) | individual_contract | (root_sym ~ continuous_modifier)),
+    // also returning where the future starts
+    fn future_symbol(&mut self, at: Region) -> Option<(Region, usize, FutureSymbol<&'a str>)> {
+        let end = self.end_of(at);
+        let (contract_at, modifier) = match self.symbol_modifier(at) {
+            Some((rest, span)) => (rest, Some(span)),
+            None => (at, None),
+        };
+        let symbol_modifier = modifier.map(|span| self.slice(span));
+
+        if let Some((contract_end, root, month, year)) = self.individual_contract(contract_at) {
+            let (rest, start, continuous) = match self.continuous_modifier(contract_end) {
+                Some((rest, at_sign)) => (rest, at_sign, true),
+                None => (contract_end, root.0, false),
+            };
+            return Some((rest, start, FutureSymbol {
+                continuous,
+                root_symbol: self.slice(root),
+                month: Some(self.slice(month)),
+                year: Some(self.slice(year)),
+                symbol_modifier,
+                original_symbol: self.slice((start, end)),
+            }));
+        }
+
+        let (root_end, root) = self.root_sym(contract_at)?;
+        let (rest, start) = self.continuous_modifier(root_end)?;
+        Some((rest, start, FutureSymbol {
+            continuous: true,
+            root_symbol: self.slice(root),
+            month: None,
+            year: None,
+            symbol_modifier,
+            original_symbol: self.slice((start, end)),
+        }))
+    }
+
+    // strike_code ~ put_call ~ (symbol_modifier)? ~ future_symbol
+    fn future_options_symbol(&mut self, at: Region) -> Option<(Region, ParseResultRef<'a>)> {
+        let end = self.end_of(at);
+        let (put_call_at, strike) = self.strike_code(at)?;
+        let (modifier_at, put_call) = self.put_call(put_call_at)?;
+        let (future_at, modifier) = match self.symbol_modifier(modifier_at) {
+            Some((rest, span)) => (rest, Some(span)),
+            None => (modifier_at, None),
+        };
+        let (rest, start, future_symbol) = self.future_symbol(future_at)?;
+
+        Some((rest, ParseResult::FutureOptionsSym(FutureOptionsSymbol {
+            future_symbol,
+            strike_code: self.slice(strike),
+            put_call,
+            symbol_modifier: modifier.map(|span| self.slice(span)),
+            original_symbol: self.slice((start, end)),
+        })))
+    }
+
+    // strike_price ~ put_call ~ date ~ root_sym
+    fn stock_options_symbol(&mut self, at: Region) -> Option<(Region, ParseResultRef<'a>)> {
+        let end = self.end_of(at);
+        let (put_call_at, strike) = self.strike_price(at)?;
+        let (date_at, put_call) = self.put_call(put_call_at)?;
+        let (root_at, date) = self.date(date_at)?;
+        let (rest, root) = self.root_sym(root_at)?;
+
+        Some((rest, ParseResult::StockOptionsSym(StockOptionsSymbol {
+            root_symbol: self.slice(root),
+            strike_price: self.slice(strike),
+            put_call,
+            date: self.slice(date),
+            original_symbol: self.slice((root.0, end)),
+        })))
+    }
+
+    // symbol_modifier? ~ root_sym
+    fn unknown_symbol(&mut self, at: Region) -> Option<(Region, ParseResultRef<'a>)> {
+        let end = self.end_of(at);
+        let (root_at, modifier) = match self.symbol_modifier(at) {
+            Some((rest, span)) => (rest, Some(span)),
+            None => (at, None),
+        };
+        let (rest, root) = self.root_sym(root_at)?;
+
+        Some((rest, ParseResult::UnknownSym(UnknownSymbol {
+            root_symbol: self.slice(root),
+            symbol_modifier: modifier.map(|span| self.slice(span)),
+            original_symbol: self.slice((root.0, end)),
+        })))
+    }
+
+    fn future_symbol_result(&mut self, at: Region) -> Option<(Region, ParseResultRef<'a>)> {
+        self.future_symbol(at).map(|(rest, _, symbol)| (rest, ParseResult::FutureSym(symbol)))
+    }
+}
+
+// SOI ~ (future_options_symbol | stock_options_symbol | future_symbol | unknown_symbol) ~ EOI
+//
+// Like any PEG, the first alternative that matches is kept even if EOI then fails.
+pub fn parse(raw_symbol: &str) -> Result<ParseResultRef<'_>, ParseError> {
+    let mut scanner = Scanner::new(raw_symbol);
+    let symbol = scanner.whole(0);
+    let matched = scanner
+        .future_options_symbol(symbol)
+        .or_else(|| scanner.stock_options_symbol(symbol))
+        .or_else(|| scanner.future_symbol_result(symbol))
+        .or_else(|| scanner.unknown_symbol(symbol));
+
+    if let Some((rest, result)) = matched {
+        if rest.len == 0 && scanner.words[rest.word].separator.is_none() {
+            return Ok(result);
+        }
+        scanner.fail::<()>(rest);
+    }
+
+    // Report the character the furthest failed rule started at
+    let stopped_at = scanner.furthest.map_or(0, |at| scanner.end_of(at));
+    let position = raw_symbol.char_indices().take_while(|(index, _)| *index < stopped_at).count().saturating_sub(1);
+    let message = if stopped_at == 0 { "unexpected end of symbol" } else { "unexpected character" };
+    Err(ParseError::at(raw_symbol, position, message))
+}
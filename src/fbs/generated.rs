@@ -0,0 +1,4427 @@
+pub use root::*;
+
+const _: () = ::planus::check_version_compatibility("planus-1.3.0");
+
+/// The root namespace
+///
+/// Generated from these locations:
+/// * File `schema/symbols.fbs`
+#[no_implicit_prelude]
+#[allow(clippy::needless_lifetimes)]
+mod root {
+    /// The namespace `symbols`
+    ///
+    /// Generated from these locations:
+    /// * File `schema/symbols.fbs`
+    pub mod symbols {
+        /// The namespace `symbols.v1`
+        ///
+        /// Generated from these locations:
+        /// * File `schema/symbols.fbs`
+        pub mod v1 {
+            /// The struct `Decimal` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Struct `Decimal` in the file `schema/symbols.fbs:8`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                PartialOrd,
+                Eq,
+                Ord,
+                Hash,
+                Default,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            pub struct Decimal {
+                /// The field `lo` in the struct `Decimal`
+                pub lo: u64,
+
+                /// The field `hi` in the struct `Decimal`
+                pub hi: u32,
+
+                /// The field `scale` in the struct `Decimal`
+                pub scale: u8,
+
+                /// The field `negative` in the struct `Decimal`
+                pub negative: bool,
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for Decimal {
+                const ALIGNMENT: usize = 8;
+                const SIZE: usize = 16;
+            }
+
+            #[allow(clippy::identity_op)]
+            impl ::planus::WriteAsPrimitive<Decimal> for Decimal {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    let (cur, cursor) = cursor.split::<8, 8>();
+                    self.lo.write(cur, buffer_position - 0);
+                    let (cur, cursor) = cursor.split::<4, 4>();
+                    self.hi.write(cur, buffer_position - 8);
+                    let (cur, cursor) = cursor.split::<1, 3>();
+                    self.scale.write(cur, buffer_position - 12);
+                    let (cur, cursor) = cursor.split::<1, 2>();
+                    self.negative.write(cur, buffer_position - 13);
+                    let cursor = cursor.write::<2, 0>([0; 2]);
+                    cursor.finish([]);
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Decimal> for Decimal {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Decimal> {
+                    unsafe {
+                        builder.write_with(16, 7, |buffer_position, bytes| {
+                            let bytes = bytes.as_mut_ptr();
+
+                            ::planus::WriteAsPrimitive::write(
+                                self,
+                                ::planus::Cursor::new(
+                                    &mut *(bytes as *mut [::core::mem::MaybeUninit<u8>; 16]),
+                                ),
+                                buffer_position,
+                            );
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<Decimal> for Decimal {
+                type Prepared = Self;
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsOptional<Decimal> for Decimal {
+                type Prepared = Self;
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<Self> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            /// Reference to a deserialized [Decimal].
+            #[derive(Copy, Clone)]
+            pub struct DecimalRef<'a>(::planus::ArrayWithStartOffset<'a, 16>);
+
+            impl<'a> DecimalRef<'a> {
+                /// Getter for the [`lo` field](Decimal#structfield.lo).
+                pub fn lo(&self) -> u64 {
+                    let buffer = self.0.advance_as_array::<8>(0).unwrap();
+
+                    u64::from_le_bytes(*buffer.as_array())
+                }
+
+                /// Getter for the [`hi` field](Decimal#structfield.hi).
+                pub fn hi(&self) -> u32 {
+                    let buffer = self.0.advance_as_array::<4>(8).unwrap();
+
+                    u32::from_le_bytes(*buffer.as_array())
+                }
+
+                /// Getter for the [`scale` field](Decimal#structfield.scale).
+                pub fn scale(&self) -> u8 {
+                    let buffer = self.0.advance_as_array::<1>(12).unwrap();
+
+                    u8::from_le_bytes(*buffer.as_array())
+                }
+
+                /// Getter for the [`negative` field](Decimal#structfield.negative).
+                pub fn negative(&self) -> bool {
+                    let buffer = self.0.advance_as_array::<1>(13).unwrap();
+
+                    buffer.as_array()[0] != 0
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for DecimalRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("DecimalRef");
+                    f.field("lo", &self.lo());
+                    f.field("hi", &self.hi());
+                    f.field("scale", &self.scale());
+                    f.field("negative", &self.negative());
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::From<::planus::ArrayWithStartOffset<'a, 16>> for DecimalRef<'a> {
+                fn from(array: ::planus::ArrayWithStartOffset<'a, 16>) -> Self {
+                    Self(array)
+                }
+            }
+
+            impl<'a> ::core::convert::From<DecimalRef<'a>> for Decimal {
+                #[allow(unreachable_code)]
+                fn from(value: DecimalRef<'a>) -> Self {
+                    Self {
+                        lo: value.lo(),
+                        hi: value.hi(),
+                        scale: value.scale(),
+                        negative: value.negative(),
+                    }
+                }
+            }
+
+            impl<'a, 'b> ::core::cmp::PartialEq<DecimalRef<'a>> for DecimalRef<'b> {
+                fn eq(&self, other: &DecimalRef<'_>) -> bool {
+                    self.lo() == other.lo()
+                        && self.hi() == other.hi()
+                        && self.scale() == other.scale()
+                        && self.negative() == other.negative()
+                }
+            }
+
+            impl<'a> ::core::cmp::Eq for DecimalRef<'a> {}
+            impl<'a, 'b> ::core::cmp::PartialOrd<DecimalRef<'a>> for DecimalRef<'b> {
+                fn partial_cmp(
+                    &self,
+                    other: &DecimalRef<'_>,
+                ) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl<'a> ::core::cmp::Ord for DecimalRef<'a> {
+                fn cmp(&self, other: &DecimalRef<'_>) -> ::core::cmp::Ordering {
+                    self.lo()
+                        .cmp(&other.lo())
+                        .then_with(|| self.hi().cmp(&other.hi()))
+                        .then_with(|| self.scale().cmp(&other.scale()))
+                        .then_with(|| self.negative().cmp(&other.negative()))
+                }
+            }
+
+            impl<'a> ::core::hash::Hash for DecimalRef<'a> {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    self.lo().hash(state);
+                    self.hi().hash(state);
+                    self.scale().hash(state);
+                    self.negative().hash(state);
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for DecimalRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let buffer = buffer.advance_as_array::<16>(offset)?;
+                    ::core::result::Result::Ok(Self(buffer))
+                }
+            }
+
+            impl<'a> ::planus::VectorRead<'a> for DecimalRef<'a> {
+                const STRIDE: usize = 16;
+
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> Self {
+                    Self(unsafe { buffer.unchecked_advance_as_array(offset) })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<Decimal> for Decimal {
+                const STRIDE: usize = 16;
+
+                type Value = Decimal;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self::Value {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Decimal],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 16];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (16 * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The struct `Date` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Struct `Date` in the file `schema/symbols.fbs:15`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                PartialOrd,
+                Eq,
+                Ord,
+                Hash,
+                Default,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            pub struct Date {
+                /// The field `year` in the struct `Date`
+                pub year: i32,
+
+                /// The field `month` in the struct `Date`
+                pub month: u8,
+
+                /// The field `day` in the struct `Date`
+                pub day: u8,
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for Date {
+                const ALIGNMENT: usize = 4;
+                const SIZE: usize = 8;
+            }
+
+            #[allow(clippy::identity_op)]
+            impl ::planus::WriteAsPrimitive<Date> for Date {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    let (cur, cursor) = cursor.split::<4, 4>();
+                    self.year.write(cur, buffer_position - 0);
+                    let (cur, cursor) = cursor.split::<1, 3>();
+                    self.month.write(cur, buffer_position - 4);
+                    let (cur, cursor) = cursor.split::<1, 2>();
+                    self.day.write(cur, buffer_position - 5);
+                    let cursor = cursor.write::<2, 0>([0; 2]);
+                    cursor.finish([]);
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Date> for Date {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Date> {
+                    unsafe {
+                        builder.write_with(8, 3, |buffer_position, bytes| {
+                            let bytes = bytes.as_mut_ptr();
+
+                            ::planus::WriteAsPrimitive::write(
+                                self,
+                                ::planus::Cursor::new(
+                                    &mut *(bytes as *mut [::core::mem::MaybeUninit<u8>; 8]),
+                                ),
+                                buffer_position,
+                            );
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<Date> for Date {
+                type Prepared = Self;
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsOptional<Date> for Date {
+                type Prepared = Self;
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<Self> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            /// Reference to a deserialized [Date].
+            #[derive(Copy, Clone)]
+            pub struct DateRef<'a>(::planus::ArrayWithStartOffset<'a, 8>);
+
+            impl<'a> DateRef<'a> {
+                /// Getter for the [`year` field](Date#structfield.year).
+                pub fn year(&self) -> i32 {
+                    let buffer = self.0.advance_as_array::<4>(0).unwrap();
+
+                    i32::from_le_bytes(*buffer.as_array())
+                }
+
+                /// Getter for the [`month` field](Date#structfield.month).
+                pub fn month(&self) -> u8 {
+                    let buffer = self.0.advance_as_array::<1>(4).unwrap();
+
+                    u8::from_le_bytes(*buffer.as_array())
+                }
+
+                /// Getter for the [`day` field](Date#structfield.day).
+                pub fn day(&self) -> u8 {
+                    let buffer = self.0.advance_as_array::<1>(5).unwrap();
+
+                    u8::from_le_bytes(*buffer.as_array())
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for DateRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("DateRef");
+                    f.field("year", &self.year());
+                    f.field("month", &self.month());
+                    f.field("day", &self.day());
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::From<::planus::ArrayWithStartOffset<'a, 8>> for DateRef<'a> {
+                fn from(array: ::planus::ArrayWithStartOffset<'a, 8>) -> Self {
+                    Self(array)
+                }
+            }
+
+            impl<'a> ::core::convert::From<DateRef<'a>> for Date {
+                #[allow(unreachable_code)]
+                fn from(value: DateRef<'a>) -> Self {
+                    Self {
+                        year: value.year(),
+                        month: value.month(),
+                        day: value.day(),
+                    }
+                }
+            }
+
+            impl<'a, 'b> ::core::cmp::PartialEq<DateRef<'a>> for DateRef<'b> {
+                fn eq(&self, other: &DateRef<'_>) -> bool {
+                    self.year() == other.year()
+                        && self.month() == other.month()
+                        && self.day() == other.day()
+                }
+            }
+
+            impl<'a> ::core::cmp::Eq for DateRef<'a> {}
+            impl<'a, 'b> ::core::cmp::PartialOrd<DateRef<'a>> for DateRef<'b> {
+                fn partial_cmp(
+                    &self,
+                    other: &DateRef<'_>,
+                ) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl<'a> ::core::cmp::Ord for DateRef<'a> {
+                fn cmp(&self, other: &DateRef<'_>) -> ::core::cmp::Ordering {
+                    self.year()
+                        .cmp(&other.year())
+                        .then_with(|| self.month().cmp(&other.month()))
+                        .then_with(|| self.day().cmp(&other.day()))
+                }
+            }
+
+            impl<'a> ::core::hash::Hash for DateRef<'a> {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    self.year().hash(state);
+                    self.month().hash(state);
+                    self.day().hash(state);
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for DateRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let buffer = buffer.advance_as_array::<8>(offset)?;
+                    ::core::result::Result::Ok(Self(buffer))
+                }
+            }
+
+            impl<'a> ::planus::VectorRead<'a> for DateRef<'a> {
+                const STRIDE: usize = 8;
+
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> Self {
+                    Self(unsafe { buffer.unchecked_advance_as_array(offset) })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<Date> for Date {
+                const STRIDE: usize = 8;
+
+                type Value = Date;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self::Value {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Date],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 8];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (8 * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The enum `SymbolType` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Enum `SymbolType` in the file `schema/symbols.fbs:21`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(u8)]
+            pub enum SymbolType {
+                /// The variant `Unknown` in the enum `SymbolType`
+                Unknown = 0,
+
+                /// The variant `Stock` in the enum `SymbolType`
+                Stock = 1,
+
+                /// The variant `Future` in the enum `SymbolType`
+                Future = 2,
+
+                /// The variant `FutureOption` in the enum `SymbolType`
+                FutureOption = 3,
+
+                /// The variant `StockOption` in the enum `SymbolType`
+                StockOption = 4,
+            }
+
+            impl SymbolType {
+                /// Array containing all valid variants of SymbolType
+                pub const ENUM_VALUES: [Self; 5] = [
+                    Self::Unknown,
+                    Self::Stock,
+                    Self::Future,
+                    Self::FutureOption,
+                    Self::StockOption,
+                ];
+            }
+
+            impl ::core::convert::TryFrom<u8> for SymbolType {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: u8,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(SymbolType::Unknown),
+                        1 => ::core::result::Result::Ok(SymbolType::Stock),
+                        2 => ::core::result::Result::Ok(SymbolType::Future),
+                        3 => ::core::result::Result::Ok(SymbolType::FutureOption),
+                        4 => ::core::result::Result::Ok(SymbolType::StockOption),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<SymbolType> for u8 {
+                #[inline]
+                fn from(value: SymbolType) -> Self {
+                    value as u8
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for SymbolType {
+                const ALIGNMENT: usize = 1;
+                const SIZE: usize = 1;
+            }
+
+            impl ::planus::WriteAsPrimitive<SymbolType> for SymbolType {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as u8).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<SymbolType> for SymbolType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> SymbolType {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<SymbolType, SymbolType> for SymbolType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &SymbolType,
+                ) -> ::core::option::Option<SymbolType> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<SymbolType> for SymbolType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<SymbolType> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for SymbolType {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: u8 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for SymbolType {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 1;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value = unsafe { *buffer.buffer.get_unchecked(offset) };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "SymbolType",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<SymbolType> for SymbolType {
+                const STRIDE: usize = 1;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 1];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - i as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The enum `PutCall` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Enum `PutCall` in the file `schema/symbols.fbs:29`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(u8)]
+            pub enum PutCall {
+                /// The variant `Put` in the enum `PutCall`
+                Put = 0,
+
+                /// The variant `Call` in the enum `PutCall`
+                Call = 1,
+            }
+
+            impl PutCall {
+                /// Array containing all valid variants of PutCall
+                pub const ENUM_VALUES: [Self; 2] = [Self::Put, Self::Call];
+            }
+
+            impl ::core::convert::TryFrom<u8> for PutCall {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: u8,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(PutCall::Put),
+                        1 => ::core::result::Result::Ok(PutCall::Call),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<PutCall> for u8 {
+                #[inline]
+                fn from(value: PutCall) -> Self {
+                    value as u8
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for PutCall {
+                const ALIGNMENT: usize = 1;
+                const SIZE: usize = 1;
+            }
+
+            impl ::planus::WriteAsPrimitive<PutCall> for PutCall {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as u8).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<PutCall> for PutCall {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> PutCall {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<PutCall, PutCall> for PutCall {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &PutCall,
+                ) -> ::core::option::Option<PutCall> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<PutCall> for PutCall {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<PutCall> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for PutCall {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: u8 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for PutCall {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 1;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value = unsafe { *buffer.buffer.get_unchecked(offset) };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "PutCall",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<PutCall> for PutCall {
+                const STRIDE: usize = 1;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 1];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - i as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The enum `ExpirationSeries` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Enum `ExpirationSeries` in the file `schema/symbols.fbs:34`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(u8)]
+            pub enum ExpirationSeries {
+                /// The variant `Monthly` in the enum `ExpirationSeries`
+                Monthly = 0,
+
+                /// The variant `Weekly` in the enum `ExpirationSeries`
+                Weekly = 1,
+
+                /// The variant `Quarterly` in the enum `ExpirationSeries`
+                Quarterly = 2,
+
+                /// The variant `EndOfMonth` in the enum `ExpirationSeries`
+                EndOfMonth = 3,
+
+                /// The variant `Daily` in the enum `ExpirationSeries`
+                Daily = 4,
+            }
+
+            impl ExpirationSeries {
+                /// Array containing all valid variants of ExpirationSeries
+                pub const ENUM_VALUES: [Self; 5] = [
+                    Self::Monthly,
+                    Self::Weekly,
+                    Self::Quarterly,
+                    Self::EndOfMonth,
+                    Self::Daily,
+                ];
+            }
+
+            impl ::core::convert::TryFrom<u8> for ExpirationSeries {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: u8,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(ExpirationSeries::Monthly),
+                        1 => ::core::result::Result::Ok(ExpirationSeries::Weekly),
+                        2 => ::core::result::Result::Ok(ExpirationSeries::Quarterly),
+                        3 => ::core::result::Result::Ok(ExpirationSeries::EndOfMonth),
+                        4 => ::core::result::Result::Ok(ExpirationSeries::Daily),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<ExpirationSeries> for u8 {
+                #[inline]
+                fn from(value: ExpirationSeries) -> Self {
+                    value as u8
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for ExpirationSeries {
+                const ALIGNMENT: usize = 1;
+                const SIZE: usize = 1;
+            }
+
+            impl ::planus::WriteAsPrimitive<ExpirationSeries> for ExpirationSeries {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as u8).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<ExpirationSeries> for ExpirationSeries {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> ExpirationSeries {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<ExpirationSeries, ExpirationSeries> for ExpirationSeries {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &ExpirationSeries,
+                ) -> ::core::option::Option<ExpirationSeries> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<ExpirationSeries> for ExpirationSeries {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<ExpirationSeries> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for ExpirationSeries {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: u8 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for ExpirationSeries {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 1;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value = unsafe { *buffer.buffer.get_unchecked(offset) };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "ExpirationSeries",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<ExpirationSeries> for ExpirationSeries {
+                const STRIDE: usize = 1;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 1];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - i as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The enum `ExerciseStyle` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Enum `ExerciseStyle` in the file `schema/symbols.fbs:42`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(u8)]
+            pub enum ExerciseStyle {
+                /// The variant `American` in the enum `ExerciseStyle`
+                American = 0,
+
+                /// The variant `European` in the enum `ExerciseStyle`
+                European = 1,
+            }
+
+            impl ExerciseStyle {
+                /// Array containing all valid variants of ExerciseStyle
+                pub const ENUM_VALUES: [Self; 2] = [Self::American, Self::European];
+            }
+
+            impl ::core::convert::TryFrom<u8> for ExerciseStyle {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: u8,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(ExerciseStyle::American),
+                        1 => ::core::result::Result::Ok(ExerciseStyle::European),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<ExerciseStyle> for u8 {
+                #[inline]
+                fn from(value: ExerciseStyle) -> Self {
+                    value as u8
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for ExerciseStyle {
+                const ALIGNMENT: usize = 1;
+                const SIZE: usize = 1;
+            }
+
+            impl ::planus::WriteAsPrimitive<ExerciseStyle> for ExerciseStyle {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as u8).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<ExerciseStyle> for ExerciseStyle {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> ExerciseStyle {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<ExerciseStyle, ExerciseStyle> for ExerciseStyle {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &ExerciseStyle,
+                ) -> ::core::option::Option<ExerciseStyle> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<ExerciseStyle> for ExerciseStyle {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<ExerciseStyle> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for ExerciseStyle {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: u8 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for ExerciseStyle {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 1;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value = unsafe { *buffer.buffer.get_unchecked(offset) };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "ExerciseStyle",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<ExerciseStyle> for ExerciseStyle {
+                const STRIDE: usize = 1;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 1];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - i as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The enum `SettlementType` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Enum `SettlementType` in the file `schema/symbols.fbs:47`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(u8)]
+            pub enum SettlementType {
+                /// The variant `Physical` in the enum `SettlementType`
+                Physical = 0,
+
+                /// The variant `Cash` in the enum `SettlementType`
+                Cash = 1,
+            }
+
+            impl SettlementType {
+                /// Array containing all valid variants of SettlementType
+                pub const ENUM_VALUES: [Self; 2] = [Self::Physical, Self::Cash];
+            }
+
+            impl ::core::convert::TryFrom<u8> for SettlementType {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: u8,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(SettlementType::Physical),
+                        1 => ::core::result::Result::Ok(SettlementType::Cash),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<SettlementType> for u8 {
+                #[inline]
+                fn from(value: SettlementType) -> Self {
+                    value as u8
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for SettlementType {
+                const ALIGNMENT: usize = 1;
+                const SIZE: usize = 1;
+            }
+
+            impl ::planus::WriteAsPrimitive<SettlementType> for SettlementType {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as u8).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<SettlementType> for SettlementType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> SettlementType {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<SettlementType, SettlementType> for SettlementType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &SettlementType,
+                ) -> ::core::option::Option<SettlementType> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<SettlementType> for SettlementType {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<SettlementType> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for SettlementType {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: u8 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for SettlementType {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 1;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value = unsafe { *buffer.buffer.get_unchecked(offset) };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "SettlementType",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<SettlementType> for SettlementType {
+                const STRIDE: usize = 1;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 1];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - i as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The enum `SettlementTime` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Enum `SettlementTime` in the file `schema/symbols.fbs:52`
+            #[derive(
+                Copy,
+                Clone,
+                Debug,
+                PartialEq,
+                Eq,
+                PartialOrd,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            #[repr(u8)]
+            pub enum SettlementTime {
+                /// The variant `Am` in the enum `SettlementTime`
+                Am = 0,
+
+                /// The variant `Pm` in the enum `SettlementTime`
+                Pm = 1,
+            }
+
+            impl SettlementTime {
+                /// Array containing all valid variants of SettlementTime
+                pub const ENUM_VALUES: [Self; 2] = [Self::Am, Self::Pm];
+            }
+
+            impl ::core::convert::TryFrom<u8> for SettlementTime {
+                type Error = ::planus::errors::UnknownEnumTagKind;
+                #[inline]
+                fn try_from(
+                    value: u8,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTagKind>
+                {
+                    #[allow(clippy::match_single_binding)]
+                    match value {
+                        0 => ::core::result::Result::Ok(SettlementTime::Am),
+                        1 => ::core::result::Result::Ok(SettlementTime::Pm),
+
+                        _ => ::core::result::Result::Err(::planus::errors::UnknownEnumTagKind {
+                            tag: value as i128,
+                        }),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<SettlementTime> for u8 {
+                #[inline]
+                fn from(value: SettlementTime) -> Self {
+                    value as u8
+                }
+            }
+
+            /// # Safety
+            /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+            unsafe impl ::planus::Primitive for SettlementTime {
+                const ALIGNMENT: usize = 1;
+                const SIZE: usize = 1;
+            }
+
+            impl ::planus::WriteAsPrimitive<SettlementTime> for SettlementTime {
+                #[inline]
+                fn write<const N: usize>(
+                    &self,
+                    cursor: ::planus::Cursor<'_, N>,
+                    buffer_position: u32,
+                ) {
+                    (*self as u8).write(cursor, buffer_position);
+                }
+            }
+
+            impl ::planus::WriteAs<SettlementTime> for SettlementTime {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> SettlementTime {
+                    *self
+                }
+            }
+
+            impl ::planus::WriteAsDefault<SettlementTime, SettlementTime> for SettlementTime {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                    default: &SettlementTime,
+                ) -> ::core::option::Option<SettlementTime> {
+                    if self == default {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(*self)
+                    }
+                }
+            }
+
+            impl ::planus::WriteAsOptional<SettlementTime> for SettlementTime {
+                type Prepared = Self;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    _builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<SettlementTime> {
+                    ::core::option::Option::Some(*self)
+                }
+            }
+
+            impl<'buf> ::planus::TableRead<'buf> for SettlementTime {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    let n: u8 = ::planus::TableRead::from_buffer(buffer, offset)?;
+                    ::core::result::Result::Ok(::core::convert::TryInto::try_into(n)?)
+                }
+            }
+
+            impl<'buf> ::planus::VectorReadInner<'buf> for SettlementTime {
+                type Error = ::planus::errors::UnknownEnumTag;
+                const STRIDE: usize = 1;
+                #[inline]
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'buf>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::UnknownEnumTag>
+                {
+                    let value = unsafe { *buffer.buffer.get_unchecked(offset) };
+                    let value: ::core::result::Result<Self, _> =
+                        ::core::convert::TryInto::try_into(value);
+                    value.map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "SettlementTime",
+                            "VectorRead::from_buffer",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<SettlementTime> for SettlementTime {
+                const STRIDE: usize = 1;
+
+                type Value = Self;
+
+                #[inline]
+                fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                    *self
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[Self],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 1];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - i as u32,
+                        );
+                    }
+                }
+            }
+
+            /// The table `Deliverable` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Table `Deliverable` in the file `schema/symbols.fbs:57`
+            #[derive(
+                Clone,
+                Debug,
+                PartialEq,
+                PartialOrd,
+                Eq,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            pub struct Deliverable {
+                /// The field `underlying` in the table `Deliverable`
+                pub underlying: ::planus::alloc::string::String,
+                /// The field `multiplier` in the table `Deliverable`
+                pub multiplier: self::Decimal,
+                /// The field `shares` in the table `Deliverable`
+                pub shares: self::Decimal,
+                /// The field `cash` in the table `Deliverable`
+                pub cash: self::Decimal,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for Deliverable {
+                fn default() -> Self {
+                    Self {
+                        underlying: ::core::default::Default::default(),
+                        multiplier: ::core::default::Default::default(),
+                        shares: ::core::default::Default::default(),
+                        cash: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl Deliverable {
+                /// Creates a [DeliverableBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> DeliverableBuilder<()> {
+                    DeliverableBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_underlying: impl ::planus::WriteAs<::planus::Offset<str>>,
+                    field_multiplier: impl ::planus::WriteAs<self::Decimal>,
+                    field_shares: impl ::planus::WriteAs<self::Decimal>,
+                    field_cash: impl ::planus::WriteAs<self::Decimal>,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_underlying = field_underlying.prepare(builder);
+                    let prepared_multiplier = field_multiplier.prepare(builder);
+                    let prepared_shares = field_shares.prepare(builder);
+                    let prepared_cash = field_cash.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<12> =
+                        ::core::default::Default::default();
+                    table_writer.write_entry::<self::Decimal>(1);
+                    table_writer.write_entry::<self::Decimal>(2);
+                    table_writer.write_entry::<self::Decimal>(3);
+                    table_writer.write_entry::<::planus::Offset<str>>(0);
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            object_writer.write::<_, _, 16>(&prepared_multiplier);
+                            object_writer.write::<_, _, 16>(&prepared_shares);
+                            object_writer.write::<_, _, 16>(&prepared_cash);
+                            object_writer.write::<_, _, 4>(&prepared_underlying);
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<Deliverable>> for Deliverable {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<Deliverable> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<Deliverable>> for Deliverable {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Deliverable>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Deliverable> for Deliverable {
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<Deliverable> {
+                    Deliverable::create(
+                        builder,
+                        &self.underlying,
+                        self.multiplier,
+                        self.shares,
+                        self.cash,
+                    )
+                }
+            }
+
+            /// Builder for serializing an instance of the [Deliverable] type.
+            ///
+            /// Can be created using the [Deliverable::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct DeliverableBuilder<State>(State);
+
+            impl DeliverableBuilder<()> {
+                /// Setter for the [`underlying` field](Deliverable#structfield.underlying).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn underlying<T0>(self, value: T0) -> DeliverableBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAs<::planus::Offset<str>>,
+                {
+                    DeliverableBuilder((value,))
+                }
+            }
+
+            impl<T0> DeliverableBuilder<(T0,)> {
+                /// Setter for the [`multiplier` field](Deliverable#structfield.multiplier).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn multiplier<T1>(self, value: T1) -> DeliverableBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAs<self::Decimal>,
+                {
+                    let (v0,) = self.0;
+                    DeliverableBuilder((v0, value))
+                }
+            }
+
+            impl<T0, T1> DeliverableBuilder<(T0, T1)> {
+                /// Setter for the [`shares` field](Deliverable#structfield.shares).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn shares<T2>(self, value: T2) -> DeliverableBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAs<self::Decimal>,
+                {
+                    let (v0, v1) = self.0;
+                    DeliverableBuilder((v0, v1, value))
+                }
+            }
+
+            impl<T0, T1, T2> DeliverableBuilder<(T0, T1, T2)> {
+                /// Setter for the [`cash` field](Deliverable#structfield.cash).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn cash<T3>(self, value: T3) -> DeliverableBuilder<(T0, T1, T2, T3)>
+                where
+                    T3: ::planus::WriteAs<self::Decimal>,
+                {
+                    let (v0, v1, v2) = self.0;
+                    DeliverableBuilder((v0, v1, v2, value))
+                }
+            }
+
+            impl<T0, T1, T2, T3> DeliverableBuilder<(T0, T1, T2, T3)> {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Deliverable].
+                #[inline]
+                pub fn finish(
+                    self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<Deliverable>
+                where
+                    Self: ::planus::WriteAsOffset<Deliverable>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<::planus::Offset<str>>,
+                    T1: ::planus::WriteAs<self::Decimal>,
+                    T2: ::planus::WriteAs<self::Decimal>,
+                    T3: ::planus::WriteAs<self::Decimal>,
+                > ::planus::WriteAs<::planus::Offset<Deliverable>>
+                for DeliverableBuilder<(T0, T1, T2, T3)>
+            {
+                type Prepared = ::planus::Offset<Deliverable>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<Deliverable> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<::planus::Offset<str>>,
+                    T1: ::planus::WriteAs<self::Decimal>,
+                    T2: ::planus::WriteAs<self::Decimal>,
+                    T3: ::planus::WriteAs<self::Decimal>,
+                > ::planus::WriteAsOptional<::planus::Offset<Deliverable>>
+                for DeliverableBuilder<(T0, T1, T2, T3)>
+            {
+                type Prepared = ::planus::Offset<Deliverable>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Deliverable>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<::planus::Offset<str>>,
+                    T1: ::planus::WriteAs<self::Decimal>,
+                    T2: ::planus::WriteAs<self::Decimal>,
+                    T3: ::planus::WriteAs<self::Decimal>,
+                > ::planus::WriteAsOffset<Deliverable> for DeliverableBuilder<(T0, T1, T2, T3)>
+            {
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<Deliverable> {
+                    let (v0, v1, v2, v3) = &self.0;
+                    Deliverable::create(builder, v0, v1, v2, v3)
+                }
+            }
+
+            /// Reference to a deserialized [Deliverable].
+            #[derive(Copy, Clone)]
+            pub struct DeliverableRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> DeliverableRef<'a> {
+                /// Getter for the [`underlying` field](Deliverable#structfield.underlying).
+                #[inline]
+                pub fn underlying(&self) -> ::planus::Result<&'a ::core::primitive::str> {
+                    self.0.access_required(0, "Deliverable", "underlying")
+                }
+
+                /// Getter for the [`multiplier` field](Deliverable#structfield.multiplier).
+                #[inline]
+                pub fn multiplier(&self) -> ::planus::Result<self::DecimalRef<'a>> {
+                    self.0.access_required(1, "Deliverable", "multiplier")
+                }
+
+                /// Getter for the [`shares` field](Deliverable#structfield.shares).
+                #[inline]
+                pub fn shares(&self) -> ::planus::Result<self::DecimalRef<'a>> {
+                    self.0.access_required(2, "Deliverable", "shares")
+                }
+
+                /// Getter for the [`cash` field](Deliverable#structfield.cash).
+                #[inline]
+                pub fn cash(&self) -> ::planus::Result<self::DecimalRef<'a>> {
+                    self.0.access_required(3, "Deliverable", "cash")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for DeliverableRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("DeliverableRef");
+                    f.field("underlying", &self.underlying());
+                    f.field("multiplier", &self.multiplier());
+                    f.field("shares", &self.shares());
+                    f.field("cash", &self.cash());
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<DeliverableRef<'a>> for Deliverable {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: DeliverableRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        underlying: ::core::convert::Into::into(value.underlying()?),
+                        multiplier: ::core::convert::Into::into(value.multiplier()?),
+                        shares: ::core::convert::Into::into(value.shares()?),
+                        cash: ::core::convert::Into::into(value.cash()?),
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for DeliverableRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for DeliverableRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[DeliverableRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<Deliverable>> for Deliverable {
+                type Value = ::planus::Offset<Deliverable>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Deliverable>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for DeliverableRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[DeliverableRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `OptionContract` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Table `OptionContract` in the file `schema/symbols.fbs:64`
+            #[derive(
+                Clone,
+                Debug,
+                PartialEq,
+                PartialOrd,
+                Eq,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            pub struct OptionContract {
+                /// The field `strike_price` in the table `OptionContract`
+                pub strike_price: self::Decimal,
+                /// The field `put_call` in the table `OptionContract`
+                pub put_call: self::PutCall,
+                /// The field `date` in the table `OptionContract`
+                pub date: ::core::option::Option<self::Date>,
+                /// The field `series` in the table `OptionContract`
+                pub series: ::core::option::Option<self::ExpirationSeries>,
+                /// The field `adjusted` in the table `OptionContract`
+                pub adjusted: bool,
+                /// The field `deliverable` in the table `OptionContract`
+                pub deliverable:
+                    ::core::option::Option<::planus::alloc::boxed::Box<self::Deliverable>>,
+                /// The field `exercise_style` in the table `OptionContract`
+                pub exercise_style: ::core::option::Option<self::ExerciseStyle>,
+                /// The field `settlement_type` in the table `OptionContract`
+                pub settlement_type: ::core::option::Option<self::SettlementType>,
+                /// The field `settlement_time` in the table `OptionContract`
+                pub settlement_time: ::core::option::Option<self::SettlementTime>,
+                /// The field `expiration_time` in the table `OptionContract`
+                pub expiration_time: ::core::option::Option<u32>,
+                /// The field `time_zone` in the table `OptionContract`
+                pub time_zone: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `root` in the table `OptionContract`
+                pub root: ::core::option::Option<::planus::alloc::string::String>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for OptionContract {
+                fn default() -> Self {
+                    Self {
+                        strike_price: ::core::default::Default::default(),
+                        put_call: self::PutCall::Put,
+                        date: ::core::default::Default::default(),
+                        series: ::core::default::Default::default(),
+                        adjusted: false,
+                        deliverable: ::core::default::Default::default(),
+                        exercise_style: ::core::default::Default::default(),
+                        settlement_type: ::core::default::Default::default(),
+                        settlement_time: ::core::default::Default::default(),
+                        expiration_time: ::core::default::Default::default(),
+                        time_zone: ::core::default::Default::default(),
+                        root: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl OptionContract {
+                /// Creates a [OptionContractBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> OptionContractBuilder<()> {
+                    OptionContractBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_strike_price: impl ::planus::WriteAs<self::Decimal>,
+                    field_put_call: impl ::planus::WriteAsDefault<self::PutCall, self::PutCall>,
+                    field_date: impl ::planus::WriteAsOptional<self::Date>,
+                    field_series: impl ::planus::WriteAsOptional<self::ExpirationSeries>,
+                    field_adjusted: impl ::planus::WriteAsDefault<bool, bool>,
+                    field_deliverable: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<self::Deliverable>,
+                    >,
+                    field_exercise_style: impl ::planus::WriteAsOptional<self::ExerciseStyle>,
+                    field_settlement_type: impl ::planus::WriteAsOptional<self::SettlementType>,
+                    field_settlement_time: impl ::planus::WriteAsOptional<self::SettlementTime>,
+                    field_expiration_time: impl ::planus::WriteAsOptional<u32>,
+                    field_time_zone: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_root: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_strike_price = field_strike_price.prepare(builder);
+                    let prepared_put_call = field_put_call.prepare(builder, &self::PutCall::Put);
+                    let prepared_date = field_date.prepare(builder);
+                    let prepared_series = field_series.prepare(builder);
+                    let prepared_adjusted = field_adjusted.prepare(builder, &false);
+                    let prepared_deliverable = field_deliverable.prepare(builder);
+                    let prepared_exercise_style = field_exercise_style.prepare(builder);
+                    let prepared_settlement_type = field_settlement_type.prepare(builder);
+                    let prepared_settlement_time = field_settlement_time.prepare(builder);
+                    let prepared_expiration_time = field_expiration_time.prepare(builder);
+                    let prepared_time_zone = field_time_zone.prepare(builder);
+                    let prepared_root = field_root.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<28> =
+                        ::core::default::Default::default();
+                    table_writer.write_entry::<self::Decimal>(0);
+                    if prepared_date.is_some() {
+                        table_writer.write_entry::<self::Date>(2);
+                    }
+                    if prepared_deliverable.is_some() {
+                        table_writer.write_entry::<::planus::Offset<self::Deliverable>>(5);
+                    }
+                    if prepared_expiration_time.is_some() {
+                        table_writer.write_entry::<u32>(9);
+                    }
+                    if prepared_time_zone.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(10);
+                    }
+                    if prepared_root.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(11);
+                    }
+                    if prepared_put_call.is_some() {
+                        table_writer.write_entry::<self::PutCall>(1);
+                    }
+                    if prepared_series.is_some() {
+                        table_writer.write_entry::<self::ExpirationSeries>(3);
+                    }
+                    if prepared_adjusted.is_some() {
+                        table_writer.write_entry::<bool>(4);
+                    }
+                    if prepared_exercise_style.is_some() {
+                        table_writer.write_entry::<self::ExerciseStyle>(6);
+                    }
+                    if prepared_settlement_type.is_some() {
+                        table_writer.write_entry::<self::SettlementType>(7);
+                    }
+                    if prepared_settlement_time.is_some() {
+                        table_writer.write_entry::<self::SettlementTime>(8);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            object_writer.write::<_, _, 16>(&prepared_strike_price);
+                            if let ::core::option::Option::Some(prepared_date) = prepared_date {
+                                object_writer.write::<_, _, 8>(&prepared_date);
+                            }
+                            if let ::core::option::Option::Some(prepared_deliverable) =
+                                prepared_deliverable
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_deliverable);
+                            }
+                            if let ::core::option::Option::Some(prepared_expiration_time) =
+                                prepared_expiration_time
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_expiration_time);
+                            }
+                            if let ::core::option::Option::Some(prepared_time_zone) =
+                                prepared_time_zone
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_time_zone);
+                            }
+                            if let ::core::option::Option::Some(prepared_root) = prepared_root {
+                                object_writer.write::<_, _, 4>(&prepared_root);
+                            }
+                            if let ::core::option::Option::Some(prepared_put_call) =
+                                prepared_put_call
+                            {
+                                object_writer.write::<_, _, 1>(&prepared_put_call);
+                            }
+                            if let ::core::option::Option::Some(prepared_series) = prepared_series {
+                                object_writer.write::<_, _, 1>(&prepared_series);
+                            }
+                            if let ::core::option::Option::Some(prepared_adjusted) =
+                                prepared_adjusted
+                            {
+                                object_writer.write::<_, _, 1>(&prepared_adjusted);
+                            }
+                            if let ::core::option::Option::Some(prepared_exercise_style) =
+                                prepared_exercise_style
+                            {
+                                object_writer.write::<_, _, 1>(&prepared_exercise_style);
+                            }
+                            if let ::core::option::Option::Some(prepared_settlement_type) =
+                                prepared_settlement_type
+                            {
+                                object_writer.write::<_, _, 1>(&prepared_settlement_type);
+                            }
+                            if let ::core::option::Option::Some(prepared_settlement_time) =
+                                prepared_settlement_time
+                            {
+                                object_writer.write::<_, _, 1>(&prepared_settlement_time);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<OptionContract>> for OptionContract {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<OptionContract> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<OptionContract>> for OptionContract {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<OptionContract>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<OptionContract> for OptionContract {
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<OptionContract> {
+                    OptionContract::create(
+                        builder,
+                        self.strike_price,
+                        self.put_call,
+                        self.date,
+                        self.series,
+                        self.adjusted,
+                        &self.deliverable,
+                        self.exercise_style,
+                        self.settlement_type,
+                        self.settlement_time,
+                        self.expiration_time,
+                        &self.time_zone,
+                        &self.root,
+                    )
+                }
+            }
+
+            /// Builder for serializing an instance of the [OptionContract] type.
+            ///
+            /// Can be created using the [OptionContract::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct OptionContractBuilder<State>(State);
+
+            impl OptionContractBuilder<()> {
+                /// Setter for the [`strike_price` field](OptionContract#structfield.strike_price).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn strike_price<T0>(self, value: T0) -> OptionContractBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAs<self::Decimal>,
+                {
+                    OptionContractBuilder((value,))
+                }
+            }
+
+            impl<T0> OptionContractBuilder<(T0,)> {
+                /// Setter for the [`put_call` field](OptionContract#structfield.put_call).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn put_call<T1>(self, value: T1) -> OptionContractBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAsDefault<self::PutCall, self::PutCall>,
+                {
+                    let (v0,) = self.0;
+                    OptionContractBuilder((v0, value))
+                }
+
+                /// Sets the [`put_call` field](OptionContract#structfield.put_call) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn put_call_as_default(
+                    self,
+                ) -> OptionContractBuilder<(T0, ::planus::DefaultValue)> {
+                    self.put_call(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1> OptionContractBuilder<(T0, T1)> {
+                /// Setter for the [`date` field](OptionContract#structfield.date).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn date<T2>(self, value: T2) -> OptionContractBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAsOptional<self::Date>,
+                {
+                    let (v0, v1) = self.0;
+                    OptionContractBuilder((v0, v1, value))
+                }
+
+                /// Sets the [`date` field](OptionContract#structfield.date) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn date_as_null(self) -> OptionContractBuilder<(T0, T1, ())> {
+                    self.date(())
+                }
+            }
+
+            impl<T0, T1, T2> OptionContractBuilder<(T0, T1, T2)> {
+                /// Setter for the [`series` field](OptionContract#structfield.series).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn series<T3>(self, value: T3) -> OptionContractBuilder<(T0, T1, T2, T3)>
+                where
+                    T3: ::planus::WriteAsOptional<self::ExpirationSeries>,
+                {
+                    let (v0, v1, v2) = self.0;
+                    OptionContractBuilder((v0, v1, v2, value))
+                }
+
+                /// Sets the [`series` field](OptionContract#structfield.series) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn series_as_null(self) -> OptionContractBuilder<(T0, T1, T2, ())> {
+                    self.series(())
+                }
+            }
+
+            impl<T0, T1, T2, T3> OptionContractBuilder<(T0, T1, T2, T3)> {
+                /// Setter for the [`adjusted` field](OptionContract#structfield.adjusted).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn adjusted<T4>(self, value: T4) -> OptionContractBuilder<(T0, T1, T2, T3, T4)>
+                where
+                    T4: ::planus::WriteAsDefault<bool, bool>,
+                {
+                    let (v0, v1, v2, v3) = self.0;
+                    OptionContractBuilder((v0, v1, v2, v3, value))
+                }
+
+                /// Sets the [`adjusted` field](OptionContract#structfield.adjusted) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn adjusted_as_default(
+                    self,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, ::planus::DefaultValue)>
+                {
+                    self.adjusted(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4> OptionContractBuilder<(T0, T1, T2, T3, T4)> {
+                /// Setter for the [`deliverable` field](OptionContract#structfield.deliverable).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn deliverable<T5>(
+                    self,
+                    value: T5,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5)>
+                where
+                    T5: ::planus::WriteAsOptional<::planus::Offset<self::Deliverable>>,
+                {
+                    let (v0, v1, v2, v3, v4) = self.0;
+                    OptionContractBuilder((v0, v1, v2, v3, v4, value))
+                }
+
+                /// Sets the [`deliverable` field](OptionContract#structfield.deliverable) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn deliverable_as_null(
+                    self,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, ())> {
+                    self.deliverable(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5> OptionContractBuilder<(T0, T1, T2, T3, T4, T5)> {
+                /// Setter for the [`exercise_style` field](OptionContract#structfield.exercise_style).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn exercise_style<T6>(
+                    self,
+                    value: T6,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6)>
+                where
+                    T6: ::planus::WriteAsOptional<self::ExerciseStyle>,
+                {
+                    let (v0, v1, v2, v3, v4, v5) = self.0;
+                    OptionContractBuilder((v0, v1, v2, v3, v4, v5, value))
+                }
+
+                /// Sets the [`exercise_style` field](OptionContract#structfield.exercise_style) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn exercise_style_as_null(
+                    self,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, ())> {
+                    self.exercise_style(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6)> {
+                /// Setter for the [`settlement_type` field](OptionContract#structfield.settlement_type).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn settlement_type<T7>(
+                    self,
+                    value: T7,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7)>
+                where
+                    T7: ::planus::WriteAsOptional<self::SettlementType>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6) = self.0;
+                    OptionContractBuilder((v0, v1, v2, v3, v4, v5, v6, value))
+                }
+
+                /// Sets the [`settlement_type` field](OptionContract#structfield.settlement_type) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn settlement_type_as_null(
+                    self,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, ())> {
+                    self.settlement_type(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7)> {
+                /// Setter for the [`settlement_time` field](OptionContract#structfield.settlement_time).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn settlement_time<T8>(
+                    self,
+                    value: T8,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8)>
+                where
+                    T8: ::planus::WriteAsOptional<self::SettlementTime>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7) = self.0;
+                    OptionContractBuilder((v0, v1, v2, v3, v4, v5, v6, v7, value))
+                }
+
+                /// Sets the [`settlement_time` field](OptionContract#structfield.settlement_time) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn settlement_time_as_null(
+                    self,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, ())> {
+                    self.settlement_time(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8>
+                OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8)>
+            {
+                /// Setter for the [`expiration_time` field](OptionContract#structfield.expiration_time).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn expiration_time<T9>(
+                    self,
+                    value: T9,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)>
+                where
+                    T9: ::planus::WriteAsOptional<u32>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8) = self.0;
+                    OptionContractBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, value))
+                }
+
+                /// Sets the [`expiration_time` field](OptionContract#structfield.expiration_time) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn expiration_time_as_null(
+                    self,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, ())>
+                {
+                    self.expiration_time(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9>
+                OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)>
+            {
+                /// Setter for the [`time_zone` field](OptionContract#structfield.time_zone).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn time_zone<T10>(
+                    self,
+                    value: T10,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)>
+                where
+                    T10: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9) = self.0;
+                    OptionContractBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, value))
+                }
+
+                /// Sets the [`time_zone` field](OptionContract#structfield.time_zone) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn time_zone_as_null(
+                    self,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, ())>
+                {
+                    self.time_zone(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10>
+                OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)>
+            {
+                /// Setter for the [`root` field](OptionContract#structfield.root).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn root<T11>(
+                    self,
+                    value: T11,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+                where
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10) = self.0;
+                    OptionContractBuilder((v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, value))
+                }
+
+                /// Sets the [`root` field](OptionContract#structfield.root) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn root_as_null(
+                    self,
+                ) -> OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, ())>
+                {
+                    self.root(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11>
+                OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [OptionContract].
+                #[inline]
+                pub fn finish(
+                    self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<OptionContract>
+                where
+                    Self: ::planus::WriteAsOffset<OptionContract>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<self::Decimal>,
+                    T1: ::planus::WriteAsDefault<self::PutCall, self::PutCall>,
+                    T2: ::planus::WriteAsOptional<self::Date>,
+                    T3: ::planus::WriteAsOptional<self::ExpirationSeries>,
+                    T4: ::planus::WriteAsDefault<bool, bool>,
+                    T5: ::planus::WriteAsOptional<::planus::Offset<self::Deliverable>>,
+                    T6: ::planus::WriteAsOptional<self::ExerciseStyle>,
+                    T7: ::planus::WriteAsOptional<self::SettlementType>,
+                    T8: ::planus::WriteAsOptional<self::SettlementTime>,
+                    T9: ::planus::WriteAsOptional<u32>,
+                    T10: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAs<::planus::Offset<OptionContract>>
+                for OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                type Prepared = ::planus::Offset<OptionContract>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<OptionContract> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<self::Decimal>,
+                    T1: ::planus::WriteAsDefault<self::PutCall, self::PutCall>,
+                    T2: ::planus::WriteAsOptional<self::Date>,
+                    T3: ::planus::WriteAsOptional<self::ExpirationSeries>,
+                    T4: ::planus::WriteAsDefault<bool, bool>,
+                    T5: ::planus::WriteAsOptional<::planus::Offset<self::Deliverable>>,
+                    T6: ::planus::WriteAsOptional<self::ExerciseStyle>,
+                    T7: ::planus::WriteAsOptional<self::SettlementType>,
+                    T8: ::planus::WriteAsOptional<self::SettlementTime>,
+                    T9: ::planus::WriteAsOptional<u32>,
+                    T10: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAsOptional<::planus::Offset<OptionContract>>
+                for OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                type Prepared = ::planus::Offset<OptionContract>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<OptionContract>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<self::Decimal>,
+                    T1: ::planus::WriteAsDefault<self::PutCall, self::PutCall>,
+                    T2: ::planus::WriteAsOptional<self::Date>,
+                    T3: ::planus::WriteAsOptional<self::ExpirationSeries>,
+                    T4: ::planus::WriteAsDefault<bool, bool>,
+                    T5: ::planus::WriteAsOptional<::planus::Offset<self::Deliverable>>,
+                    T6: ::planus::WriteAsOptional<self::ExerciseStyle>,
+                    T7: ::planus::WriteAsOptional<self::SettlementType>,
+                    T8: ::planus::WriteAsOptional<self::SettlementTime>,
+                    T9: ::planus::WriteAsOptional<u32>,
+                    T10: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T11: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                > ::planus::WriteAsOffset<OptionContract>
+                for OptionContractBuilder<(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)>
+            {
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<OptionContract> {
+                    let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11) = &self.0;
+                    OptionContract::create(
+                        builder, v0, v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11,
+                    )
+                }
+            }
+
+            /// Reference to a deserialized [OptionContract].
+            #[derive(Copy, Clone)]
+            pub struct OptionContractRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> OptionContractRef<'a> {
+                /// Getter for the [`strike_price` field](OptionContract#structfield.strike_price).
+                #[inline]
+                pub fn strike_price(&self) -> ::planus::Result<self::DecimalRef<'a>> {
+                    self.0.access_required(0, "OptionContract", "strike_price")
+                }
+
+                /// Getter for the [`put_call` field](OptionContract#structfield.put_call).
+                #[inline]
+                pub fn put_call(&self) -> ::planus::Result<self::PutCall> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(1, "OptionContract", "put_call")?
+                            .unwrap_or(self::PutCall::Put),
+                    )
+                }
+
+                /// Getter for the [`date` field](OptionContract#structfield.date).
+                #[inline]
+                pub fn date(&self) -> ::planus::Result<::core::option::Option<self::DateRef<'a>>> {
+                    self.0.access(2, "OptionContract", "date")
+                }
+
+                /// Getter for the [`series` field](OptionContract#structfield.series).
+                #[inline]
+                pub fn series(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<self::ExpirationSeries>>
+                {
+                    self.0.access(3, "OptionContract", "series")
+                }
+
+                /// Getter for the [`adjusted` field](OptionContract#structfield.adjusted).
+                #[inline]
+                pub fn adjusted(&self) -> ::planus::Result<bool> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(4, "OptionContract", "adjusted")?
+                            .unwrap_or(false),
+                    )
+                }
+
+                /// Getter for the [`deliverable` field](OptionContract#structfield.deliverable).
+                #[inline]
+                pub fn deliverable(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<self::DeliverableRef<'a>>>
+                {
+                    self.0.access(5, "OptionContract", "deliverable")
+                }
+
+                /// Getter for the [`exercise_style` field](OptionContract#structfield.exercise_style).
+                #[inline]
+                pub fn exercise_style(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<self::ExerciseStyle>> {
+                    self.0.access(6, "OptionContract", "exercise_style")
+                }
+
+                /// Getter for the [`settlement_type` field](OptionContract#structfield.settlement_type).
+                #[inline]
+                pub fn settlement_type(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<self::SettlementType>>
+                {
+                    self.0.access(7, "OptionContract", "settlement_type")
+                }
+
+                /// Getter for the [`settlement_time` field](OptionContract#structfield.settlement_time).
+                #[inline]
+                pub fn settlement_time(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<self::SettlementTime>>
+                {
+                    self.0.access(8, "OptionContract", "settlement_time")
+                }
+
+                /// Getter for the [`expiration_time` field](OptionContract#structfield.expiration_time).
+                #[inline]
+                pub fn expiration_time(&self) -> ::planus::Result<::core::option::Option<u32>> {
+                    self.0.access(9, "OptionContract", "expiration_time")
+                }
+
+                /// Getter for the [`time_zone` field](OptionContract#structfield.time_zone).
+                #[inline]
+                pub fn time_zone(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(10, "OptionContract", "time_zone")
+                }
+
+                /// Getter for the [`root` field](OptionContract#structfield.root).
+                #[inline]
+                pub fn root(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(11, "OptionContract", "root")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for OptionContractRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("OptionContractRef");
+                    f.field("strike_price", &self.strike_price());
+                    f.field("put_call", &self.put_call());
+                    if let ::core::option::Option::Some(field_date) = self.date().transpose() {
+                        f.field("date", &field_date);
+                    }
+                    if let ::core::option::Option::Some(field_series) = self.series().transpose() {
+                        f.field("series", &field_series);
+                    }
+                    f.field("adjusted", &self.adjusted());
+                    if let ::core::option::Option::Some(field_deliverable) =
+                        self.deliverable().transpose()
+                    {
+                        f.field("deliverable", &field_deliverable);
+                    }
+                    if let ::core::option::Option::Some(field_exercise_style) =
+                        self.exercise_style().transpose()
+                    {
+                        f.field("exercise_style", &field_exercise_style);
+                    }
+                    if let ::core::option::Option::Some(field_settlement_type) =
+                        self.settlement_type().transpose()
+                    {
+                        f.field("settlement_type", &field_settlement_type);
+                    }
+                    if let ::core::option::Option::Some(field_settlement_time) =
+                        self.settlement_time().transpose()
+                    {
+                        f.field("settlement_time", &field_settlement_time);
+                    }
+                    if let ::core::option::Option::Some(field_expiration_time) =
+                        self.expiration_time().transpose()
+                    {
+                        f.field("expiration_time", &field_expiration_time);
+                    }
+                    if let ::core::option::Option::Some(field_time_zone) =
+                        self.time_zone().transpose()
+                    {
+                        f.field("time_zone", &field_time_zone);
+                    }
+                    if let ::core::option::Option::Some(field_root) = self.root().transpose() {
+                        f.field("root", &field_root);
+                    }
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<OptionContractRef<'a>> for OptionContract {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: OptionContractRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        strike_price: ::core::convert::Into::into(value.strike_price()?),
+                        put_call: ::core::convert::TryInto::try_into(value.put_call()?)?,
+                        date: value.date()?.map(::core::convert::Into::into),
+                        series: if let ::core::option::Option::Some(series) = value.series()? {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                series,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        adjusted: ::core::convert::TryInto::try_into(value.adjusted()?)?,
+                        deliverable: if let ::core::option::Option::Some(deliverable) =
+                            value.deliverable()?
+                        {
+                            ::core::option::Option::Some(::planus::alloc::boxed::Box::new(
+                                ::core::convert::TryInto::try_into(deliverable)?,
+                            ))
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        exercise_style: if let ::core::option::Option::Some(exercise_style) =
+                            value.exercise_style()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                exercise_style,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        settlement_type: if let ::core::option::Option::Some(settlement_type) =
+                            value.settlement_type()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                settlement_type,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        settlement_time: if let ::core::option::Option::Some(settlement_time) =
+                            value.settlement_time()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                settlement_time,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        expiration_time: if let ::core::option::Option::Some(expiration_time) =
+                            value.expiration_time()?
+                        {
+                            ::core::option::Option::Some(::core::convert::TryInto::try_into(
+                                expiration_time,
+                            )?)
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        time_zone: value.time_zone()?.map(::core::convert::Into::into),
+                        root: value.root()?.map(::core::convert::Into::into),
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for OptionContractRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for OptionContractRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[OptionContractRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<OptionContract>> for OptionContract {
+                type Value = ::planus::Offset<OptionContract>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<OptionContract>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for OptionContractRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[OptionContractRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `FutureContract` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Table `FutureContract` in the file `schema/symbols.fbs:83`
+            #[derive(
+                Clone,
+                Debug,
+                PartialEq,
+                PartialOrd,
+                Eq,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            pub struct FutureContract {
+                /// The field `continuous` in the table `FutureContract`
+                pub continuous: bool,
+                /// The field `month` in the table `FutureContract`
+                pub month: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `year` in the table `FutureContract`
+                pub year: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `expiration` in the table `FutureContract`
+                pub expiration: ::core::option::Option<self::Date>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for FutureContract {
+                fn default() -> Self {
+                    Self {
+                        continuous: false,
+                        month: ::core::default::Default::default(),
+                        year: ::core::default::Default::default(),
+                        expiration: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl FutureContract {
+                /// Creates a [FutureContractBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> FutureContractBuilder<()> {
+                    FutureContractBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_continuous: impl ::planus::WriteAsDefault<bool, bool>,
+                    field_month: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_year: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    field_expiration: impl ::planus::WriteAsOptional<self::Date>,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_continuous = field_continuous.prepare(builder, &false);
+                    let prepared_month = field_month.prepare(builder);
+                    let prepared_year = field_year.prepare(builder);
+                    let prepared_expiration = field_expiration.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<12> =
+                        ::core::default::Default::default();
+                    if prepared_month.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(1);
+                    }
+                    if prepared_year.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(2);
+                    }
+                    if prepared_expiration.is_some() {
+                        table_writer.write_entry::<self::Date>(3);
+                    }
+                    if prepared_continuous.is_some() {
+                        table_writer.write_entry::<bool>(0);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            if let ::core::option::Option::Some(prepared_month) = prepared_month {
+                                object_writer.write::<_, _, 4>(&prepared_month);
+                            }
+                            if let ::core::option::Option::Some(prepared_year) = prepared_year {
+                                object_writer.write::<_, _, 4>(&prepared_year);
+                            }
+                            if let ::core::option::Option::Some(prepared_expiration) =
+                                prepared_expiration
+                            {
+                                object_writer.write::<_, _, 8>(&prepared_expiration);
+                            }
+                            if let ::core::option::Option::Some(prepared_continuous) =
+                                prepared_continuous
+                            {
+                                object_writer.write::<_, _, 1>(&prepared_continuous);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<FutureContract>> for FutureContract {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<FutureContract> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<FutureContract>> for FutureContract {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<FutureContract>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<FutureContract> for FutureContract {
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<FutureContract> {
+                    FutureContract::create(
+                        builder,
+                        self.continuous,
+                        &self.month,
+                        &self.year,
+                        self.expiration,
+                    )
+                }
+            }
+
+            /// Builder for serializing an instance of the [FutureContract] type.
+            ///
+            /// Can be created using the [FutureContract::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct FutureContractBuilder<State>(State);
+
+            impl FutureContractBuilder<()> {
+                /// Setter for the [`continuous` field](FutureContract#structfield.continuous).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn continuous<T0>(self, value: T0) -> FutureContractBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAsDefault<bool, bool>,
+                {
+                    FutureContractBuilder((value,))
+                }
+
+                /// Sets the [`continuous` field](FutureContract#structfield.continuous) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn continuous_as_default(
+                    self,
+                ) -> FutureContractBuilder<(::planus::DefaultValue,)> {
+                    self.continuous(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0> FutureContractBuilder<(T0,)> {
+                /// Setter for the [`month` field](FutureContract#structfield.month).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn month<T1>(self, value: T1) -> FutureContractBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0,) = self.0;
+                    FutureContractBuilder((v0, value))
+                }
+
+                /// Sets the [`month` field](FutureContract#structfield.month) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn month_as_null(self) -> FutureContractBuilder<(T0, ())> {
+                    self.month(())
+                }
+            }
+
+            impl<T0, T1> FutureContractBuilder<(T0, T1)> {
+                /// Setter for the [`year` field](FutureContract#structfield.year).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn year<T2>(self, value: T2) -> FutureContractBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1) = self.0;
+                    FutureContractBuilder((v0, v1, value))
+                }
+
+                /// Sets the [`year` field](FutureContract#structfield.year) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn year_as_null(self) -> FutureContractBuilder<(T0, T1, ())> {
+                    self.year(())
+                }
+            }
+
+            impl<T0, T1, T2> FutureContractBuilder<(T0, T1, T2)> {
+                /// Setter for the [`expiration` field](FutureContract#structfield.expiration).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn expiration<T3>(self, value: T3) -> FutureContractBuilder<(T0, T1, T2, T3)>
+                where
+                    T3: ::planus::WriteAsOptional<self::Date>,
+                {
+                    let (v0, v1, v2) = self.0;
+                    FutureContractBuilder((v0, v1, v2, value))
+                }
+
+                /// Sets the [`expiration` field](FutureContract#structfield.expiration) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn expiration_as_null(self) -> FutureContractBuilder<(T0, T1, T2, ())> {
+                    self.expiration(())
+                }
+            }
+
+            impl<T0, T1, T2, T3> FutureContractBuilder<(T0, T1, T2, T3)> {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [FutureContract].
+                #[inline]
+                pub fn finish(
+                    self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<FutureContract>
+                where
+                    Self: ::planus::WriteAsOffset<FutureContract>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<bool, bool>,
+                    T1: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T2: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T3: ::planus::WriteAsOptional<self::Date>,
+                > ::planus::WriteAs<::planus::Offset<FutureContract>>
+                for FutureContractBuilder<(T0, T1, T2, T3)>
+            {
+                type Prepared = ::planus::Offset<FutureContract>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<FutureContract> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<bool, bool>,
+                    T1: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T2: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T3: ::planus::WriteAsOptional<self::Date>,
+                > ::planus::WriteAsOptional<::planus::Offset<FutureContract>>
+                for FutureContractBuilder<(T0, T1, T2, T3)>
+            {
+                type Prepared = ::planus::Offset<FutureContract>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<FutureContract>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<bool, bool>,
+                    T1: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T2: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T3: ::planus::WriteAsOptional<self::Date>,
+                > ::planus::WriteAsOffset<FutureContract>
+                for FutureContractBuilder<(T0, T1, T2, T3)>
+            {
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::planus::Offset<FutureContract> {
+                    let (v0, v1, v2, v3) = &self.0;
+                    FutureContract::create(builder, v0, v1, v2, v3)
+                }
+            }
+
+            /// Reference to a deserialized [FutureContract].
+            #[derive(Copy, Clone)]
+            pub struct FutureContractRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> FutureContractRef<'a> {
+                /// Getter for the [`continuous` field](FutureContract#structfield.continuous).
+                #[inline]
+                pub fn continuous(&self) -> ::planus::Result<bool> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(0, "FutureContract", "continuous")?
+                            .unwrap_or(false),
+                    )
+                }
+
+                /// Getter for the [`month` field](FutureContract#structfield.month).
+                #[inline]
+                pub fn month(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(1, "FutureContract", "month")
+                }
+
+                /// Getter for the [`year` field](FutureContract#structfield.year).
+                #[inline]
+                pub fn year(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(2, "FutureContract", "year")
+                }
+
+                /// Getter for the [`expiration` field](FutureContract#structfield.expiration).
+                #[inline]
+                pub fn expiration(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<self::DateRef<'a>>> {
+                    self.0.access(3, "FutureContract", "expiration")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for FutureContractRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("FutureContractRef");
+                    f.field("continuous", &self.continuous());
+                    if let ::core::option::Option::Some(field_month) = self.month().transpose() {
+                        f.field("month", &field_month);
+                    }
+                    if let ::core::option::Option::Some(field_year) = self.year().transpose() {
+                        f.field("year", &field_year);
+                    }
+                    if let ::core::option::Option::Some(field_expiration) =
+                        self.expiration().transpose()
+                    {
+                        f.field("expiration", &field_expiration);
+                    }
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<FutureContractRef<'a>> for FutureContract {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: FutureContractRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        continuous: ::core::convert::TryInto::try_into(value.continuous()?)?,
+                        month: value.month()?.map(::core::convert::Into::into),
+                        year: value.year()?.map(::core::convert::Into::into),
+                        expiration: value.expiration()?.map(::core::convert::Into::into),
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for FutureContractRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for FutureContractRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[FutureContractRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<FutureContract>> for FutureContract {
+                type Value = ::planus::Offset<FutureContract>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<FutureContract>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for FutureContractRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[FutureContractRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `Symbol` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Table `Symbol` in the file `schema/symbols.fbs:90`
+            #[derive(
+                Clone,
+                Debug,
+                PartialEq,
+                PartialOrd,
+                Eq,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            pub struct Symbol {
+                /// The field `symbol_type` in the table `Symbol`
+                pub symbol_type: self::SymbolType,
+                /// The field `original_symbol` in the table `Symbol`
+                pub original_symbol: ::planus::alloc::string::String,
+                /// The field `underlying_symbol` in the table `Symbol`
+                pub underlying_symbol: ::planus::alloc::string::String,
+                /// The field `symbol_modifier` in the table `Symbol`
+                pub symbol_modifier: ::core::option::Option<::planus::alloc::string::String>,
+                /// The field `option_contract` in the table `Symbol`
+                pub option_contract:
+                    ::core::option::Option<::planus::alloc::boxed::Box<self::OptionContract>>,
+                /// The field `future_contract` in the table `Symbol`
+                pub future_contract:
+                    ::core::option::Option<::planus::alloc::boxed::Box<self::FutureContract>>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for Symbol {
+                fn default() -> Self {
+                    Self {
+                        symbol_type: self::SymbolType::Unknown,
+                        original_symbol: ::core::default::Default::default(),
+                        underlying_symbol: ::core::default::Default::default(),
+                        symbol_modifier: ::core::default::Default::default(),
+                        option_contract: ::core::default::Default::default(),
+                        future_contract: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl Symbol {
+                /// Creates a [SymbolBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> SymbolBuilder<()> {
+                    SymbolBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_symbol_type: impl ::planus::WriteAsDefault<self::SymbolType, self::SymbolType>,
+                    field_original_symbol: impl ::planus::WriteAs<::planus::Offset<str>>,
+                    field_underlying_symbol: impl ::planus::WriteAs<::planus::Offset<str>>,
+                    field_symbol_modifier: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<::core::primitive::str>,
+                    >,
+                    field_option_contract: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<self::OptionContract>,
+                    >,
+                    field_future_contract: impl ::planus::WriteAsOptional<
+                        ::planus::Offset<self::FutureContract>,
+                    >,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_symbol_type =
+                        field_symbol_type.prepare(builder, &self::SymbolType::Unknown);
+                    let prepared_original_symbol = field_original_symbol.prepare(builder);
+                    let prepared_underlying_symbol = field_underlying_symbol.prepare(builder);
+                    let prepared_symbol_modifier = field_symbol_modifier.prepare(builder);
+                    let prepared_option_contract = field_option_contract.prepare(builder);
+                    let prepared_future_contract = field_future_contract.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<16> =
+                        ::core::default::Default::default();
+                    table_writer.write_entry::<::planus::Offset<str>>(1);
+                    table_writer.write_entry::<::planus::Offset<str>>(2);
+                    if prepared_symbol_modifier.is_some() {
+                        table_writer.write_entry::<::planus::Offset<str>>(3);
+                    }
+                    if prepared_option_contract.is_some() {
+                        table_writer.write_entry::<::planus::Offset<self::OptionContract>>(4);
+                    }
+                    if prepared_future_contract.is_some() {
+                        table_writer.write_entry::<::planus::Offset<self::FutureContract>>(5);
+                    }
+                    if prepared_symbol_type.is_some() {
+                        table_writer.write_entry::<self::SymbolType>(0);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            object_writer.write::<_, _, 4>(&prepared_original_symbol);
+                            object_writer.write::<_, _, 4>(&prepared_underlying_symbol);
+                            if let ::core::option::Option::Some(prepared_symbol_modifier) =
+                                prepared_symbol_modifier
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_symbol_modifier);
+                            }
+                            if let ::core::option::Option::Some(prepared_option_contract) =
+                                prepared_option_contract
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_option_contract);
+                            }
+                            if let ::core::option::Option::Some(prepared_future_contract) =
+                                prepared_future_contract
+                            {
+                                object_writer.write::<_, _, 4>(&prepared_future_contract);
+                            }
+                            if let ::core::option::Option::Some(prepared_symbol_type) =
+                                prepared_symbol_type
+                            {
+                                object_writer.write::<_, _, 1>(&prepared_symbol_type);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<Symbol>> for Symbol {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Symbol> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<Symbol>> for Symbol {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Symbol>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Symbol> for Symbol {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Symbol> {
+                    Symbol::create(
+                        builder,
+                        self.symbol_type,
+                        &self.original_symbol,
+                        &self.underlying_symbol,
+                        &self.symbol_modifier,
+                        &self.option_contract,
+                        &self.future_contract,
+                    )
+                }
+            }
+
+            /// Builder for serializing an instance of the [Symbol] type.
+            ///
+            /// Can be created using the [Symbol::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct SymbolBuilder<State>(State);
+
+            impl SymbolBuilder<()> {
+                /// Setter for the [`symbol_type` field](Symbol#structfield.symbol_type).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol_type<T0>(self, value: T0) -> SymbolBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAsDefault<self::SymbolType, self::SymbolType>,
+                {
+                    SymbolBuilder((value,))
+                }
+
+                /// Sets the [`symbol_type` field](Symbol#structfield.symbol_type) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol_type_as_default(self) -> SymbolBuilder<(::planus::DefaultValue,)> {
+                    self.symbol_type(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0> SymbolBuilder<(T0,)> {
+                /// Setter for the [`original_symbol` field](Symbol#structfield.original_symbol).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn original_symbol<T1>(self, value: T1) -> SymbolBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAs<::planus::Offset<str>>,
+                {
+                    let (v0,) = self.0;
+                    SymbolBuilder((v0, value))
+                }
+            }
+
+            impl<T0, T1> SymbolBuilder<(T0, T1)> {
+                /// Setter for the [`underlying_symbol` field](Symbol#structfield.underlying_symbol).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn underlying_symbol<T2>(self, value: T2) -> SymbolBuilder<(T0, T1, T2)>
+                where
+                    T2: ::planus::WriteAs<::planus::Offset<str>>,
+                {
+                    let (v0, v1) = self.0;
+                    SymbolBuilder((v0, v1, value))
+                }
+            }
+
+            impl<T0, T1, T2> SymbolBuilder<(T0, T1, T2)> {
+                /// Setter for the [`symbol_modifier` field](Symbol#structfield.symbol_modifier).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol_modifier<T3>(self, value: T3) -> SymbolBuilder<(T0, T1, T2, T3)>
+                where
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                {
+                    let (v0, v1, v2) = self.0;
+                    SymbolBuilder((v0, v1, v2, value))
+                }
+
+                /// Sets the [`symbol_modifier` field](Symbol#structfield.symbol_modifier) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol_modifier_as_null(self) -> SymbolBuilder<(T0, T1, T2, ())> {
+                    self.symbol_modifier(())
+                }
+            }
+
+            impl<T0, T1, T2, T3> SymbolBuilder<(T0, T1, T2, T3)> {
+                /// Setter for the [`option_contract` field](Symbol#structfield.option_contract).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn option_contract<T4>(self, value: T4) -> SymbolBuilder<(T0, T1, T2, T3, T4)>
+                where
+                    T4: ::planus::WriteAsOptional<::planus::Offset<self::OptionContract>>,
+                {
+                    let (v0, v1, v2, v3) = self.0;
+                    SymbolBuilder((v0, v1, v2, v3, value))
+                }
+
+                /// Sets the [`option_contract` field](Symbol#structfield.option_contract) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn option_contract_as_null(self) -> SymbolBuilder<(T0, T1, T2, T3, ())> {
+                    self.option_contract(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4> SymbolBuilder<(T0, T1, T2, T3, T4)> {
+                /// Setter for the [`future_contract` field](Symbol#structfield.future_contract).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn future_contract<T5>(
+                    self,
+                    value: T5,
+                ) -> SymbolBuilder<(T0, T1, T2, T3, T4, T5)>
+                where
+                    T5: ::planus::WriteAsOptional<::planus::Offset<self::FutureContract>>,
+                {
+                    let (v0, v1, v2, v3, v4) = self.0;
+                    SymbolBuilder((v0, v1, v2, v3, v4, value))
+                }
+
+                /// Sets the [`future_contract` field](Symbol#structfield.future_contract) to null.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn future_contract_as_null(self) -> SymbolBuilder<(T0, T1, T2, T3, T4, ())> {
+                    self.future_contract(())
+                }
+            }
+
+            impl<T0, T1, T2, T3, T4, T5> SymbolBuilder<(T0, T1, T2, T3, T4, T5)> {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Symbol].
+                #[inline]
+                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Symbol>
+                where
+                    Self: ::planus::WriteAsOffset<Symbol>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<self::SymbolType, self::SymbolType>,
+                    T1: ::planus::WriteAs<::planus::Offset<str>>,
+                    T2: ::planus::WriteAs<::planus::Offset<str>>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<self::OptionContract>>,
+                    T5: ::planus::WriteAsOptional<::planus::Offset<self::FutureContract>>,
+                > ::planus::WriteAs<::planus::Offset<Symbol>>
+                for SymbolBuilder<(T0, T1, T2, T3, T4, T5)>
+            {
+                type Prepared = ::planus::Offset<Symbol>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Symbol> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<self::SymbolType, self::SymbolType>,
+                    T1: ::planus::WriteAs<::planus::Offset<str>>,
+                    T2: ::planus::WriteAs<::planus::Offset<str>>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<self::OptionContract>>,
+                    T5: ::planus::WriteAsOptional<::planus::Offset<self::FutureContract>>,
+                > ::planus::WriteAsOptional<::planus::Offset<Symbol>>
+                for SymbolBuilder<(T0, T1, T2, T3, T4, T5)>
+            {
+                type Prepared = ::planus::Offset<Symbol>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Symbol>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAsDefault<self::SymbolType, self::SymbolType>,
+                    T1: ::planus::WriteAs<::planus::Offset<str>>,
+                    T2: ::planus::WriteAs<::planus::Offset<str>>,
+                    T3: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                    T4: ::planus::WriteAsOptional<::planus::Offset<self::OptionContract>>,
+                    T5: ::planus::WriteAsOptional<::planus::Offset<self::FutureContract>>,
+                > ::planus::WriteAsOffset<Symbol> for SymbolBuilder<(T0, T1, T2, T3, T4, T5)>
+            {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Symbol> {
+                    let (v0, v1, v2, v3, v4, v5) = &self.0;
+                    Symbol::create(builder, v0, v1, v2, v3, v4, v5)
+                }
+            }
+
+            /// Reference to a deserialized [Symbol].
+            #[derive(Copy, Clone)]
+            pub struct SymbolRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> SymbolRef<'a> {
+                /// Getter for the [`symbol_type` field](Symbol#structfield.symbol_type).
+                #[inline]
+                pub fn symbol_type(&self) -> ::planus::Result<self::SymbolType> {
+                    ::core::result::Result::Ok(
+                        self.0
+                            .access(0, "Symbol", "symbol_type")?
+                            .unwrap_or(self::SymbolType::Unknown),
+                    )
+                }
+
+                /// Getter for the [`original_symbol` field](Symbol#structfield.original_symbol).
+                #[inline]
+                pub fn original_symbol(&self) -> ::planus::Result<&'a ::core::primitive::str> {
+                    self.0.access_required(1, "Symbol", "original_symbol")
+                }
+
+                /// Getter for the [`underlying_symbol` field](Symbol#structfield.underlying_symbol).
+                #[inline]
+                pub fn underlying_symbol(&self) -> ::planus::Result<&'a ::core::primitive::str> {
+                    self.0.access_required(2, "Symbol", "underlying_symbol")
+                }
+
+                /// Getter for the [`symbol_modifier` field](Symbol#structfield.symbol_modifier).
+                #[inline]
+                pub fn symbol_modifier(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>>
+                {
+                    self.0.access(3, "Symbol", "symbol_modifier")
+                }
+
+                /// Getter for the [`option_contract` field](Symbol#structfield.option_contract).
+                #[inline]
+                pub fn option_contract(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<self::OptionContractRef<'a>>>
+                {
+                    self.0.access(4, "Symbol", "option_contract")
+                }
+
+                /// Getter for the [`future_contract` field](Symbol#structfield.future_contract).
+                #[inline]
+                pub fn future_contract(
+                    &self,
+                ) -> ::planus::Result<::core::option::Option<self::FutureContractRef<'a>>>
+                {
+                    self.0.access(5, "Symbol", "future_contract")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for SymbolRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("SymbolRef");
+                    f.field("symbol_type", &self.symbol_type());
+                    f.field("original_symbol", &self.original_symbol());
+                    f.field("underlying_symbol", &self.underlying_symbol());
+                    if let ::core::option::Option::Some(field_symbol_modifier) =
+                        self.symbol_modifier().transpose()
+                    {
+                        f.field("symbol_modifier", &field_symbol_modifier);
+                    }
+                    if let ::core::option::Option::Some(field_option_contract) =
+                        self.option_contract().transpose()
+                    {
+                        f.field("option_contract", &field_option_contract);
+                    }
+                    if let ::core::option::Option::Some(field_future_contract) =
+                        self.future_contract().transpose()
+                    {
+                        f.field("future_contract", &field_future_contract);
+                    }
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<SymbolRef<'a>> for Symbol {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: SymbolRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        symbol_type: ::core::convert::TryInto::try_into(value.symbol_type()?)?,
+                        original_symbol: ::core::convert::Into::into(value.original_symbol()?),
+                        underlying_symbol: ::core::convert::Into::into(value.underlying_symbol()?),
+                        symbol_modifier: value.symbol_modifier()?.map(::core::convert::Into::into),
+                        option_contract: if let ::core::option::Option::Some(option_contract) =
+                            value.option_contract()?
+                        {
+                            ::core::option::Option::Some(::planus::alloc::boxed::Box::new(
+                                ::core::convert::TryInto::try_into(option_contract)?,
+                            ))
+                        } else {
+                            ::core::option::Option::None
+                        },
+                        future_contract: if let ::core::option::Option::Some(future_contract) =
+                            value.future_contract()?
+                        {
+                            ::core::option::Option::Some(::planus::alloc::boxed::Box::new(
+                                ::core::convert::TryInto::try_into(future_contract)?,
+                            ))
+                        } else {
+                            ::core::option::Option::None
+                        },
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for SymbolRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for SymbolRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[SymbolRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<Symbol>> for Symbol {
+                type Value = ::planus::Offset<Symbol>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Symbol>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for SymbolRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[SymbolRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `SpreadLeg` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Table `SpreadLeg` in the file `schema/symbols.fbs:100`
+            #[derive(
+                Clone,
+                Debug,
+                PartialEq,
+                PartialOrd,
+                Eq,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            pub struct SpreadLeg {
+                /// The field `symbol` in the table `SpreadLeg`
+                pub symbol: ::planus::alloc::boxed::Box<self::Symbol>,
+                /// The field `ratio` in the table `SpreadLeg`
+                pub ratio: i32,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for SpreadLeg {
+                fn default() -> Self {
+                    Self {
+                        symbol: ::core::default::Default::default(),
+                        ratio: 0,
+                    }
+                }
+            }
+
+            impl SpreadLeg {
+                /// Creates a [SpreadLegBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> SpreadLegBuilder<()> {
+                    SpreadLegBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_symbol: impl ::planus::WriteAs<::planus::Offset<self::Symbol>>,
+                    field_ratio: impl ::planus::WriteAsDefault<i32, i32>,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_symbol = field_symbol.prepare(builder);
+                    let prepared_ratio = field_ratio.prepare(builder, &0);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<8> =
+                        ::core::default::Default::default();
+                    table_writer.write_entry::<::planus::Offset<self::Symbol>>(0);
+                    if prepared_ratio.is_some() {
+                        table_writer.write_entry::<i32>(1);
+                    }
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            object_writer.write::<_, _, 4>(&prepared_symbol);
+                            if let ::core::option::Option::Some(prepared_ratio) = prepared_ratio {
+                                object_writer.write::<_, _, 4>(&prepared_ratio);
+                            }
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<SpreadLeg>> for SpreadLeg {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<SpreadLeg> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<SpreadLeg>> for SpreadLeg {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<SpreadLeg>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<SpreadLeg> for SpreadLeg {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<SpreadLeg> {
+                    SpreadLeg::create(builder, &self.symbol, self.ratio)
+                }
+            }
+
+            /// Builder for serializing an instance of the [SpreadLeg] type.
+            ///
+            /// Can be created using the [SpreadLeg::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct SpreadLegBuilder<State>(State);
+
+            impl SpreadLegBuilder<()> {
+                /// Setter for the [`symbol` field](SpreadLeg#structfield.symbol).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn symbol<T0>(self, value: T0) -> SpreadLegBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAs<::planus::Offset<self::Symbol>>,
+                {
+                    SpreadLegBuilder((value,))
+                }
+            }
+
+            impl<T0> SpreadLegBuilder<(T0,)> {
+                /// Setter for the [`ratio` field](SpreadLeg#structfield.ratio).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ratio<T1>(self, value: T1) -> SpreadLegBuilder<(T0, T1)>
+                where
+                    T1: ::planus::WriteAsDefault<i32, i32>,
+                {
+                    let (v0,) = self.0;
+                    SpreadLegBuilder((v0, value))
+                }
+
+                /// Sets the [`ratio` field](SpreadLeg#structfield.ratio) to the default value.
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn ratio_as_default(self) -> SpreadLegBuilder<(T0, ::planus::DefaultValue)> {
+                    self.ratio(::planus::DefaultValue)
+                }
+            }
+
+            impl<T0, T1> SpreadLegBuilder<(T0, T1)> {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [SpreadLeg].
+                #[inline]
+                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<SpreadLeg>
+                where
+                    Self: ::planus::WriteAsOffset<SpreadLeg>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<::planus::Offset<self::Symbol>>,
+                    T1: ::planus::WriteAsDefault<i32, i32>,
+                > ::planus::WriteAs<::planus::Offset<SpreadLeg>> for SpreadLegBuilder<(T0, T1)>
+            {
+                type Prepared = ::planus::Offset<SpreadLeg>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<SpreadLeg> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<::planus::Offset<self::Symbol>>,
+                    T1: ::planus::WriteAsDefault<i32, i32>,
+                > ::planus::WriteAsOptional<::planus::Offset<SpreadLeg>>
+                for SpreadLegBuilder<(T0, T1)>
+            {
+                type Prepared = ::planus::Offset<SpreadLeg>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<SpreadLeg>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<
+                    T0: ::planus::WriteAs<::planus::Offset<self::Symbol>>,
+                    T1: ::planus::WriteAsDefault<i32, i32>,
+                > ::planus::WriteAsOffset<SpreadLeg> for SpreadLegBuilder<(T0, T1)>
+            {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<SpreadLeg> {
+                    let (v0, v1) = &self.0;
+                    SpreadLeg::create(builder, v0, v1)
+                }
+            }
+
+            /// Reference to a deserialized [SpreadLeg].
+            #[derive(Copy, Clone)]
+            pub struct SpreadLegRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> SpreadLegRef<'a> {
+                /// Getter for the [`symbol` field](SpreadLeg#structfield.symbol).
+                #[inline]
+                pub fn symbol(&self) -> ::planus::Result<self::SymbolRef<'a>> {
+                    self.0.access_required(0, "SpreadLeg", "symbol")
+                }
+
+                /// Getter for the [`ratio` field](SpreadLeg#structfield.ratio).
+                #[inline]
+                pub fn ratio(&self) -> ::planus::Result<i32> {
+                    ::core::result::Result::Ok(self.0.access(1, "SpreadLeg", "ratio")?.unwrap_or(0))
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for SpreadLegRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("SpreadLegRef");
+                    f.field("symbol", &self.symbol());
+                    f.field("ratio", &self.ratio());
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<SpreadLegRef<'a>> for SpreadLeg {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: SpreadLegRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        symbol: ::planus::alloc::boxed::Box::new(
+                            ::core::convert::TryInto::try_into(value.symbol()?)?,
+                        ),
+                        ratio: ::core::convert::TryInto::try_into(value.ratio()?)?,
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for SpreadLegRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for SpreadLegRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[SpreadLegRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<SpreadLeg>> for SpreadLeg {
+                type Value = ::planus::Offset<SpreadLeg>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<SpreadLeg>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for SpreadLegRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[SpreadLegRef]", "read_as_root", 0)
+                    })
+                }
+            }
+
+            /// The table `Spread` in the namespace `symbols.v1`
+            ///
+            /// Generated from these locations:
+            /// * Table `Spread` in the file `schema/symbols.fbs:105`
+            #[derive(
+                Clone,
+                Debug,
+                PartialEq,
+                PartialOrd,
+                Eq,
+                Ord,
+                Hash,
+                ::serde::Serialize,
+                ::serde::Deserialize,
+            )]
+            pub struct Spread {
+                /// The field `legs` in the table `Spread`
+                pub legs: ::planus::alloc::vec::Vec<self::SpreadLeg>,
+            }
+
+            #[allow(clippy::derivable_impls)]
+            impl ::core::default::Default for Spread {
+                fn default() -> Self {
+                    Self {
+                        legs: ::core::default::Default::default(),
+                    }
+                }
+            }
+
+            impl Spread {
+                /// Creates a [SpreadBuilder] for serializing an instance of this table.
+                #[inline]
+                pub fn builder() -> SpreadBuilder<()> {
+                    SpreadBuilder(())
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn create(
+                    builder: &mut ::planus::Builder,
+                    field_legs: impl ::planus::WriteAs<
+                        ::planus::Offset<[::planus::Offset<self::SpreadLeg>]>,
+                    >,
+                ) -> ::planus::Offset<Self> {
+                    let prepared_legs = field_legs.prepare(builder);
+
+                    let mut table_writer: ::planus::table_writer::TableWriter<6> =
+                        ::core::default::Default::default();
+                    table_writer
+                        .write_entry::<::planus::Offset<[::planus::Offset<self::SpreadLeg>]>>(0);
+
+                    unsafe {
+                        table_writer.finish(builder, |object_writer| {
+                            object_writer.write::<_, _, 4>(&prepared_legs);
+                        });
+                    }
+                    builder.current_offset()
+                }
+            }
+
+            impl ::planus::WriteAs<::planus::Offset<Spread>> for Spread {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Spread> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl ::planus::WriteAsOptional<::planus::Offset<Spread>> for Spread {
+                type Prepared = ::planus::Offset<Self>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Spread>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl ::planus::WriteAsOffset<Spread> for Spread {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Spread> {
+                    Spread::create(builder, &self.legs)
+                }
+            }
+
+            /// Builder for serializing an instance of the [Spread] type.
+            ///
+            /// Can be created using the [Spread::builder] method.
+            #[derive(Debug)]
+            #[must_use]
+            pub struct SpreadBuilder<State>(State);
+
+            impl SpreadBuilder<()> {
+                /// Setter for the [`legs` field](Spread#structfield.legs).
+                #[inline]
+                #[allow(clippy::type_complexity)]
+                pub fn legs<T0>(self, value: T0) -> SpreadBuilder<(T0,)>
+                where
+                    T0: ::planus::WriteAs<::planus::Offset<[::planus::Offset<self::SpreadLeg>]>>,
+                {
+                    SpreadBuilder((value,))
+                }
+            }
+
+            impl<T0> SpreadBuilder<(T0,)> {
+                /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [Spread].
+                #[inline]
+                pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<Spread>
+                where
+                    Self: ::planus::WriteAsOffset<Spread>,
+                {
+                    ::planus::WriteAsOffset::prepare(&self, builder)
+                }
+            }
+
+            impl<T0: ::planus::WriteAs<::planus::Offset<[::planus::Offset<self::SpreadLeg>]>>>
+                ::planus::WriteAs<::planus::Offset<Spread>> for SpreadBuilder<(T0,)>
+            {
+                type Prepared = ::planus::Offset<Spread>;
+
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Spread> {
+                    ::planus::WriteAsOffset::prepare(self, builder)
+                }
+            }
+
+            impl<T0: ::planus::WriteAs<::planus::Offset<[::planus::Offset<self::SpreadLeg>]>>>
+                ::planus::WriteAsOptional<::planus::Offset<Spread>> for SpreadBuilder<(T0,)>
+            {
+                type Prepared = ::planus::Offset<Spread>;
+
+                #[inline]
+                fn prepare(
+                    &self,
+                    builder: &mut ::planus::Builder,
+                ) -> ::core::option::Option<::planus::Offset<Spread>> {
+                    ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+                }
+            }
+
+            impl<T0: ::planus::WriteAs<::planus::Offset<[::planus::Offset<self::SpreadLeg>]>>>
+                ::planus::WriteAsOffset<Spread> for SpreadBuilder<(T0,)>
+            {
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Spread> {
+                    let (v0,) = &self.0;
+                    Spread::create(builder, v0)
+                }
+            }
+
+            /// Reference to a deserialized [Spread].
+            #[derive(Copy, Clone)]
+            pub struct SpreadRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+            impl<'a> SpreadRef<'a> {
+                /// Getter for the [`legs` field](Spread#structfield.legs).
+                #[inline]
+                pub fn legs(
+                    &self,
+                ) -> ::planus::Result<::planus::Vector<'a, ::planus::Result<self::SpreadLegRef<'a>>>>
+                {
+                    self.0.access_required(0, "Spread", "legs")
+                }
+            }
+
+            impl<'a> ::core::fmt::Debug for SpreadRef<'a> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut f = f.debug_struct("SpreadRef");
+                    f.field("legs", &self.legs());
+                    f.finish()
+                }
+            }
+
+            impl<'a> ::core::convert::TryFrom<SpreadRef<'a>> for Spread {
+                type Error = ::planus::Error;
+
+                #[allow(unreachable_code)]
+                fn try_from(value: SpreadRef<'a>) -> ::planus::Result<Self> {
+                    ::core::result::Result::Ok(Self {
+                        legs: value.legs()?.to_vec_result()?,
+                    })
+                }
+            }
+
+            impl<'a> ::planus::TableRead<'a> for SpreadRef<'a> {
+                #[inline]
+                fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                    ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                        buffer, offset,
+                    )?))
+                }
+            }
+
+            impl<'a> ::planus::VectorReadInner<'a> for SpreadRef<'a> {
+                type Error = ::planus::Error;
+                const STRIDE: usize = 4;
+
+                unsafe fn from_buffer(
+                    buffer: ::planus::SliceWithStartOffset<'a>,
+                    offset: usize,
+                ) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                        error_kind.with_error_location(
+                            "[SpreadRef]",
+                            "get",
+                            buffer.offset_from_start,
+                        )
+                    })
+                }
+            }
+
+            /// # Safety
+            /// The planus compiler generates implementations that initialize
+            /// the bytes in `write_values`.
+            unsafe impl ::planus::VectorWrite<::planus::Offset<Spread>> for Spread {
+                type Value = ::planus::Offset<Spread>;
+                const STRIDE: usize = 4;
+                #[inline]
+                fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                    ::planus::WriteAs::prepare(self, builder)
+                }
+
+                #[inline]
+                unsafe fn write_values(
+                    values: &[::planus::Offset<Spread>],
+                    bytes: *mut ::core::mem::MaybeUninit<u8>,
+                    buffer_position: u32,
+                ) {
+                    let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                    for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                        ::planus::WriteAsPrimitive::write(
+                            v,
+                            ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                            buffer_position - (Self::STRIDE * i) as u32,
+                        );
+                    }
+                }
+            }
+
+            impl<'a> ::planus::ReadAsRoot<'a> for SpreadRef<'a> {
+                fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                    ::planus::TableRead::from_buffer(
+                        ::planus::SliceWithStartOffset {
+                            buffer: slice,
+                            offset_from_start: 0,
+                        },
+                        0,
+                    )
+                    .map_err(|error_kind| {
+                        error_kind.with_error_location("[SpreadRef]", "read_as_root", 0)
+                    })
+                }
+            }
+        }
+    }
+}
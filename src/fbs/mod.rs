@@ -0,0 +1,241 @@
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use planus::{Builder, ReadAsRoot};
+use rust_decimal::Decimal;
+
+use crate::schema::SchemaError;
+use crate::symbol::{
+    Deliverable, ExerciseStyle, ExpirationSeries, FutureContract, OptionContract, PutOrCall, SettlementTime,
+    SettlementType, Spread, SpreadLeg, Symbol, SymbolType,
+};
+
+// FlatBuffers tables for schema/symbols.fbs. generated.rs is written by the planus CLI,
+// rerun it after changing the .fbs:
+//
+//   planus rust -o src/fbs/generated.rs schema/symbols.fbs
+#[allow(clippy::all, dead_code, unused_imports)]
+mod generated;
+
+pub use generated::symbols::v1;
+
+fn invalid(message: String) -> SchemaError {
+    SchemaError { version: None, message }
+}
+
+pub fn encode(symbol: &Symbol) -> Vec<u8> {
+    Builder::new().finish(v1::Symbol::from(symbol), None).to_vec()
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Symbol, SchemaError> {
+    let symbol = v1::SymbolRef::read_as_root(bytes).map_err(|err| invalid(err.to_string()))?;
+    v1::Symbol::try_from(symbol).map_err(|err| invalid(err.to_string()))?.try_into()
+}
+
+pub fn encode_spread(spread: &Spread) -> Vec<u8> {
+    Builder::new().finish(v1::Spread::from(spread), None).to_vec()
+}
+
+pub fn decode_spread(bytes: &[u8]) -> Result<Spread, SchemaError> {
+    let spread = v1::SpreadRef::read_as_root(bytes).map_err(|err| invalid(err.to_string()))?;
+    v1::Spread::try_from(spread).map_err(|err| invalid(err.to_string()))?.try_into()
+}
+
+// The 96 bit mantissa split into its low 64 and high 32 bits
+impl From<Decimal> for v1::Decimal {
+    fn from(decimal: Decimal) -> Self {
+        let mantissa = decimal.mantissa().unsigned_abs();
+        v1::Decimal {
+            lo: mantissa as u64,
+            hi: (mantissa >> 64) as u32,
+            scale: decimal.scale() as u8,
+            negative: decimal.is_sign_negative(),
+        }
+    }
+}
+
+fn from_decimal(decimal: v1::Decimal) -> Result<Decimal, SchemaError> {
+    let mantissa = ((decimal.hi as i128) << 64) | decimal.lo as i128;
+    let value = Decimal::try_from_i128_with_scale(mantissa, decimal.scale as u32)
+        .map_err(|err| invalid(format!("decimal with scale {}: {err}", decimal.scale)))?;
+    Ok(if decimal.negative { -value } else { value })
+}
+
+fn date(date: NaiveDate) -> v1::Date {
+    v1::Date {
+        year: date.year(),
+        month: date.month() as u8,
+        day: date.day() as u8,
+    }
+}
+
+fn from_date(date: v1::Date) -> Result<NaiveDate, SchemaError> {
+    NaiveDate::from_ymd_opt(date.year, date.month.into(), date.day.into())
+        .ok_or_else(|| invalid(format!("{}-{}-{} is not a date", date.year, date.month, date.day)))
+}
+
+fn from_time(seconds: u32) -> Result<NaiveTime, SchemaError> {
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0)
+        .ok_or_else(|| invalid(format!("{seconds} seconds is past midnight")))
+}
+
+// The generated enums have the library's variant names
+macro_rules! fbs_enum {
+    ($domain:ident: $fbs:ident { $($variant:ident),* $(,)? }) => {
+        impl From<$domain> for v1::$fbs {
+            fn from(value: $domain) -> Self {
+                match value {
+                    $($domain::$variant => v1::$fbs::$variant),*
+                }
+            }
+        }
+
+        impl From<v1::$fbs> for $domain {
+            fn from(value: v1::$fbs) -> Self {
+                match value {
+                    $(v1::$fbs::$variant => $domain::$variant),*
+                }
+            }
+        }
+    };
+}
+
+fbs_enum!(SymbolType: SymbolType { Stock, Future, FutureOption, StockOption, Unknown });
+fbs_enum!(PutOrCall: PutCall { Put, Call });
+fbs_enum!(ExpirationSeries: ExpirationSeries { Monthly, Weekly, Quarterly, EndOfMonth, Daily });
+fbs_enum!(ExerciseStyle: ExerciseStyle { American, European });
+fbs_enum!(SettlementType: SettlementType { Physical, Cash });
+fbs_enum!(SettlementTime: SettlementTime { Am, Pm });
+
+impl From<&OptionContract> for v1::OptionContract {
+    fn from(option: &OptionContract) -> Self {
+        v1::OptionContract {
+            strike_price: option.strike_price.into(),
+            put_call: option.put_call.into(),
+            date: option.date.map(date),
+            series: option.series.map(Into::into),
+            adjusted: option.adjusted,
+            deliverable: option.deliverable.as_ref().map(|deliverable| {
+                Box::new(v1::Deliverable {
+                    underlying: deliverable.underlying.to_owned(),
+                    multiplier: deliverable.multiplier.into(),
+                    shares: deliverable.shares.into(),
+                    cash: deliverable.cash.into(),
+                })
+            }),
+            exercise_style: option.exercise_style.map(Into::into),
+            settlement_type: option.settlement_type.map(Into::into),
+            settlement_time: option.settlement_time.map(Into::into),
+            expiration_time: option.expiration_time.map(|time| time.num_seconds_from_midnight()),
+            time_zone: option.time_zone.to_owned(),
+            root: Some(option.root.to_owned()),
+        }
+    }
+}
+
+impl TryFrom<v1::OptionContract> for OptionContract {
+    type Error = SchemaError;
+
+    fn try_from(option: v1::OptionContract) -> Result<Self, Self::Error> {
+        Ok(OptionContract {
+            root: option.root.unwrap_or_default(),
+            strike_price: from_decimal(option.strike_price)?,
+            put_call: option.put_call.into(),
+            date: option.date.map(from_date).transpose()?,
+            series: option.series.map(Into::into),
+            adjusted: option.adjusted,
+            deliverable: option
+                .deliverable
+                .map(|deliverable| {
+                    Ok::<_, SchemaError>(Deliverable {
+                        multiplier: from_decimal(deliverable.multiplier)?,
+                        shares: from_decimal(deliverable.shares)?,
+                        cash: from_decimal(deliverable.cash)?,
+                        underlying: deliverable.underlying,
+                    })
+                })
+                .transpose()?,
+            exercise_style: option.exercise_style.map(Into::into),
+            settlement_type: option.settlement_type.map(Into::into),
+            settlement_time: option.settlement_time.map(Into::into),
+            expiration_time: option.expiration_time.map(from_time).transpose()?,
+            time_zone: option.time_zone,
+        })
+    }
+}
+
+impl From<&FutureContract> for v1::FutureContract {
+    fn from(future: &FutureContract) -> Self {
+        v1::FutureContract {
+            continuous: future.continuous,
+            month: future.month.to_owned(),
+            year: future.year.to_owned(),
+            expiration: future.expiration.map(date),
+        }
+    }
+}
+
+impl TryFrom<v1::FutureContract> for FutureContract {
+    type Error = SchemaError;
+
+    fn try_from(future: v1::FutureContract) -> Result<Self, Self::Error> {
+        Ok(FutureContract {
+            continuous: future.continuous,
+            month: future.month,
+            year: future.year,
+            expiration: future.expiration.map(from_date).transpose()?,
+        })
+    }
+}
+
+impl From<&Symbol> for v1::Symbol {
+    fn from(symbol: &Symbol) -> Self {
+        v1::Symbol {
+            symbol_type: symbol.symbol_type.into(),
+            original_symbol: symbol.original_symbol.to_owned(),
+            underlying_symbol: symbol.underlying_symbol.to_owned(),
+            symbol_modifier: symbol.symbol_modifier.to_owned(),
+            option_contract: symbol.option_contract.as_ref().map(|option| Box::new(option.into())),
+            future_contract: symbol.future_contract.as_ref().map(|future| Box::new(future.into())),
+        }
+    }
+}
+
+impl TryFrom<v1::Symbol> for Symbol {
+    type Error = SchemaError;
+
+    fn try_from(symbol: v1::Symbol) -> Result<Self, Self::Error> {
+        Ok(Symbol {
+            symbol_type: symbol.symbol_type.into(),
+            original_symbol: symbol.original_symbol,
+            underlying_symbol: symbol.underlying_symbol,
+            symbol_modifier: symbol.symbol_modifier,
+            option_contract: symbol.option_contract.map(|option| (*option).try_into()).transpose()?,
+            future_contract: symbol.future_contract.map(|future| (*future).try_into()).transpose()?,
+        }
+        .with_option_root())
+    }
+}
+
+impl From<&Spread> for v1::Spread {
+    fn from(spread: &Spread) -> Self {
+        v1::Spread {
+            legs: spread
+                .legs
+                .iter()
+                .map(|leg| v1::SpreadLeg { symbol: Box::new((&leg.symbol).into()), ratio: leg.ratio })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<v1::Spread> for Spread {
+    type Error = SchemaError;
+
+    fn try_from(spread: v1::Spread) -> Result<Self, Self::Error> {
+        let legs = spread
+            .legs
+            .into_iter()
+            .map(|leg| Ok(SpreadLeg { symbol: (*leg.symbol).try_into()?, ratio: leg.ratio }))
+            .collect::<Result<_, SchemaError>>()?;
+        Ok(Spread { legs })
+    }
+}
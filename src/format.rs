@@ -0,0 +1,275 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::Datelike;
+use rust_decimal::prelude::*;
+use serde::Serialize;
+
+use crate::symbol::{PutOrCall, Symbol, SymbolType, FUTURE_MONTHS};
+use crate::symbol_parser::{parse_symbol, ParseError, ParseResult, ParseResultRef};
+
+const OCC_ROOT_WIDTH: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Symbology {
+    // What this crate parses: MSFT 110122C27.5, ESU20, ESU20 C2700, @ES
+    Native,
+    // OCC/OSI 21 character option symbol, root padded to 6: SPY   251219C00650000
+    Occ,
+    // OCC option symbol without the root padding: SPY251219C00650000
+    OccCompact,
+    // CME Globex, one digit years: ESU0, ESU0 C2700
+    Cme,
+}
+
+impl Symbology {
+    pub const ALL: [Symbology; 4] = [Symbology::Native, Symbology::Occ, Symbology::OccCompact, Symbology::Cme];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Symbology::Native => "native",
+            Symbology::Occ => "occ",
+            Symbology::OccCompact => "occ-compact",
+            Symbology::Cme => "cme",
+        }
+    }
+
+    // Whether the raw symbol is written the way this symbology writes it. The grammar
+    // reads all of them, so this only looks at the shape of the parsed tokens.
+    pub fn accepts(&self, raw_symbol: &str, parsed: &ParseResultRef<'_>) -> bool {
+        match (self, parsed) {
+            (Symbology::Native, _) => true,
+            (Symbology::Occ, ParseResult::StockOptionsSym(option)) => {
+                raw_symbol.len() == OCC_ROOT_WIDTH + 15 && option.strike_price.len() == 8
+            }
+            (Symbology::OccCompact, ParseResult::StockOptionsSym(option)) => {
+                !raw_symbol.contains(' ') && option.strike_price.len() == 8
+            }
+            (Symbology::Cme, ParseResult::FutureSym(future)) => future.year.as_ref().is_none_or(|year| year.len() == 1),
+            (Symbology::Cme, ParseResult::FutureOptionsSym(option)) => {
+                option.future_symbol.year.as_ref().is_none_or(|year| year.len() == 1)
+            }
+            (_, ParseResult::UnknownSym(_)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn format(&self, symbol: &Symbol) -> Result<String, FormatError> {
+        let unsupported = || FormatError::unsupported(*self, symbol);
+        // Modifiers keep their separator, .ZERO or :A
+        let modifier = symbol.symbol_modifier.as_deref().unwrap_or_default();
+
+        match (self, symbol.symbol_type) {
+            (_, SymbolType::Stock | SymbolType::Unknown) => Ok(format!("{}{modifier}", symbol.underlying_symbol)),
+            (Symbology::Native | Symbology::Cme, SymbolType::Future) => {
+                Ok(format!("{}{modifier}", self.future(symbol).ok_or_else(unsupported)?))
+            }
+            (Symbology::Native | Symbology::Cme, SymbolType::FutureOption) => {
+                let future = self.future(symbol).ok_or_else(unsupported)?;
+                let option = symbol.option_contract.as_ref().ok_or_else(unsupported)?;
+                Ok(format!("{future}{modifier} {}{}", put_call(option.put_call), option.strike_price.normalize()))
+            }
+            (Symbology::Native, SymbolType::StockOption) => {
+                let option = symbol.option_contract.as_ref().ok_or_else(unsupported)?;
+                let date = option.date.ok_or_else(unsupported)?;
+                Ok(format!(
+                    "{} {}{}{}",
+                    option.root,
+                    date.format("%y%m%d"),
+                    put_call(option.put_call),
+                    option.strike_price.normalize()
+                ))
+            }
+            (Symbology::Occ | Symbology::OccCompact, SymbolType::StockOption) => {
+                let option = symbol.option_contract.as_ref().ok_or_else(unsupported)?;
+                let date = option.date.ok_or_else(unsupported)?;
+                let strike = (option.strike_price * Decimal::ONE_THOUSAND).normalize();
+                if option.root.len() > OCC_ROOT_WIDTH || strike.scale() != 0 || strike >= Decimal::from(100_000_000) {
+                    return Err(unsupported());
+                }
+                let width = if *self == Symbology::Occ { OCC_ROOT_WIDTH } else { 0 };
+                Ok(format!(
+                    "{:<width$}{}{}{:08}",
+                    option.root,
+                    date.format("%y%m%d"),
+                    put_call(option.put_call),
+                    strike
+                ))
+            }
+            _ => Err(unsupported()),
+        }
+    }
+
+    // @ES, ESU20 or ESU0, from the expiration so the year is rewritten to this symbology's width
+    fn future(&self, symbol: &Symbol) -> Option<String> {
+        let future = symbol.future_contract.as_ref()?;
+        let continuous = if future.continuous { "@" } else { "" };
+        let Some(expiration) = future.expiration else {
+            return (future.month.is_none()).then(|| format!("{continuous}{}", symbol.underlying_symbol));
+        };
+        let month = FUTURE_MONTHS[expiration.month0() as usize];
+        let year = match self {
+            Symbology::Cme => format!("{}", expiration.year() % 10),
+            _ => format!("{:02}", expiration.year() % 100),
+        };
+        Some(format!("{continuous}{}{month}{year}", symbol.underlying_symbol))
+    }
+}
+
+impl fmt::Display for Symbology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Symbology {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Symbology::ALL
+            .into_iter()
+            .find(|symbology| symbology.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("unknown symbology {name:?}"))
+    }
+}
+
+fn put_call(put_call: PutOrCall) -> char {
+    match put_call {
+        PutOrCall::Put => 'P',
+        PutOrCall::Call => 'C',
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FormatError {
+    pub input: String,
+    pub message: String,
+}
+
+impl FormatError {
+    fn unsupported(symbology: Symbology, symbol: &Symbol) -> Self {
+        FormatError {
+            input: symbol.original_symbol.to_owned(),
+            message: format!("{:?} symbols cannot be written in the {symbology} symbology", symbol.symbol_type),
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not translate symbol {:?}: {}", self.input, self.message)
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl From<ParseError> for FormatError {
+    fn from(err: ParseError) -> Self {
+        let message = match err.position {
+            Some(position) => format!("{} at position {position}", err.message),
+            None => err.message,
+        };
+        FormatError { input: err.input, message }
+    }
+}
+
+pub fn translate(raw_symbol: &str, from: Symbology, to: Symbology) -> Result<String, FormatError> {
+    let parsed = parse_symbol(raw_symbol)?;
+    if !from.accepts(raw_symbol, &parsed) {
+        return Err(FormatError {
+            input: raw_symbol.to_owned(),
+            message: format!("not written in the {from} symbology"),
+        });
+    }
+    let symbol = Symbol::parse(raw_symbol)?;
+    to.format(&symbol)
+}
+
+// One flat row per input, for tabular outputs. Failed inputs keep their row with only
+// the error filled in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SymbolRecord {
+    pub input: String,
+    pub valid: bool,
+    pub symbol_type: Option<SymbolType>,
+    pub underlying: Option<String>,
+    pub modifier: Option<String>,
+    pub expiration: Option<String>,
+    pub strike: Option<Decimal>,
+    pub put_call: Option<PutOrCall>,
+    pub series: Option<String>,
+    pub continuous: Option<bool>,
+    pub month: Option<String>,
+    pub year: Option<String>,
+    pub error: Option<String>,
+}
+
+impl SymbolRecord {
+    pub const COLUMNS: [&'static str; 13] = [
+        "input",
+        "valid",
+        "symbol_type",
+        "underlying",
+        "modifier",
+        "expiration",
+        "strike",
+        "put_call",
+        "series",
+        "continuous",
+        "month",
+        "year",
+        "error",
+    ];
+
+    pub fn new(input: &str, result: &Result<Symbol, ParseError>) -> Self {
+        let symbol = match result {
+            Ok(symbol) => symbol,
+            Err(err) => {
+                return SymbolRecord {
+                    input: input.to_owned(),
+                    error: Some(err.to_string()),
+                    ..Default::default()
+                }
+            }
+        };
+        let option = symbol.option_contract.as_ref();
+        let future = symbol.future_contract.as_ref();
+        SymbolRecord {
+            input: input.to_owned(),
+            valid: true,
+            symbol_type: Some(symbol.symbol_type),
+            underlying: Some(symbol.underlying_symbol.to_owned()),
+            modifier: symbol.symbol_modifier.to_owned(),
+            expiration: symbol.expiration().map(|date| date.to_string()),
+            strike: option.map(|option| option.strike_price.normalize()),
+            put_call: option.map(|option| option.put_call),
+            series: option.and_then(|option| option.series).map(|series| format!("{series:?}")),
+            continuous: future.map(|future| future.continuous),
+            month: future.and_then(|future| future.month.to_owned()),
+            year: future.and_then(|future| future.year.to_owned()),
+            error: None,
+        }
+    }
+
+    // Cells in COLUMNS order, empty for missing values
+    pub fn cells(&self) -> Vec<String> {
+        fn cell<T: fmt::Debug>(value: &Option<T>) -> String {
+            value.as_ref().map(|value| format!("{value:?}")).unwrap_or_default()
+        }
+        vec![
+            self.input.to_owned(),
+            self.valid.to_string(),
+            cell(&self.symbol_type),
+            self.underlying.to_owned().unwrap_or_default(),
+            self.modifier.to_owned().unwrap_or_default(),
+            self.expiration.to_owned().unwrap_or_default(),
+            self.strike.map(|strike| strike.to_string()).unwrap_or_default(),
+            cell(&self.put_call),
+            self.series.to_owned().unwrap_or_default(),
+            self.continuous.map(|continuous| continuous.to_string()).unwrap_or_default(),
+            self.month.to_owned().unwrap_or_default(),
+            self.year.to_owned().unwrap_or_default(),
+            self.error.to_owned().unwrap_or_default(),
+        ]
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use chrono::{Days, NaiveDate};
+use rust_decimal::prelude::*;
+
+use crate::symbol::{PutOrCall, Symbol, SymbolType, FUTURE_MONTHS};
+use crate::symbol_parser::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SymbolId(u32);
+
+impl SymbolId {
+    pub fn index(self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UnderlyingId(u32);
+
+impl UnderlyingId {
+    pub fn index(self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Default)]
+struct InternerState {
+    ids: HashMap<Arc<Symbol>, SymbolId>,
+    symbols: Vec<(Arc<Symbol>, UnderlyingId)>,
+    underlying_ids: HashMap<Arc<str>, UnderlyingId>,
+    underlyings: Vec<Arc<str>>,
+}
+
+impl InternerState {
+    fn intern_underlying(&mut self, underlying: &str) -> UnderlyingId {
+        if let Some(id) = self.underlying_ids.get(underlying) {
+            return *id;
+        }
+        let id = UnderlyingId(u32::try_from(self.underlyings.len()).expect("too many underlyings interned"));
+        let underlying: Arc<str> = Arc::from(underlying);
+        self.underlyings.push(underlying.clone());
+        self.underlying_ids.insert(underlying, id);
+        id
+    }
+}
+
+// Maps symbols to dense ids, so hot maps can key on a u32 instead of hashing strings.
+// Ids are handed out in insertion order and never reused. Every contract on the same
+// underlying shares one copy of its name, reachable through underlying().
+#[derive(Default)]
+pub struct SymbolInterner {
+    state: RwLock<InternerState>,
+}
+
+impl SymbolInterner {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn intern(&self, symbol: Symbol) -> SymbolId {
+        if let Some(id) = self.get(&symbol) {
+            return id;
+        }
+
+        let mut state = self.write();
+        // Another thread may have interned it between the two locks
+        if let Some(id) = state.ids.get(&symbol) {
+            return *id;
+        }
+        let id = SymbolId(u32::try_from(state.symbols.len()).expect("too many symbols interned"));
+        let underlying = state.intern_underlying(&symbol.underlying_symbol);
+        let symbol = Arc::new(symbol);
+        state.symbols.push((symbol.clone(), underlying));
+        state.ids.insert(symbol, id);
+        id
+    }
+
+    pub fn intern_str(&self, raw_symbol: &str) -> Result<SymbolId, ParseError> {
+        Symbol::parse(raw_symbol).map(|symbol| self.intern(symbol))
+    }
+
+    pub fn get(&self, symbol: &Symbol) -> Option<SymbolId> {
+        self.read().ids.get(symbol).copied()
+    }
+
+    pub fn resolve(&self, id: SymbolId) -> Option<Arc<Symbol>> {
+        let state = self.read();
+        state.symbols.get(id.0 as usize).map(|(symbol, _)| symbol.clone())
+    }
+
+    pub fn underlying_id(&self, id: SymbolId) -> Option<UnderlyingId> {
+        let state = self.read();
+        state.symbols.get(id.0 as usize).map(|(_, underlying)| *underlying)
+    }
+
+    pub fn underlying(&self, id: UnderlyingId) -> Option<Arc<str>> {
+        self.read().underlyings.get(id.0 as usize).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn underlying_count(&self) -> usize {
+        self.read().underlyings.len()
+    }
+
+    // The tables are only ever appended to, so like the registry this keeps going after
+    // a panic poisoned the lock
+    fn read(&self) -> RwLockReadGuard<'_, InternerState> {
+        self.state.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, InternerState> {
+        self.state.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+// Fixed-size key holding the parse-derived fields of a symbol, for when the ids need to
+// agree across processes. Layout, from the least significant bit:
+//
+//   strike x 1000       32 bits
+//   put/call             1 bit, 1 for calls
+//   has option           1 bit
+//   continuous           1 bit
+//   symbol type          3 bits
+//   future month         4 bits, 1-12, 0 for none
+//   future year          7 bits, as written
+//   future year digits   2 bits, 0 for none
+//   expiration date     16 bits, days since 1999-12-31, 0 for none
+//   root                48 bits, up to 8 characters of 6 bits, 0-9 then A-Z, 0 padded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PackedSymbol(u128);
+
+const STRIKE_SHIFT: u32 = 0;
+const PUT_CALL_SHIFT: u32 = 32;
+const OPTION_SHIFT: u32 = 33;
+const CONTINUOUS_SHIFT: u32 = 34;
+const TYPE_SHIFT: u32 = 35;
+const MONTH_SHIFT: u32 = 38;
+const YEAR_SHIFT: u32 = 42;
+const YEAR_DIGITS_SHIFT: u32 = 49;
+const DATE_SHIFT: u32 = 51;
+const ROOT_SHIFT: u32 = 67;
+
+const MAX_ROOT_LEN: usize = 8;
+const ROOT_ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+const SYMBOL_TYPES: [SymbolType; 5] = [
+    SymbolType::Stock,
+    SymbolType::Future,
+    SymbolType::FutureOption,
+    SymbolType::StockOption,
+    SymbolType::Unknown,
+];
+
+fn date_epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1999, 12, 31).unwrap()
+}
+
+impl PackedSymbol {
+    // None when the symbol carries something the layout has no room for: a modifier, a
+    // root longer than 8 characters or outside 0-9A-Z, a strike with more than three
+    // decimals or above 4294967.295, or a date outside 2000-2179
+    pub fn pack(symbol: &Symbol) -> Option<Self> {
+        if symbol.symbol_modifier.is_some() || symbol.root().len() > MAX_ROOT_LEN {
+            return None;
+        }
+
+        let mut root = 0u128;
+        for (index, b) in symbol.root().bytes().enumerate() {
+            let code = ROOT_ALPHABET.iter().position(|c| *c == b)? as u128 + 1;
+            root |= code << (6 * index);
+        }
+
+        let symbol_type = SYMBOL_TYPES.iter().position(|t| *t == symbol.symbol_type)? as u128;
+        let mut packed = (root << ROOT_SHIFT) | (symbol_type << TYPE_SHIFT);
+
+        if let Some(option) = &symbol.option_contract {
+            let strike = (option.strike_price * Decimal::ONE_THOUSAND).normalize();
+            if strike.scale() != 0 {
+                return None;
+            }
+            let strike = strike.to_u32()? as u128;
+            packed |= (strike << STRIKE_SHIFT) | (1 << OPTION_SHIFT);
+            if option.put_call == PutOrCall::Call {
+                packed |= 1 << PUT_CALL_SHIFT;
+            }
+            if let Some(date) = option.date {
+                let days = (date - date_epoch()).num_days();
+                if !(1..=u16::MAX as i64).contains(&days) {
+                    return None;
+                }
+                packed |= (days as u128) << DATE_SHIFT;
+            }
+        }
+
+        if let Some(future) = &symbol.future_contract {
+            if future.continuous {
+                packed |= 1 << CONTINUOUS_SHIFT;
+            }
+            if let Some(month) = &future.month {
+                let month = FUTURE_MONTHS.iter().position(|m| m == month)? as u128 + 1;
+                packed |= month << MONTH_SHIFT;
+            }
+            if let Some(year) = &future.year {
+                if year.is_empty() || year.len() > 2 {
+                    return None;
+                }
+                let value = year.parse::<u8>().ok()? as u128;
+                packed |= (value << YEAR_SHIFT) | ((year.len() as u128) << YEAR_DIGITS_SHIFT);
+            }
+        }
+
+        Some(PackedSymbol(packed))
+    }
+
+    pub fn from_bits(bits: u128) -> Self {
+        PackedSymbol(bits)
+    }
+
+    pub fn bits(self) -> u128 {
+        self.0
+    }
+
+    fn field(self, shift: u32, width: u32) -> u128 {
+        (self.0 >> shift) & ((1 << width) - 1)
+    }
+
+    pub fn symbol_type(self) -> SymbolType {
+        SYMBOL_TYPES.get(self.field(TYPE_SHIFT, 3) as usize).copied().unwrap_or_default()
+    }
+
+    pub fn root(self) -> String {
+        (0..MAX_ROOT_LEN)
+            .map(|index| self.field(ROOT_SHIFT + 6 * index as u32, 6) as usize)
+            .take_while(|code| *code != 0)
+            .filter_map(|code| ROOT_ALPHABET.get(code - 1).map(|b| *b as char))
+            .collect()
+    }
+
+    pub fn strike_price(self) -> Option<Decimal> {
+        (self.field(OPTION_SHIFT, 1) == 1).then(|| Decimal::new(self.field(STRIKE_SHIFT, 32) as i64, 3).normalize())
+    }
+
+    pub fn put_call(self) -> Option<PutOrCall> {
+        (self.field(OPTION_SHIFT, 1) == 1).then(|| match self.field(PUT_CALL_SHIFT, 1) {
+            1 => PutOrCall::Call,
+            _ => PutOrCall::Put,
+        })
+    }
+
+    pub fn date(self) -> Option<NaiveDate> {
+        match self.field(DATE_SHIFT, 16) {
+            0 => None,
+            days => date_epoch().checked_add_days(Days::new(days as u64)),
+        }
+    }
+
+    pub fn continuous(self) -> bool {
+        self.field(CONTINUOUS_SHIFT, 1) == 1
+    }
+
+    pub fn month(self) -> Option<&'static str> {
+        match self.field(MONTH_SHIFT, 4) as usize {
+            0 => None,
+            month => FUTURE_MONTHS.get(month - 1).copied(),
+        }
+    }
+
+    pub fn year(self) -> Option<String> {
+        match self.field(YEAR_DIGITS_SHIFT, 2) as usize {
+            0 => None,
+            digits => Some(format!("{:0digits$}", self.field(YEAR_SHIFT, 7))),
+        }
+    }
+}
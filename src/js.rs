@@ -0,0 +1,196 @@
+use js_sys::Object;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::bulk;
+use crate::cache::LocalSymbolCache;
+use crate::format::{self, FormatError, Symbology};
+use crate::registry;
+use crate::suggest::{suggest, Suggestion};
+use crate::symbol::Symbol;
+use crate::symbol_parser::ParseError;
+
+// The JavaScript API, shared by the WASM package and the Node addon in symbols_node so
+// both hand out the same values. The bindings only move these in and out of JS; both
+// serialize with JSON semantics (None is null, maps are plain objects). The TypeScript
+// interfaces come from the Tsify derives and are copied into types/symbols.d.ts.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Tsify)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    // The input does not parse
+    InvalidSymbol,
+    // The symbol cannot be written in the target symbology, or was not written in the
+    // source one
+    UnsupportedSymbology,
+    UnknownSymbology,
+    // A value passed in from JS does not have the expected shape
+    InvalidArgument,
+}
+
+// Thrown as an Error with these fields set on it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Tsify)]
+pub struct SymbolError {
+    pub code: ErrorCode,
+    pub message: String,
+    pub input: String,
+    pub position: Option<usize>,
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl SymbolError {
+    pub fn new(code: ErrorCode, input: &str, message: String) -> Self {
+        SymbolError {
+            code,
+            message,
+            input: input.to_owned(),
+            position: None,
+            suggestions: Vec::new(),
+        }
+    }
+}
+
+impl From<ParseError> for SymbolError {
+    fn from(err: ParseError) -> Self {
+        SymbolError {
+            position: err.position,
+            ..SymbolError::new(ErrorCode::InvalidSymbol, &err.input, err.to_string())
+        }
+    }
+}
+
+impl From<FormatError> for SymbolError {
+    fn from(err: FormatError) -> Self {
+        SymbolError::new(ErrorCode::UnsupportedSymbology, &err.input, err.to_string())
+    }
+}
+
+// One element of parseMany
+#[derive(Debug, Clone, Serialize, Tsify)]
+#[serde(untagged)]
+pub enum ParseOutcome {
+    Parsed { symbol: Symbol },
+    Failed { error: SymbolError },
+}
+
+pub fn parse(raw_symbol: &str) -> Result<Symbol, SymbolError> {
+    Symbol::parse(raw_symbol).map_err(|err| SymbolError {
+        suggestions: suggest(raw_symbol, Some(&registry::default_registry())),
+        ..err.into()
+    })
+}
+
+// Failures stay in their slot and skip the suggestions, which are too slow for batches
+pub fn parse_many<I>(symbols: I) -> Vec<ParseOutcome>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    bulk::parse_many(symbols)
+        .map(|result| match result {
+            Ok(symbol) => ParseOutcome::Parsed { symbol },
+            Err(err) => ParseOutcome::Failed { error: err.into() },
+        })
+        .collect()
+}
+
+pub fn translate(raw_symbol: &str, from: &str, to: &str) -> Result<String, SymbolError> {
+    format::translate(raw_symbol, symbology(raw_symbol, from)?, symbology(raw_symbol, to)?).map_err(SymbolError::from)
+}
+
+pub fn format(symbol: &Symbol, to: &str) -> Result<String, SymbolError> {
+    Ok(symbology(&symbol.original_symbol, to)?.format(symbol)?)
+}
+
+fn symbology(input: &str, name: &str) -> Result<Symbology, SymbolError> {
+    name.parse().map_err(|message| SymbolError::new(ErrorCode::UnknownSymbology, input, message))
+}
+
+pub(crate) fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|err| throw(SymbolError::new(ErrorCode::InvalidArgument, "", err.to_string())))
+}
+
+fn from_js<T: for<'de> Deserialize<'de>>(value: JsValue) -> Result<T, JsValue> {
+    serde_wasm_bindgen::from_value(value).map_err(|err| throw(SymbolError::new(ErrorCode::InvalidArgument, "", err.to_string())))
+}
+
+pub(crate) fn throw(err: SymbolError) -> JsValue {
+    let error = js_sys::Error::new(&err.message);
+    error.set_name("SymbolError");
+    if let Ok(fields) = to_js(&err) {
+        Object::assign(&error, fields.unchecked_ref());
+    }
+    error.into()
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT: &str = r#"
+export type Symbology = "native" | "occ" | "occ-compact" | "cme";
+"#;
+
+#[wasm_bindgen(js_name = parse, unchecked_return_type = "Symbol")]
+pub fn parse_js(raw_symbol: &str) -> Result<JsValue, JsValue> {
+    to_js(&parse(raw_symbol).map_err(throw)?)
+}
+
+// One outcome per input, in order; a bad symbol does not throw
+#[wasm_bindgen(js_name = parseMany, unchecked_return_type = "ParseOutcome[]")]
+pub fn parse_many_js(#[wasm_bindgen(unchecked_param_type = "string[]")] symbols: JsValue) -> Result<JsValue, JsValue> {
+    let symbols: Vec<String> = from_js(symbols)?;
+    to_js(&parse_many(&symbols))
+}
+
+#[wasm_bindgen(js_name = translate)]
+pub fn translate_js(
+    raw_symbol: &str,
+    #[wasm_bindgen(unchecked_param_type = "Symbology")] from: &str,
+    #[wasm_bindgen(unchecked_param_type = "Symbology")] to: &str,
+) -> Result<String, JsValue> {
+    translate(raw_symbol, from, to).map_err(throw)
+}
+
+#[wasm_bindgen(js_name = format)]
+pub fn format_js(
+    #[wasm_bindgen(unchecked_param_type = "Symbol")] symbol: JsValue,
+    #[wasm_bindgen(unchecked_param_type = "Symbology")] to: &str,
+) -> Result<String, JsValue> {
+    let symbol: Symbol = from_js(symbol)?;
+    format(&symbol, to).map_err(throw)
+}
+
+// Parses through a LocalSymbolCache: a symbol seen before is returned from the cache,
+// anything else is parsed and inserted. Failures throw like parse but, as in parseMany,
+// without suggestions.
+#[wasm_bindgen(js_name = SymbolCache)]
+pub struct SymbolCacheJs {
+    cache: LocalSymbolCache,
+}
+
+#[wasm_bindgen(js_class = SymbolCache)]
+impl SymbolCacheJs {
+    #[wasm_bindgen(constructor)]
+    pub fn new(capacity: usize) -> Self {
+        SymbolCacheJs {
+            cache: LocalSymbolCache::new(capacity),
+        }
+    }
+
+    #[wasm_bindgen(unchecked_return_type = "Symbol")]
+    pub fn parse(&mut self, raw_symbol: &str) -> Result<JsValue, JsValue> {
+        let symbol = self.cache.parse(raw_symbol).map_err(|err| throw(err.into()))?;
+        to_js(&*symbol)
+    }
+
+    #[wasm_bindgen(unchecked_return_type = "CacheStats")]
+    pub fn stats(&self) -> Result<JsValue, JsValue> {
+        to_js(&self.cache.stats())
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
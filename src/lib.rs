@@ -1,2 +1,22 @@
 pub mod symbol_parser;
-pub mod symbol;
\ No newline at end of file
+pub mod symbol;
+pub mod calendar;
+pub mod registry;
+pub mod normalize;
+pub mod suggest;
+pub mod fast_parser;
+pub mod interner;
+pub mod bulk;
+pub mod cache;
+pub mod format;
+pub mod chain;
+pub mod js;
+pub mod schema;
+#[cfg(feature = "arrow")]
+pub mod columnar;
+#[cfg(feature = "protobuf")]
+pub mod proto;
+#[cfg(feature = "flatbuffers")]
+pub mod fbs;
+#[cfg(feature = "serve")]
+pub mod server;
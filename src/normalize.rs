@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+// Rewrites the lenient parser applies before handing the symbol to the grammar,
+// reported in the order they were applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Tsify)]
+pub enum Normalization {
+    // Full-width forms (ＥＳＵ２０) folded to ASCII
+    FullWidth,
+    // Tabs, newlines and other whitespace replaced with spaces
+    Whitespace,
+    // Runs of spaces collapsed into one
+    CollapsedSpaces,
+    // Leading or trailing whitespace removed
+    Trimmed,
+    Uppercased,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized {
+    pub symbol: String,
+    pub applied: Vec<Normalization>,
+}
+
+impl Normalized {
+    pub fn is_unchanged(&self) -> bool {
+        self.applied.is_empty()
+    }
+}
+
+// Full-width ASCII variants live at U+FF01..U+FF5E, offset from ASCII by 0xFEE0.
+// The ideographic space maps to a regular space.
+fn fold_full_width(c: char) -> Option<char> {
+    match c {
+        '\u{3000}' => Some(' '),
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0),
+        _ => None,
+    }
+}
+
+pub fn normalize(raw: &str) -> Normalized {
+    let mut applied = Vec::new();
+    let mut apply = |normalization: Normalization| {
+        if !applied.contains(&normalization) {
+            applied.push(normalization);
+        }
+    };
+
+    let mut symbol = String::with_capacity(raw.len());
+    let mut spaces = 0;
+    for c in raw.chars() {
+        let c = match fold_full_width(c) {
+            Some(folded) => {
+                apply(Normalization::FullWidth);
+                folded
+            }
+            None => c,
+        };
+
+        if c.is_whitespace() {
+            if c != ' ' {
+                apply(Normalization::Whitespace);
+            }
+            spaces += 1;
+            continue;
+        }
+
+        if spaces > 0 {
+            if symbol.is_empty() {
+                apply(Normalization::Trimmed);
+            } else {
+                if spaces > 1 {
+                    apply(Normalization::CollapsedSpaces);
+                }
+                symbol.push(' ');
+            }
+            spaces = 0;
+        }
+
+        if c.is_ascii_lowercase() {
+            apply(Normalization::Uppercased);
+        }
+        symbol.push(c.to_ascii_uppercase());
+    }
+
+    if spaces > 0 {
+        apply(Normalization::Trimmed);
+    }
+
+    Normalized { symbol, applied }
+}
@@ -0,0 +1,275 @@
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use prost::Message;
+use rust_decimal::Decimal;
+
+use crate::schema::SchemaError;
+use crate::symbol::{
+    Deliverable, ExerciseStyle, ExpirationSeries, FutureContract, OptionContract, PutOrCall, SettlementTime,
+    SettlementType, Spread, SpreadLeg, Symbol, SymbolType,
+};
+
+// Protobuf messages for schema/symbols.proto. symbols.v1.rs is generated with
+// prost-build, rerun it after changing the .proto:
+//
+//   prost_build::Config::new().out_dir("src/proto").compile_protos(&["schema/symbols.proto"], &["schema"])
+#[allow(clippy::all)]
+pub mod v1 {
+    include!("symbols.v1.rs");
+}
+
+fn invalid(message: String) -> SchemaError {
+    SchemaError { version: None, message }
+}
+
+pub fn encode(symbol: &Symbol) -> Vec<u8> {
+    v1::Symbol::from(symbol).encode_to_vec()
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Symbol, SchemaError> {
+    let message = v1::Symbol::decode(bytes).map_err(|err| invalid(err.to_string()))?;
+    message.try_into()
+}
+
+pub fn encode_spread(spread: &Spread) -> Vec<u8> {
+    v1::Spread::from(spread).encode_to_vec()
+}
+
+pub fn decode_spread(bytes: &[u8]) -> Result<Spread, SchemaError> {
+    let message = v1::Spread::decode(bytes).map_err(|err| invalid(err.to_string()))?;
+    message.try_into()
+}
+
+fn decimal(field: &str, value: &str) -> Result<Decimal, SchemaError> {
+    value.parse().map_err(|_| invalid(format!("{field} {value:?} is not a decimal")))
+}
+
+fn date(date: NaiveDate) -> v1::Date {
+    v1::Date {
+        year: date.year(),
+        month: date.month(),
+        day: date.day(),
+    }
+}
+
+fn from_date(date: v1::Date) -> Result<NaiveDate, SchemaError> {
+    NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+        .ok_or_else(|| invalid(format!("{}-{}-{} is not a date", date.year, date.month, date.day)))
+}
+
+fn from_time(seconds: u32) -> Result<NaiveTime, SchemaError> {
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0)
+        .ok_or_else(|| invalid(format!("{seconds} seconds is past midnight")))
+}
+
+// The optional enums are UNSPECIFIED when unset
+fn from_enum<T: TryFrom<i32>, U>(field: &str, value: i32, convert: impl FnOnce(T) -> Option<U>) -> Result<Option<U>, SchemaError> {
+    let value = T::try_from(value).map_err(|_| invalid(format!("{value} is not a {field}")))?;
+    Ok(convert(value))
+}
+
+impl From<SymbolType> for v1::SymbolType {
+    fn from(symbol_type: SymbolType) -> Self {
+        match symbol_type {
+            SymbolType::Stock => v1::SymbolType::Stock,
+            SymbolType::Future => v1::SymbolType::Future,
+            SymbolType::FutureOption => v1::SymbolType::FutureOption,
+            SymbolType::StockOption => v1::SymbolType::StockOption,
+            SymbolType::Unknown => v1::SymbolType::Unknown,
+        }
+    }
+}
+
+impl From<v1::SymbolType> for SymbolType {
+    fn from(symbol_type: v1::SymbolType) -> Self {
+        match symbol_type {
+            v1::SymbolType::Stock => SymbolType::Stock,
+            v1::SymbolType::Future => SymbolType::Future,
+            v1::SymbolType::FutureOption => SymbolType::FutureOption,
+            v1::SymbolType::StockOption => SymbolType::StockOption,
+            v1::SymbolType::Unknown => SymbolType::Unknown,
+        }
+    }
+}
+
+impl From<&OptionContract> for v1::OptionContract {
+    fn from(option: &OptionContract) -> Self {
+        v1::OptionContract {
+            strike_price: option.strike_price.to_string(),
+            put_call: match option.put_call {
+                PutOrCall::Put => v1::PutCall::Put,
+                PutOrCall::Call => v1::PutCall::Call,
+            } as i32,
+            date: option.date.map(date),
+            series: match option.series {
+                None => v1::ExpirationSeries::Unspecified,
+                Some(ExpirationSeries::Monthly) => v1::ExpirationSeries::Monthly,
+                Some(ExpirationSeries::Weekly) => v1::ExpirationSeries::Weekly,
+                Some(ExpirationSeries::Quarterly) => v1::ExpirationSeries::Quarterly,
+                Some(ExpirationSeries::EndOfMonth) => v1::ExpirationSeries::EndOfMonth,
+                Some(ExpirationSeries::Daily) => v1::ExpirationSeries::Daily,
+            } as i32,
+            adjusted: option.adjusted,
+            deliverable: option.deliverable.as_ref().map(|deliverable| v1::Deliverable {
+                underlying: deliverable.underlying.to_owned(),
+                multiplier: deliverable.multiplier.to_string(),
+                shares: deliverable.shares.to_string(),
+                cash: deliverable.cash.to_string(),
+            }),
+            exercise_style: match option.exercise_style {
+                None => v1::ExerciseStyle::Unspecified,
+                Some(ExerciseStyle::American) => v1::ExerciseStyle::American,
+                Some(ExerciseStyle::European) => v1::ExerciseStyle::European,
+            } as i32,
+            settlement_type: match option.settlement_type {
+                None => v1::SettlementType::Unspecified,
+                Some(SettlementType::Physical) => v1::SettlementType::Physical,
+                Some(SettlementType::Cash) => v1::SettlementType::Cash,
+            } as i32,
+            settlement_time: match option.settlement_time {
+                None => v1::SettlementTime::Unspecified,
+                Some(SettlementTime::Am) => v1::SettlementTime::Am,
+                Some(SettlementTime::Pm) => v1::SettlementTime::Pm,
+            } as i32,
+            expiration_time: option.expiration_time.map(|time| time.num_seconds_from_midnight()),
+            time_zone: option.time_zone.to_owned(),
+            root: option.root.to_owned(),
+        }
+    }
+}
+
+impl TryFrom<v1::OptionContract> for OptionContract {
+    type Error = SchemaError;
+
+    fn try_from(option: v1::OptionContract) -> Result<Self, Self::Error> {
+        let put_call = from_enum("put/call", option.put_call, |put_call| match put_call {
+            v1::PutCall::Unspecified => None,
+            v1::PutCall::Put => Some(PutOrCall::Put),
+            v1::PutCall::Call => Some(PutOrCall::Call),
+        })?;
+        Ok(OptionContract {
+            root: option.root,
+            strike_price: decimal("strike price", &option.strike_price)?,
+            put_call: put_call.ok_or_else(|| invalid("option contract without put/call".to_owned()))?,
+            date: option.date.map(from_date).transpose()?,
+            series: from_enum("series", option.series, |series| match series {
+                v1::ExpirationSeries::Unspecified => None,
+                v1::ExpirationSeries::Monthly => Some(ExpirationSeries::Monthly),
+                v1::ExpirationSeries::Weekly => Some(ExpirationSeries::Weekly),
+                v1::ExpirationSeries::Quarterly => Some(ExpirationSeries::Quarterly),
+                v1::ExpirationSeries::EndOfMonth => Some(ExpirationSeries::EndOfMonth),
+                v1::ExpirationSeries::Daily => Some(ExpirationSeries::Daily),
+            })?,
+            adjusted: option.adjusted,
+            deliverable: option
+                .deliverable
+                .map(|deliverable| {
+                    Ok::<_, SchemaError>(Deliverable {
+                        multiplier: decimal("multiplier", &deliverable.multiplier)?,
+                        shares: decimal("shares", &deliverable.shares)?,
+                        cash: decimal("cash", &deliverable.cash)?,
+                        underlying: deliverable.underlying,
+                    })
+                })
+                .transpose()?,
+            exercise_style: from_enum("exercise style", option.exercise_style, |style| match style {
+                v1::ExerciseStyle::Unspecified => None,
+                v1::ExerciseStyle::American => Some(ExerciseStyle::American),
+                v1::ExerciseStyle::European => Some(ExerciseStyle::European),
+            })?,
+            settlement_type: from_enum("settlement type", option.settlement_type, |settlement| match settlement {
+                v1::SettlementType::Unspecified => None,
+                v1::SettlementType::Physical => Some(SettlementType::Physical),
+                v1::SettlementType::Cash => Some(SettlementType::Cash),
+            })?,
+            settlement_time: from_enum("settlement time", option.settlement_time, |time| match time {
+                v1::SettlementTime::Unspecified => None,
+                v1::SettlementTime::Am => Some(SettlementTime::Am),
+                v1::SettlementTime::Pm => Some(SettlementTime::Pm),
+            })?,
+            expiration_time: option.expiration_time.map(from_time).transpose()?,
+            time_zone: option.time_zone,
+        })
+    }
+}
+
+impl From<&FutureContract> for v1::FutureContract {
+    fn from(future: &FutureContract) -> Self {
+        v1::FutureContract {
+            continuous: future.continuous,
+            month: future.month.to_owned(),
+            year: future.year.to_owned(),
+            expiration: future.expiration.map(date),
+        }
+    }
+}
+
+impl TryFrom<v1::FutureContract> for FutureContract {
+    type Error = SchemaError;
+
+    fn try_from(future: v1::FutureContract) -> Result<Self, Self::Error> {
+        Ok(FutureContract {
+            continuous: future.continuous,
+            month: future.month,
+            year: future.year,
+            expiration: future.expiration.map(from_date).transpose()?,
+        })
+    }
+}
+
+impl From<&Symbol> for v1::Symbol {
+    fn from(symbol: &Symbol) -> Self {
+        v1::Symbol {
+            symbol_type: v1::SymbolType::from(symbol.symbol_type) as i32,
+            original_symbol: symbol.original_symbol.to_owned(),
+            underlying_symbol: symbol.underlying_symbol.to_owned(),
+            symbol_modifier: symbol.symbol_modifier.to_owned(),
+            option_contract: symbol.option_contract.as_ref().map(Into::into),
+            future_contract: symbol.future_contract.as_ref().map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<v1::Symbol> for Symbol {
+    type Error = SchemaError;
+
+    fn try_from(symbol: v1::Symbol) -> Result<Self, Self::Error> {
+        let symbol_type = from_enum("symbol type", symbol.symbol_type, |symbol_type: v1::SymbolType| Some(symbol_type.into()))?;
+        Ok(Symbol {
+            symbol_type: symbol_type.unwrap_or_default(),
+            original_symbol: symbol.original_symbol,
+            underlying_symbol: symbol.underlying_symbol,
+            symbol_modifier: symbol.symbol_modifier,
+            option_contract: symbol.option_contract.map(TryInto::try_into).transpose()?,
+            future_contract: symbol.future_contract.map(TryInto::try_into).transpose()?,
+        }
+        .with_option_root())
+    }
+}
+
+impl From<&Spread> for v1::Spread {
+    fn from(spread: &Spread) -> Self {
+        v1::Spread {
+            legs: spread
+                .legs
+                .iter()
+                .map(|leg| v1::SpreadLeg { symbol: Some((&leg.symbol).into()), ratio: leg.ratio })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<v1::Spread> for Spread {
+    type Error = SchemaError;
+
+    fn try_from(spread: v1::Spread) -> Result<Self, Self::Error> {
+        let legs = spread
+            .legs
+            .into_iter()
+            .map(|leg| {
+                let symbol = leg.symbol.ok_or_else(|| invalid("spread leg without a symbol".to_owned()))?;
+                Ok(SpreadLeg { symbol: symbol.try_into()?, ratio: leg.ratio })
+            })
+            .collect::<Result<_, SchemaError>>()?;
+        Ok(Spread { legs })
+    }
+}
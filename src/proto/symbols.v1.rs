@@ -0,0 +1,282 @@
+// This file is @generated by prost-build.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Date {
+    #[prost(int32, tag = "1")]
+    pub year: i32,
+    #[prost(uint32, tag = "2")]
+    pub month: u32,
+    #[prost(uint32, tag = "3")]
+    pub day: u32,
+}
+/// Decimals are strings, "27.5", exact like the JSON form
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Deliverable {
+    #[prost(string, tag = "1")]
+    pub underlying: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub multiplier: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub shares: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub cash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OptionContract {
+    #[prost(string, tag = "1")]
+    pub strike_price: ::prost::alloc::string::String,
+    #[prost(enumeration = "PutCall", tag = "2")]
+    pub put_call: i32,
+    #[prost(message, optional, tag = "3")]
+    pub date: ::core::option::Option<Date>,
+    #[prost(enumeration = "ExpirationSeries", tag = "4")]
+    pub series: i32,
+    #[prost(bool, tag = "5")]
+    pub adjusted: bool,
+    #[prost(message, optional, tag = "6")]
+    pub deliverable: ::core::option::Option<Deliverable>,
+    #[prost(enumeration = "ExerciseStyle", tag = "7")]
+    pub exercise_style: i32,
+    #[prost(enumeration = "SettlementType", tag = "8")]
+    pub settlement_type: i32,
+    #[prost(enumeration = "SettlementTime", tag = "9")]
+    pub settlement_time: i32,
+    /// Seconds after midnight in time_zone
+    #[prost(uint32, optional, tag = "10")]
+    pub expiration_time: ::core::option::Option<u32>,
+    /// IANA zone name
+    #[prost(string, optional, tag = "11")]
+    pub time_zone: ::core::option::Option<::prost::alloc::string::String>,
+    /// Root the option is listed under, AAPL1 or SPXW. Empty in messages written before it was
+    /// added, where it is the underlying.
+    #[prost(string, tag = "12")]
+    pub root: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FutureContract {
+    #[prost(bool, tag = "1")]
+    pub continuous: bool,
+    #[prost(string, optional, tag = "2")]
+    pub month: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub year: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "4")]
+    pub expiration: ::core::option::Option<Date>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Symbol {
+    #[prost(enumeration = "SymbolType", tag = "1")]
+    pub symbol_type: i32,
+    #[prost(string, tag = "2")]
+    pub original_symbol: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub underlying_symbol: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub symbol_modifier: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "5")]
+    pub option_contract: ::core::option::Option<OptionContract>,
+    #[prost(message, optional, tag = "6")]
+    pub future_contract: ::core::option::Option<FutureContract>,
+}
+/// Positive ratios are bought, negative ones sold
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpreadLeg {
+    #[prost(message, optional, tag = "1")]
+    pub symbol: ::core::option::Option<Symbol>,
+    #[prost(int32, tag = "2")]
+    pub ratio: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Spread {
+    #[prost(message, repeated, tag = "1")]
+    pub legs: ::prost::alloc::vec::Vec<SpreadLeg>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SymbolType {
+    Unknown = 0,
+    Stock = 1,
+    Future = 2,
+    FutureOption = 3,
+    StockOption = 4,
+}
+impl SymbolType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "SYMBOL_TYPE_UNKNOWN",
+            Self::Stock => "SYMBOL_TYPE_STOCK",
+            Self::Future => "SYMBOL_TYPE_FUTURE",
+            Self::FutureOption => "SYMBOL_TYPE_FUTURE_OPTION",
+            Self::StockOption => "SYMBOL_TYPE_STOCK_OPTION",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SYMBOL_TYPE_UNKNOWN" => Some(Self::Unknown),
+            "SYMBOL_TYPE_STOCK" => Some(Self::Stock),
+            "SYMBOL_TYPE_FUTURE" => Some(Self::Future),
+            "SYMBOL_TYPE_FUTURE_OPTION" => Some(Self::FutureOption),
+            "SYMBOL_TYPE_STOCK_OPTION" => Some(Self::StockOption),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PutCall {
+    Unspecified = 0,
+    Put = 1,
+    Call = 2,
+}
+impl PutCall {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PUT_CALL_UNSPECIFIED",
+            Self::Put => "PUT_CALL_PUT",
+            Self::Call => "PUT_CALL_CALL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PUT_CALL_UNSPECIFIED" => Some(Self::Unspecified),
+            "PUT_CALL_PUT" => Some(Self::Put),
+            "PUT_CALL_CALL" => Some(Self::Call),
+            _ => None,
+        }
+    }
+}
+/// UNSPECIFIED for the optional enums below means the field is not set
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExpirationSeries {
+    Unspecified = 0,
+    Monthly = 1,
+    Weekly = 2,
+    Quarterly = 3,
+    EndOfMonth = 4,
+    Daily = 5,
+}
+impl ExpirationSeries {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "EXPIRATION_SERIES_UNSPECIFIED",
+            Self::Monthly => "EXPIRATION_SERIES_MONTHLY",
+            Self::Weekly => "EXPIRATION_SERIES_WEEKLY",
+            Self::Quarterly => "EXPIRATION_SERIES_QUARTERLY",
+            Self::EndOfMonth => "EXPIRATION_SERIES_END_OF_MONTH",
+            Self::Daily => "EXPIRATION_SERIES_DAILY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EXPIRATION_SERIES_UNSPECIFIED" => Some(Self::Unspecified),
+            "EXPIRATION_SERIES_MONTHLY" => Some(Self::Monthly),
+            "EXPIRATION_SERIES_WEEKLY" => Some(Self::Weekly),
+            "EXPIRATION_SERIES_QUARTERLY" => Some(Self::Quarterly),
+            "EXPIRATION_SERIES_END_OF_MONTH" => Some(Self::EndOfMonth),
+            "EXPIRATION_SERIES_DAILY" => Some(Self::Daily),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExerciseStyle {
+    Unspecified = 0,
+    American = 1,
+    European = 2,
+}
+impl ExerciseStyle {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "EXERCISE_STYLE_UNSPECIFIED",
+            Self::American => "EXERCISE_STYLE_AMERICAN",
+            Self::European => "EXERCISE_STYLE_EUROPEAN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EXERCISE_STYLE_UNSPECIFIED" => Some(Self::Unspecified),
+            "EXERCISE_STYLE_AMERICAN" => Some(Self::American),
+            "EXERCISE_STYLE_EUROPEAN" => Some(Self::European),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SettlementType {
+    Unspecified = 0,
+    Physical = 1,
+    Cash = 2,
+}
+impl SettlementType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SETTLEMENT_TYPE_UNSPECIFIED",
+            Self::Physical => "SETTLEMENT_TYPE_PHYSICAL",
+            Self::Cash => "SETTLEMENT_TYPE_CASH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SETTLEMENT_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SETTLEMENT_TYPE_PHYSICAL" => Some(Self::Physical),
+            "SETTLEMENT_TYPE_CASH" => Some(Self::Cash),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SettlementTime {
+    Unspecified = 0,
+    Am = 1,
+    Pm = 2,
+}
+impl SettlementTime {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SETTLEMENT_TIME_UNSPECIFIED",
+            Self::Am => "SETTLEMENT_TIME_AM",
+            Self::Pm => "SETTLEMENT_TIME_PM",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SETTLEMENT_TIME_UNSPECIFIED" => Some(Self::Unspecified),
+            "SETTLEMENT_TIME_AM" => Some(Self::Am),
+            "SETTLEMENT_TIME_PM" => Some(Self::Pm),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::sync::{RwLock, RwLockReadGuard};
+
+use chrono::prelude::*;
+use chrono::Days;
+use once_cell::sync::Lazy;
+use rust_decimal::prelude::*;
+
+use crate::calendar;
+use crate::symbol::{Deliverable, ExerciseStyle, ExpirationSeries, SettlementTime, SettlementType};
+
+// US equity and index options settle and expire on exchange time
+pub const EXCHANGE_TIME_ZONE: &str = "America/New_York";
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptionTerms {
+    pub exercise_style: ExerciseStyle,
+    pub settlement_type: SettlementType,
+    pub settlement_time: SettlementTime,
+}
+
+impl OptionTerms {
+    pub const EQUITY: OptionTerms = OptionTerms {
+        exercise_style: ExerciseStyle::American,
+        settlement_type: SettlementType::Physical,
+        settlement_time: SettlementTime::Pm,
+    };
+
+    pub const INDEX_AM: OptionTerms = OptionTerms {
+        exercise_style: ExerciseStyle::European,
+        settlement_type: SettlementType::Cash,
+        settlement_time: SettlementTime::Am,
+    };
+
+    pub const INDEX_PM: OptionTerms = OptionTerms {
+        settlement_time: SettlementTime::Pm,
+        ..OptionTerms::INDEX_AM
+    };
+
+    // Options on futures exercise into the future
+    pub const FUTURE: OptionTerms = OptionTerms {
+        settlement_type: SettlementType::Physical,
+        ..OptionTerms::EQUITY
+    };
+
+    // Quarterly options on equity index futures expire with the future and settle in
+    // cash on the special opening quotation
+    pub const INDEX_FUTURE: OptionTerms = OptionTerms {
+        exercise_style: ExerciseStyle::American,
+        ..OptionTerms::INDEX_AM
+    };
+}
+
+#[derive(Debug, Clone)]
+pub struct OptionProduct {
+    pub root: String,
+    pub deliverable: Deliverable,
+    // Alias roots such as SPXW never carry the standard AM-settled monthly, so a
+    // third Friday expiration under them is a weekly like any other Friday.
+    pub weeklys_root: bool,
+    // Mini options are listed under the root with a 7 suffix (AAPL7) and deliver 10
+    // shares. The suffix looks like an adjustment, so they are registered by name.
+    pub mini: bool,
+    pub terms: OptionTerms,
+}
+
+impl OptionProduct {
+    pub fn new(root: &str, deliverable: Deliverable) -> Self {
+        OptionProduct {
+            root: root.to_owned(),
+            deliverable,
+            weeklys_root: false,
+            mini: false,
+            terms: OptionTerms::EQUITY,
+        }
+    }
+
+    // Cash-settled index options deliver no shares
+    pub fn index(root: &str, underlying: &str) -> Self {
+        OptionProduct {
+            terms: OptionTerms::INDEX_AM,
+            ..OptionProduct::new(root, Deliverable {
+                shares: Decimal::ZERO,
+                ..Deliverable::standard(underlying)
+            })
+        }
+    }
+
+    pub fn mini(root: &str, underlying: &str) -> Self {
+        OptionProduct {
+            mini: true,
+            ..OptionProduct::new(root, Deliverable {
+                multiplier: Decimal::TEN,
+                shares: Decimal::TEN,
+                ..Deliverable::standard(underlying)
+            })
+        }
+    }
+
+    pub fn index_weeklys(root: &str, underlying: &str) -> Self {
+        OptionProduct {
+            weeklys_root: true,
+            terms: OptionTerms::INDEX_PM,
+            ..OptionProduct::index(root, underlying)
+        }
+    }
+}
+
+// When the options on a future's contract month expire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FutureOptionExpiration {
+    // Third Friday of the contract month, as for equity index futures
+    ThirdFriday,
+    // Second Friday before the contract month's third Wednesday, as for currency futures
+    SecondFridayBeforeThirdWednesday,
+    // Last Friday at least two trading days before the last trading day of the month
+    // before the contract month, as for Treasury and grain futures
+    LastFridayOfPriorMonth,
+}
+
+impl FutureOptionExpiration {
+    // Expiration of the options on the contract month, the trading day before when the
+    // exchange is closed
+    pub fn expiration(self, year: i32, month: u32) -> Option<NaiveDate> {
+        let date = match self {
+            FutureOptionExpiration::ThirdFriday => calendar::third_friday(year, month)?,
+            FutureOptionExpiration::SecondFridayBeforeThirdWednesday => {
+                NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Wed, 3)? - Days::new(12)
+            }
+            FutureOptionExpiration::LastFridayOfPriorMonth => {
+                let mut date = calendar::roll_back(NaiveDate::from_ymd_opt(year, month, 1)?.pred_opt()?);
+                for _ in 0..2 {
+                    date = calendar::roll_back(date.pred_opt()?);
+                }
+                date - Days::new(u64::from((date.weekday().num_days_from_monday() + 3) % 7))
+            }
+        };
+        Some(calendar::roll_back(date))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FutureProduct {
+    pub root: String,
+    // Month codes the contract lists, e.g. "HMUZ" for the quarterly cycle
+    pub listed_months: String,
+    // None when the options' expiration rule is not known, so no chain can be built
+    pub option_expiration: Option<FutureOptionExpiration>,
+    pub option_terms: OptionTerms,
+}
+
+impl FutureProduct {
+    pub fn new(root: &str, listed_months: &str) -> Self {
+        FutureProduct {
+            root: root.to_owned(),
+            listed_months: listed_months.to_owned(),
+            option_expiration: None,
+            option_terms: OptionTerms::FUTURE,
+        }
+    }
+
+    pub fn with_options(root: &str, listed_months: &str, expiration: FutureOptionExpiration, terms: OptionTerms) -> Self {
+        FutureProduct {
+            option_expiration: Some(expiration),
+            option_terms: terms,
+            ..FutureProduct::new(root, listed_months)
+        }
+    }
+
+    pub fn is_listed_month(&self, month: &str) -> bool {
+        month.len() == 1 && self.listed_months.contains(month)
+    }
+}
+
+#[derive(Debug)]
+pub enum DeliverablesError {
+    Io(io::Error),
+    InvalidLine { line: usize, message: String },
+}
+
+impl fmt::Display for DeliverablesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeliverablesError::Io(err) => write!(f, "could not read deliverables: {err}"),
+            DeliverablesError::InvalidLine { line, message } => write!(f, "invalid deliverable on line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for DeliverablesError {}
+
+impl From<io::Error> for DeliverablesError {
+    fn from(err: io::Error) -> Self {
+        DeliverablesError::Io(err)
+    }
+}
+
+// OCC adjusted roots append a single digit to the original root (AAPL1, GE2).
+// Returns the original root when the given one looks adjusted. Mini option roots look
+// the same; ProductRegistry::is_adjusted tells them apart.
+pub fn adjusted_root(root: &str) -> Option<&str> {
+    let (suffix, base) = root.as_bytes().split_last()?;
+    let is_adjusted = !base.is_empty()
+        && base.iter().all(u8::is_ascii_alphabetic)
+        && (b'1'..=b'9').contains(suffix);
+    is_adjusted.then(|| &root[..base.len()])
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProductRegistry {
+    options: HashMap<String, OptionProduct>,
+    futures: HashMap<String, FutureProduct>,
+}
+
+impl ProductRegistry {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Index option roots and their weeklys aliases, the mini options that were listed,
+    // and the most active futures
+    pub fn with_defaults() -> Self {
+        let mut registry = ProductRegistry::new();
+        for product in [
+            OptionProduct::index("SPX", "SPX"),
+            OptionProduct::index_weeklys("SPXW", "SPX"),
+            OptionProduct { terms: OptionTerms::INDEX_PM, ..OptionProduct::index("XSP", "XSP") },
+            OptionProduct::index("NDX", "NDX"),
+            OptionProduct::index_weeklys("NDXP", "NDX"),
+            OptionProduct::index("RUT", "RUT"),
+            OptionProduct::index_weeklys("RUTW", "RUT"),
+            OptionProduct::index("VIX", "VIX"),
+            // VIX weeklys settle on the special opening quotation like the monthlies
+            OptionProduct { terms: OptionTerms::INDEX_AM, ..OptionProduct::index_weeklys("VIXW", "VIX") },
+            OptionProduct::index("DJX", "DJX"),
+            OptionProduct::mini("AAPL7", "AAPL"),
+            OptionProduct::mini("AMZN7", "AMZN"),
+            OptionProduct::mini("GLD7", "GLD"),
+            OptionProduct::mini("GOOG7", "GOOG"),
+            OptionProduct::mini("SPY7", "SPY"),
+        ] {
+            registry.insert_option(product);
+        }
+        for product in [
+            FutureProduct::with_options("ES", "HMUZ", FutureOptionExpiration::ThirdFriday, OptionTerms::INDEX_FUTURE),
+            FutureProduct::with_options("NQ", "HMUZ", FutureOptionExpiration::ThirdFriday, OptionTerms::INDEX_FUTURE),
+            FutureProduct::with_options("RTY", "HMUZ", FutureOptionExpiration::ThirdFriday, OptionTerms::INDEX_FUTURE),
+            FutureProduct::with_options("YM", "HMUZ", FutureOptionExpiration::ThirdFriday, OptionTerms::INDEX_FUTURE),
+            FutureProduct::with_options("ZN", "HMUZ", FutureOptionExpiration::LastFridayOfPriorMonth, OptionTerms::FUTURE),
+            FutureProduct::with_options("ZB", "HMUZ", FutureOptionExpiration::LastFridayOfPriorMonth, OptionTerms::FUTURE),
+            // Currency options stop trading in the morning of their expiration
+            FutureProduct::with_options(
+                "6E",
+                "HMUZ",
+                FutureOptionExpiration::SecondFridayBeforeThirdWednesday,
+                OptionTerms { settlement_time: SettlementTime::Am, ..OptionTerms::FUTURE },
+            ),
+            // Energy and metals options expire on business-day counts back from the
+            // futures' own last trading day, which is not modelled
+            FutureProduct::new("CL", "FGHJKMNQUVXZ"),
+            FutureProduct::new("NG", "FGHJKMNQUVXZ"),
+            FutureProduct::new("GC", "GJMQVZ"),
+            FutureProduct::with_options("ZC", "HKNUZ", FutureOptionExpiration::LastFridayOfPriorMonth, OptionTerms::FUTURE),
+        ] {
+            registry.insert_future(product);
+        }
+        registry
+    }
+
+    pub fn insert_future(&mut self, product: FutureProduct) {
+        self.futures.insert(product.root.to_owned(), product);
+    }
+
+    pub fn future_product(&self, root: &str) -> Option<&FutureProduct> {
+        self.futures.get(root)
+    }
+
+    pub fn known_roots(&self) -> impl Iterator<Item = &str> {
+        self.options.keys().chain(self.futures.keys()).map(String::as_str)
+    }
+
+    pub fn is_known_root(&self, root: &str) -> bool {
+        self.options.contains_key(root) || self.futures.contains_key(root)
+    }
+
+    pub fn insert_option(&mut self, product: OptionProduct) {
+        self.options.insert(product.root.to_owned(), product);
+    }
+
+    pub fn option_product(&self, root: &str) -> Option<&OptionProduct> {
+        self.options.get(root)
+    }
+
+    pub fn underlying<'a>(&'a self, root: &'a str) -> &'a str {
+        if let Some(product) = self.option_product(root) {
+            &product.deliverable.underlying
+        } else {
+            adjusted_root(root).unwrap_or(root)
+        }
+    }
+
+    // Terms of the contract listed under the root. Adjusted roots that are not in
+    // the registry have unknown terms.
+    pub fn deliverable(&self, root: &str) -> Option<Deliverable> {
+        if let Some(product) = self.option_product(root) {
+            return Some(product.deliverable.clone());
+        }
+        adjusted_root(root).is_none().then(|| Deliverable::standard(root))
+    }
+
+    // Whether the root was adjusted after a corporate action, as opposed to a mini
+    pub fn is_adjusted(&self, root: &str) -> bool {
+        adjusted_root(root).is_some() && !self.option_product(root).is_some_and(|product| product.mini)
+    }
+
+    // Unlisted roots are treated as equity options
+    pub fn option_terms(&self, root: &str) -> OptionTerms {
+        self.option_product(root).map_or(OptionTerms::EQUITY, |product| product.terms)
+    }
+
+    // Terms of the options on a future, unknown for unlisted roots
+    pub fn future_option_terms(&self, root: &str) -> Option<OptionTerms> {
+        self.future_product(root).map(|product| product.option_terms)
+    }
+
+    // Reads adjusted deliverables, one root per line:
+    //   root,underlying,multiplier,shares,cash
+    // Blank lines and lines starting with # are skipped. Returns the number of roots loaded.
+    pub fn load_deliverables<R: BufRead>(&mut self, reader: R) -> Result<usize, DeliverablesError> {
+        let mut loaded = 0;
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = |message: &str| DeliverablesError::InvalidLine { line: index + 1, message: message.to_owned() };
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [root, underlying, multiplier, shares, cash] = fields[..] else {
+                return Err(invalid("expected root,underlying,multiplier,shares,cash"));
+            };
+            if root.is_empty() || underlying.is_empty() {
+                return Err(invalid("root and underlying are required"));
+            }
+            let decimal = |value: &str| Decimal::from_str(value).map_err(|_| invalid(&format!("{value} is not a number")));
+
+            let existing = self.option_product(root).map(|product| (product.weeklys_root, product.mini, product.terms));
+            let (weeklys_root, mini, terms) = existing.unwrap_or((false, false, OptionTerms::EQUITY));
+            self.insert_option(OptionProduct {
+                weeklys_root,
+                mini,
+                terms,
+                ..OptionProduct::new(root, Deliverable {
+                    underlying: underlying.to_owned(),
+                    multiplier: decimal(multiplier)?,
+                    shares: decimal(shares)?,
+                    cash: decimal(cash)?,
+                })
+            });
+            loaded += 1;
+        }
+        Ok(loaded)
+    }
+
+    pub fn load_deliverables_file<P: AsRef<Path>>(&mut self, path: P) -> Result<usize, DeliverablesError> {
+        self.load_deliverables(BufReader::new(File::open(path)?))
+    }
+
+    pub fn classify_expiration(&self, root: &str, date: NaiveDate) -> ExpirationSeries {
+        let weeklys_root = self.option_product(root).is_some_and(|product| product.weeklys_root);
+        let (year, month) = (date.year(), date.month());
+
+        if !weeklys_root {
+            if calendar::monthly_expiration(year, month) == Some(date) {
+                return ExpirationSeries::Monthly;
+            }
+            // Before 2015 standard options expired on the Saturday after the third Friday
+            if date.weekday() == Weekday::Sat && calendar::third_friday(year, month) == date.pred_opt() {
+                return ExpirationSeries::Monthly;
+            }
+        }
+
+        if calendar::last_trading_day_of_month(year, month) == Some(date) {
+            return match month {
+                3 | 6 | 9 | 12 => ExpirationSeries::Quarterly,
+                _ => ExpirationSeries::EndOfMonth,
+            };
+        }
+
+        if calendar::weekly_expiration(date) == date {
+            ExpirationSeries::Weekly
+        } else {
+            ExpirationSeries::Daily
+        }
+    }
+}
+
+static DEFAULT_REGISTRY: Lazy<RwLock<ProductRegistry>> = Lazy::new(|| RwLock::new(ProductRegistry::with_defaults()));
+
+// Registry used when converting parse results into symbols
+pub fn default_registry() -> RwLockReadGuard<'static, ProductRegistry> {
+    DEFAULT_REGISTRY.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+pub fn set_default_registry(registry: ProductRegistry) {
+    let mut current = DEFAULT_REGISTRY.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    *current = registry;
+}
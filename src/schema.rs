@@ -0,0 +1,278 @@
+use std::fmt;
+
+use chrono::{NaiveDate, NaiveTime};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::symbol::{
+    Deliverable, ExerciseStyle, ExpirationSeries, FutureContract, OptionContract, PutOrCall, SettlementTime,
+    SettlementType, Symbol, SymbolType,
+};
+
+// Versioned JSON document for storing symbols, kept apart from the serde derives on
+// Symbol so those can follow the library while stored documents keep their shape.
+// schema/README.md describes the versions; schema/symbol.v1.schema.json is generated
+// from the types below.
+//
+//   0  the derive output before documents were versioned: no schema_version, PascalCase
+//      enum tags
+//   1  schema_version 1, snake_case enum tags, decimals as strings, ISO 8601 dates
+//
+// A version only gains optional fields, which older readers ignore. Anything else is a
+// new version, and from_json keeps reading every version before it.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    // The document's schema_version, when it got that far
+    pub version: Option<u64>,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.version {
+            Some(version) => write!(f, "could not read symbol document version {version}: {}", self.message),
+            None => write!(f, "could not read symbol document: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json-schema", schemars(title = "Symbol", description = "A parsed symbol, schema version 1"))]
+pub struct SymbolDocument {
+    pub schema_version: u32,
+    pub symbol_type: DocumentSymbolType,
+    // The input as written, MSFT 110122C27.5
+    pub original_symbol: String,
+    pub underlying_symbol: String,
+    // Share class or exchange suffix with its separator, .B or :A
+    #[serde(default)]
+    pub symbol_modifier: Option<String>,
+    #[serde(default)]
+    pub option_contract: Option<OptionDocument>,
+    #[serde(default)]
+    pub future_contract: Option<FutureDocument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct OptionDocument {
+    // Root the option is listed under when it differs from underlying_symbol, AAPL1 or SPXW
+    #[serde(default)]
+    pub root: Option<String>,
+    pub strike_price: Decimal,
+    pub put_call: DocumentPutCall,
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    #[serde(default)]
+    pub series: Option<DocumentSeries>,
+    #[serde(default)]
+    pub adjusted: bool,
+    #[serde(default)]
+    pub deliverable: Option<DeliverableDocument>,
+    #[serde(default)]
+    pub exercise_style: Option<DocumentExerciseStyle>,
+    #[serde(default)]
+    pub settlement_type: Option<DocumentSettlementType>,
+    #[serde(default)]
+    pub settlement_time: Option<DocumentSettlementTime>,
+    #[serde(default)]
+    pub expiration_time: Option<NaiveTime>,
+    // IANA zone of expiration_time
+    #[serde(default)]
+    pub time_zone: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DeliverableDocument {
+    pub underlying: String,
+    pub multiplier: Decimal,
+    pub shares: Decimal,
+    pub cash: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct FutureDocument {
+    #[serde(default)]
+    pub continuous: bool,
+    #[serde(default)]
+    pub month: Option<String>,
+    #[serde(default)]
+    pub year: Option<String>,
+    #[serde(default)]
+    pub expiration: Option<NaiveDate>,
+}
+
+// The tags are part of the format, so each enum is spelled out here rather than
+// borrowing the library's
+macro_rules! document_enum {
+    ($name:ident: $domain:ident { $($variant:ident),* $(,)? }) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+        #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+        #[serde(rename_all = "snake_case")]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl From<$domain> for $name {
+            fn from(value: $domain) -> Self {
+                match value {
+                    $($domain::$variant => $name::$variant),*
+                }
+            }
+        }
+
+        impl From<$name> for $domain {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $domain::$variant),*
+                }
+            }
+        }
+    };
+}
+
+document_enum!(DocumentSymbolType: SymbolType { Stock, Future, FutureOption, StockOption, Unknown });
+document_enum!(DocumentPutCall: PutOrCall { Put, Call });
+document_enum!(DocumentSeries: ExpirationSeries { Monthly, Weekly, Quarterly, EndOfMonth, Daily });
+document_enum!(DocumentExerciseStyle: ExerciseStyle { American, European });
+document_enum!(DocumentSettlementType: SettlementType { Physical, Cash });
+document_enum!(DocumentSettlementTime: SettlementTime { Am, Pm });
+
+impl From<&Symbol> for SymbolDocument {
+    fn from(symbol: &Symbol) -> Self {
+        SymbolDocument {
+            schema_version: SCHEMA_VERSION,
+            symbol_type: symbol.symbol_type.into(),
+            original_symbol: symbol.original_symbol.to_owned(),
+            underlying_symbol: symbol.underlying_symbol.to_owned(),
+            symbol_modifier: symbol.symbol_modifier.to_owned(),
+            option_contract: symbol.option_contract.as_ref().map(|option| OptionDocument {
+                root: (option.root != symbol.underlying_symbol).then(|| option.root.to_owned()),
+                strike_price: option.strike_price,
+                put_call: option.put_call.into(),
+                date: option.date,
+                series: option.series.map(Into::into),
+                adjusted: option.adjusted,
+                deliverable: option.deliverable.as_ref().map(|deliverable| DeliverableDocument {
+                    underlying: deliverable.underlying.to_owned(),
+                    multiplier: deliverable.multiplier,
+                    shares: deliverable.shares,
+                    cash: deliverable.cash,
+                }),
+                exercise_style: option.exercise_style.map(Into::into),
+                settlement_type: option.settlement_type.map(Into::into),
+                settlement_time: option.settlement_time.map(Into::into),
+                expiration_time: option.expiration_time,
+                time_zone: option.time_zone.to_owned(),
+            }),
+            future_contract: symbol.future_contract.as_ref().map(|future| FutureDocument {
+                continuous: future.continuous,
+                month: future.month.to_owned(),
+                year: future.year.to_owned(),
+                expiration: future.expiration,
+            }),
+        }
+    }
+}
+
+impl From<SymbolDocument> for Symbol {
+    fn from(document: SymbolDocument) -> Self {
+        Symbol {
+            symbol_type: document.symbol_type.into(),
+            original_symbol: document.original_symbol,
+            symbol_modifier: document.symbol_modifier,
+            option_contract: document.option_contract.map(|option| OptionContract {
+                root: option.root.unwrap_or_else(|| document.underlying_symbol.to_owned()),
+                strike_price: option.strike_price,
+                put_call: option.put_call.into(),
+                date: option.date,
+                series: option.series.map(Into::into),
+                adjusted: option.adjusted,
+                deliverable: option.deliverable.map(|deliverable| Deliverable {
+                    underlying: deliverable.underlying,
+                    multiplier: deliverable.multiplier,
+                    shares: deliverable.shares,
+                    cash: deliverable.cash,
+                }),
+                exercise_style: option.exercise_style.map(Into::into),
+                settlement_type: option.settlement_type.map(Into::into),
+                settlement_time: option.settlement_time.map(Into::into),
+                expiration_time: option.expiration_time,
+                time_zone: option.time_zone,
+            }),
+            future_contract: document.future_contract.map(|future| FutureContract {
+                continuous: future.continuous,
+                month: future.month,
+                year: future.year,
+                expiration: future.expiration,
+            }),
+            underlying_symbol: document.underlying_symbol,
+        }
+    }
+}
+
+pub fn to_value(symbol: &Symbol) -> serde_json::Value {
+    serde_json::to_value(SymbolDocument::from(symbol)).expect("symbol documents serialize to JSON")
+}
+
+pub fn to_json(symbol: &Symbol) -> String {
+    serde_json::to_string(&SymbolDocument::from(symbol)).expect("symbol documents serialize to JSON")
+}
+
+// Reads any version up to SCHEMA_VERSION
+pub fn from_value(value: serde_json::Value) -> Result<Symbol, SchemaError> {
+    let version = match value.get("schema_version") {
+        None => None,
+        Some(version) => Some(version.as_u64().ok_or_else(|| SchemaError {
+            version: None,
+            message: format!("schema_version must be a positive integer, not {version}"),
+        })?),
+    };
+    let invalid = |err: serde_json::Error| SchemaError { version, message: err.to_string() };
+    match version {
+        None | Some(0) => serde_json::from_value::<Symbol>(with_option_root(value)).map_err(invalid),
+        Some(1) => serde_json::from_value::<SymbolDocument>(value).map(Symbol::from).map_err(invalid),
+        Some(_) => Err(SchemaError {
+            version,
+            message: format!("this build reads versions up to {SCHEMA_VERSION}"),
+        }),
+    }
+}
+
+// Options stored before they carried a root are listed under their underlying
+fn with_option_root(mut value: serde_json::Value) -> serde_json::Value {
+    let underlying = value.get("underlying_symbol").cloned();
+    if let (Some(option), Some(underlying)) = (value.get_mut("option_contract").and_then(|option| option.as_object_mut()), underlying) {
+        option.entry("root").or_insert(underlying);
+    }
+    value
+}
+
+pub fn from_json(json: &str) -> Result<Symbol, SchemaError> {
+    let value = serde_json::from_str(json).map_err(|err| SchemaError { version: None, message: err.to_string() })?;
+    from_value(value)
+}
+
+// For `#[serde(with = "symbols::schema")]` on a Symbol field of a larger event
+pub fn serialize<S: Serializer>(symbol: &Symbol, serializer: S) -> Result<S::Ok, S::Error> {
+    SymbolDocument::from(symbol).serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Symbol, D::Error> {
+    let value = serde_json::Value::deserialize(deserializer)?;
+    from_value(value).map_err(serde::de::Error::custom)
+}
+
+#[cfg(feature = "json-schema")]
+pub fn json_schema() -> serde_json::Value {
+    let mut schema = schemars::schema_for!(SymbolDocument).to_value();
+    schema["properties"]["schema_version"] = serde_json::json!({ "const": SCHEMA_VERSION });
+    schema
+}
@@ -0,0 +1,313 @@
+use std::io::{self, Read};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::bulk::parse_many;
+use crate::cache::SymbolCache;
+use crate::chain::{ChainBuilder, StrikeIncrements};
+use crate::format::{translate, Symbology};
+use crate::registry::default_registry;
+use crate::suggest::{suggest, Suggestion};
+use crate::symbol::Symbol;
+use crate::symbol_parser::ParseError;
+
+const CACHE_CAPACITY: usize = 1 << 16;
+const MAX_BODY_BYTES: u64 = 16 << 20;
+const WORKERS: usize = 4;
+const MAX_CHAIN_SYMBOLS: usize = 100_000;
+
+const ENDPOINTS: [&str; 6] = ["/parse", "/parse/batch", "/translate", "/chain", "/health", "/metrics"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: impl Serialize) -> Self {
+        Response {
+            status,
+            content_type: "application/json",
+            body: serde_json::to_string(&body).expect("responses serialize to JSON"),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Response::json(status, json!({ "error": { "message": message } }))
+    }
+}
+
+#[derive(Deserialize)]
+struct ParseRequest {
+    symbol: String,
+}
+
+#[derive(Deserialize)]
+struct BatchRequest {
+    symbols: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ParseFailure<'a> {
+    error: &'a ParseError,
+    suggestions: Vec<Suggestion>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum BatchResult<'a> {
+    Parsed { symbol: &'a Symbol },
+    Failed { error: &'a ParseError },
+}
+
+#[derive(Default)]
+struct Metrics {
+    requests: [AtomicU64; ENDPOINTS.len()],
+    not_found: AtomicU64,
+    parsed: AtomicU64,
+    parse_failures: AtomicU64,
+}
+
+// Request handling without any I/O, so it can be driven in-process by tests and by
+// the tiny_http loop in serve()
+pub struct Service {
+    cache: SymbolCache,
+    metrics: Metrics,
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Service {
+            cache: SymbolCache::new(CACHE_CAPACITY),
+            metrics: Default::default(),
+        }
+    }
+}
+
+impl Service {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // `url` is the request target, path and query string
+    pub fn handle(&self, method: &str, url: &str, body: &[u8]) -> Response {
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let Some(endpoint) = ENDPOINTS.iter().position(|endpoint| *endpoint == path) else {
+            self.metrics.not_found.fetch_add(1, Ordering::Relaxed);
+            return Response::error(404, &format!("no endpoint at {path}"));
+        };
+        self.metrics.requests[endpoint].fetch_add(1, Ordering::Relaxed);
+
+        match (method, path) {
+            ("POST", "/parse") => self.parse(body),
+            ("POST", "/parse/batch") => self.parse_batch(body),
+            ("GET", "/translate") => self.translate(query),
+            ("GET", "/chain") => self.chain(query),
+            ("GET", "/health") => Response::json(200, json!({ "status": "ok", "version": env!("CARGO_PKG_VERSION") })),
+            ("GET", "/metrics") => self.metrics(),
+            _ => Response::error(405, &format!("{method} is not allowed on {path}")),
+        }
+    }
+
+    fn parse(&self, body: &[u8]) -> Response {
+        let request: ParseRequest = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(err) => return Response::error(400, &format!("expected {{\"symbol\": string}}: {err}")),
+        };
+        match self.cache.parse(&request.symbol) {
+            Ok(symbol) => {
+                self.metrics.parsed.fetch_add(1, Ordering::Relaxed);
+                Response::json(200, symbol.as_ref())
+            }
+            Err(err) => {
+                self.metrics.parse_failures.fetch_add(1, Ordering::Relaxed);
+                let suggestions = suggest(&request.symbol, Some(&default_registry()));
+                Response::json(422, ParseFailure { error: &err, suggestions })
+            }
+        }
+    }
+
+    // One result per symbol, in order; failures do not fail the request
+    fn parse_batch(&self, body: &[u8]) -> Response {
+        let request: BatchRequest = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(err) => return Response::error(400, &format!("expected {{\"symbols\": [string]}}: {err}")),
+        };
+        let results: Vec<Result<Symbol, ParseError>> = parse_many(&request.symbols).collect();
+        let failures = results.iter().filter(|result| result.is_err()).count() as u64;
+        self.metrics.parsed.fetch_add(results.len() as u64 - failures, Ordering::Relaxed);
+        self.metrics.parse_failures.fetch_add(failures, Ordering::Relaxed);
+
+        let results: Vec<BatchResult> = results
+            .iter()
+            .map(|result| match result {
+                Ok(symbol) => BatchResult::Parsed { symbol },
+                Err(error) => BatchResult::Failed { error },
+            })
+            .collect();
+        Response::json(200, json!({ "results": results }))
+    }
+
+    fn translate(&self, query: &str) -> Response {
+        let mut symbol = None;
+        let mut from = Symbology::Native;
+        let mut to = None;
+        for (key, value) in query_pairs(query) {
+            match key.as_str() {
+                "symbol" => symbol = Some(value),
+                "from" | "to" => {
+                    let Ok(symbology) = value.parse::<Symbology>() else {
+                        return Response::error(400, &format!("unknown symbology {value:?}"));
+                    };
+                    if key == "from" {
+                        from = symbology;
+                    } else {
+                        to = Some(symbology);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let (Some(symbol), Some(to)) = (symbol, to) else {
+            return Response::error(400, "symbol and to are required");
+        };
+        match translate(&symbol, from, to) {
+            Ok(output) => Response::json(200, json!({ "input": symbol, "output": output, "from": from.name(), "to": to.name() })),
+            Err(err) => Response::json(422, json!({ "error": err })),
+        }
+    }
+
+    fn chain(&self, query: &str) -> Response {
+        let (builder, symbology) = match chain_request(query) {
+            Ok(request) => request,
+            Err(response) => return response,
+        };
+        let symbols = match builder.build() {
+            Ok(symbols) => symbols,
+            Err(err) => return Response::json(422, json!({ "error": err })),
+        };
+        match symbols.iter().map(|symbol| symbology.format(symbol)).collect::<Result<Vec<_>, _>>() {
+            Ok(symbols) => Response::json(200, json!({ "symbology": symbology.name(), "symbols": symbols })),
+            Err(err) => Response::json(422, json!({ "error": err })),
+        }
+    }
+
+    // Prometheus text exposition format
+    fn metrics(&self) -> Response {
+        let mut body = String::from("# TYPE symbols_requests_total counter\n");
+        for (endpoint, count) in ENDPOINTS.iter().zip(&self.metrics.requests) {
+            body += &format!("symbols_requests_total{{endpoint=\"{endpoint}\"}} {}\n", count.load(Ordering::Relaxed));
+        }
+        let stats = self.cache.stats();
+        let counters = [
+            ("symbols_not_found_total", self.metrics.not_found.load(Ordering::Relaxed)),
+            ("symbols_parsed_total", self.metrics.parsed.load(Ordering::Relaxed)),
+            ("symbols_parse_failures_total", self.metrics.parse_failures.load(Ordering::Relaxed)),
+            ("symbols_cache_hits_total", stats.hits),
+            ("symbols_cache_misses_total", stats.misses),
+        ];
+        for (name, value) in counters {
+            body += &format!("# TYPE {name} counter\n{name} {value}\n");
+        }
+        body += &format!("# TYPE symbols_cache_entries gauge\nsymbols_cache_entries {}\n", stats.len);
+        Response {
+            status: 200,
+            content_type: "text/plain; version=0.0.4",
+            body,
+        }
+    }
+}
+
+// root, date and the strike range low and high are required; days, a uniform strike
+// increment and the symbology to write the chain in are optional
+fn chain_request(query: &str) -> Result<(ChainBuilder, Symbology), Response> {
+    let pairs: Vec<(String, String)> = query_pairs(query).collect();
+    let (Some(root), Some(date), Some(low), Some(high)) = (
+        query_param::<String>(&pairs, "root")?,
+        query_param(&pairs, "date")?,
+        query_param(&pairs, "low")?,
+        query_param(&pairs, "high")?,
+    ) else {
+        return Err(Response::error(400, "root, date, low and high are required"));
+    };
+    let mut builder = ChainBuilder::new(&root, date).strikes(low, high).limit(MAX_CHAIN_SYMBOLS);
+    if let Some(days) = query_param(&pairs, "days")? {
+        builder = builder.days(days);
+    }
+    if let Some(increment) = query_param(&pairs, "increment")? {
+        builder = builder.increments(StrikeIncrements::uniform(increment));
+    }
+    Ok((builder, query_param(&pairs, "symbology")?.unwrap_or(Symbology::Native)))
+}
+
+fn query_param<T: FromStr>(pairs: &[(String, String)], key: &str) -> Result<Option<T>, Response> {
+    let Some((_, value)) = pairs.iter().find(|(name, _)| name == key) else {
+        return Ok(None);
+    };
+    let parsed = value.parse().map_err(|_| Response::error(400, &format!("{key} {value:?} is not valid")))?;
+    Ok(Some(parsed))
+}
+
+fn query_pairs(query: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    query.split('&').filter(|pair| !pair.is_empty()).map(|pair| {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        (percent_decode(key), percent_decode(value))
+    })
+}
+
+fn percent_decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+            (b'+', _) => decoded.push(b' '),
+            (byte, _) => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+// Serves until the process is stopped. Meant for local use: no TLS, no auth.
+pub fn serve(addr: &str, service: Arc<Service>) -> io::Result<()> {
+    let server = Arc::new(tiny_http::Server::http(addr).map_err(io::Error::other)?);
+    let workers: Vec<_> = (0..WORKERS)
+        .map(|_| {
+            let server = server.clone();
+            let service = service.clone();
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let mut body = Vec::new();
+                    let response = match request.as_reader().take(MAX_BODY_BYTES).read_to_end(&mut body) {
+                        Ok(_) => service.handle(request.method().as_str(), request.url(), &body),
+                        Err(err) => Response::error(400, &err.to_string()),
+                    };
+                    let header = tiny_http::Header::from_bytes("Content-Type", response.content_type).expect("valid header");
+                    let reply = tiny_http::Response::from_string(response.body)
+                        .with_status_code(response.status)
+                        .with_header(header);
+                    // The client hanging up is not the server's problem
+                    let _ = request.respond(reply);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().map_err(|_| io::Error::other("server worker panicked"))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,285 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use tsify::Tsify;
+
+use crate::calendar;
+use crate::normalize::normalize;
+use crate::registry::ProductRegistry;
+use crate::symbol::{ExpirationSeries, FUTURE_MONTHS};
+use crate::symbol_parser::{parse_symbol, ParseResult, ParseResultRef};
+
+const MAX_SUGGESTIONS: usize = 5;
+const MAX_DATE_REPAIRS: usize = 3;
+
+// Characters commonly typed in place of one another
+const CONFUSABLES: [(char, char); 11] = [
+    ('O', '0'),
+    ('0', 'O'),
+    ('I', '1'),
+    ('L', '1'),
+    ('1', 'I'),
+    ('S', '5'),
+    ('5', 'S'),
+    ('B', '8'),
+    ('8', 'B'),
+    ('Z', '2'),
+    ('2', 'Z'),
+];
+
+// [Option Root]<space>[YYMMDD][C or P][Strike Price] with one digit too few or too many in the date
+static MISSIZED_DATE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?P<root>[A-Z0-9]+ *)(?P<date>\d{5}|\d{7})(?P<put_call>[CP])(?P<strike>[0-9.]+)$").unwrap());
+
+// Ordered by how confident the repair is; ties in edit distance keep this order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Tsify)]
+pub enum SuggestionReason {
+    // Parses once lowercase, full-width characters or stray whitespace are cleaned up
+    Normalized,
+    MissingDigit,
+    ExtraDigit,
+    // The future's month is not in the root's listing cycle
+    UnlistedMonth,
+    // A letter typed for a digit or the other way around (O/0, I/1, S/5, ...)
+    Confusable,
+    // The future's root is not in the registry but a listed one is close
+    UnknownRoot,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Tsify)]
+pub struct Suggestion {
+    pub symbol: String,
+    pub reason: SuggestionReason,
+    // Edit distance from the input
+    pub distance: usize,
+}
+
+// Closest valid symbols to the input, best first. Symbols that fail to parse or only
+// parse as the catch-all unknown symbol are repaired through the grammar; futures are
+// additionally checked against the registry's roots and listing cycles.
+pub fn suggest(raw_symbol: &str, registry: Option<&ProductRegistry>) -> Vec<Suggestion> {
+    let normalized = normalize(raw_symbol);
+    let symbol = normalized.symbol.as_str();
+    let parsed = parse_symbol(symbol).ok().filter(is_valid);
+    let needs_repair = parsed.as_ref().is_none_or(|result| matches!(result, ParseResult::UnknownSym(_)));
+
+    let mut candidates = Vec::new();
+    let strict_failed = parse_symbol(raw_symbol).is_err();
+    if !normalized.is_unchanged() && parsed.is_some() && (!needs_repair || strict_failed) {
+        candidates.push((symbol.to_owned(), SuggestionReason::Normalized));
+    }
+    if needs_repair {
+        candidates.extend(date_repairs(symbol, registry));
+        candidates.extend(confusables(symbol, parsed.is_some()));
+    }
+    if let (Some(registry), Some(parsed)) = (registry, &parsed) {
+        candidates.extend(listed_months(symbol, parsed, registry));
+        candidates.extend(known_roots(symbol, parsed, registry));
+    }
+
+    let mut suggestions: Vec<Suggestion> = Vec::new();
+    for (candidate, reason) in candidates {
+        if candidate == raw_symbol || suggestions.iter().any(|s| s.symbol == candidate) {
+            continue;
+        }
+        suggestions.push(Suggestion {
+            distance: edit_distance(raw_symbol, &candidate),
+            symbol: candidate,
+            reason,
+        });
+    }
+    suggestions.sort_by_key(|suggestion| suggestion.distance);
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+// Stock option dates that are not calendar dates are rejected when converting to a Symbol
+fn is_valid(result: &ParseResultRef<'_>) -> bool {
+    match result {
+        ParseResult::StockOptionsSym(symbol) => NaiveDate::parse_from_str(symbol.date, "%y%m%d").is_ok(),
+        _ => true,
+    }
+}
+
+fn root_symbol<'a>(result: &ParseResultRef<'a>) -> &'a str {
+    match result {
+        ParseResult::UnknownSym(symbol) => symbol.root_symbol,
+        ParseResult::StockOptionsSym(symbol) => symbol.root_symbol,
+        ParseResult::FutureSym(symbol) => symbol.root_symbol,
+        ParseResult::FutureOptionsSym(symbol) => symbol.future_symbol.root_symbol,
+        ParseResult::Unused => "",
+    }
+}
+
+// A repair must parse, and must do better than the catch-all when the input already did.
+// The grammar lets roots swallow spaces, which never makes for a sensible suggestion.
+fn is_repaired(candidate: &str, input_parsed: bool) -> bool {
+    match parse_symbol(candidate) {
+        Ok(ParseResult::UnknownSym(_)) if input_parsed => false,
+        Ok(result) => is_valid(&result) && !root_symbol(&result).contains(' '),
+        Err(_) => false,
+    }
+}
+
+fn date_repairs(symbol: &str, registry: Option<&ProductRegistry>) -> Vec<(String, SuggestionReason)> {
+    let Some(captures) = MISSIZED_DATE.captures(symbol) else {
+        return Vec::new();
+    };
+    let root = &captures["root"];
+    let date = &captures["date"];
+    let rest = format!("{}{}", &captures["put_call"], &captures["strike"]);
+
+    let (reason, dates): (SuggestionReason, Vec<String>) = if date.len() == 5 {
+        let insertions = (0..=date.len()).rev().flat_map(|index| {
+            ('0'..='9').map(move |digit| format!("{}{digit}{}", &date[..index], &date[index..]))
+        });
+        (SuggestionReason::MissingDigit, insertions.collect())
+    } else {
+        let deletions = (0..date.len()).rev().map(|index| format!("{}{}", &date[..index], &date[index + 1..]));
+        (SuggestionReason::ExtraDigit, deletions.collect())
+    };
+
+    let default_registry = ProductRegistry::new();
+    let registry = registry.unwrap_or(&default_registry);
+    let root_symbol = root.trim_end();
+    let mut repairs: Vec<(usize, String)> = Vec::new();
+    for date in dates {
+        let Ok(expiration) = NaiveDate::parse_from_str(&date, "%y%m%d") else {
+            continue;
+        };
+        // Prefer dates something actually expires on. Saturdays are kept for the
+        // pre-2015 monthly expirations.
+        let rank = if !calendar::is_trading_day(expiration) && expiration.weekday() != Weekday::Sat {
+            4
+        } else {
+            match registry.classify_expiration(root_symbol, expiration) {
+                ExpirationSeries::Monthly => 0,
+                ExpirationSeries::Quarterly | ExpirationSeries::EndOfMonth => 1,
+                ExpirationSeries::Weekly => 2,
+                ExpirationSeries::Daily => 3,
+            }
+        };
+        let candidate = format!("{root}{date}{rest}");
+        if !repairs.iter().any(|(_, existing)| *existing == candidate) {
+            repairs.push((rank, candidate));
+        }
+    }
+    repairs.sort_by_key(|(rank, _)| *rank);
+
+    repairs
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .filter(|candidate| is_repaired(candidate, false))
+        .take(MAX_DATE_REPAIRS)
+        .map(|candidate| (candidate, reason))
+        .collect()
+}
+
+fn confusables(symbol: &str, input_parsed: bool) -> Vec<(String, SuggestionReason)> {
+    let mut repairs = Vec::new();
+    for (index, c) in symbol.char_indices() {
+        for (from, to) in CONFUSABLES {
+            if c != from {
+                continue;
+            }
+            let candidate = format!("{}{to}{}", &symbol[..index], &symbol[index + c.len_utf8()..]);
+            if is_repaired(&candidate, input_parsed) {
+                repairs.push((candidate, SuggestionReason::Confusable));
+            }
+        }
+    }
+    repairs
+}
+
+// Root, month and year of a future or future option
+fn future_parts<'a>(parsed: &ParseResultRef<'a>) -> Option<(&'a str, Option<&'a str>, Option<&'a str>)> {
+    let future = match parsed {
+        ParseResult::FutureSym(future) => future,
+        ParseResult::FutureOptionsSym(option) => &option.future_symbol,
+        _ => return None,
+    };
+    Some((future.root_symbol, future.month, future.year))
+}
+
+fn listed_months(symbol: &str, parsed: &ParseResultRef<'_>, registry: &ProductRegistry) -> Vec<(String, SuggestionReason)> {
+    let Some((root, Some(month), Some(year))) = future_parts(parsed) else {
+        return Vec::new();
+    };
+    let Some(product) = registry.future_product(root) else {
+        return Vec::new();
+    };
+    let Some(month_index) = FUTURE_MONTHS.iter().position(|m| *m == month) else {
+        return Vec::new();
+    };
+    if product.is_listed_month(month) {
+        return Vec::new();
+    }
+    let Ok(year_number) = year.parse::<u32>() else {
+        return Vec::new();
+    };
+    let year_modulus = 10u32.pow(year.len() as u32);
+
+    // Walk outwards from the month, nearest listed months first, later before earlier
+    let contract = format!("{root}{month}{year}");
+    let mut repairs = Vec::new();
+    for offset in 1..12i32 {
+        for direction in [1, -1] {
+            let months = month_index as i32 + direction * offset;
+            let listed_month = FUTURE_MONTHS[months.rem_euclid(12) as usize];
+            if !product.is_listed_month(listed_month) {
+                continue;
+            }
+            let listed_year = (year_number as i32 + months.div_euclid(12)).rem_euclid(year_modulus as i32);
+            let replacement = format!("{root}{listed_month}{listed_year:0width$}", width = year.len());
+            repairs.push((symbol.replacen(&contract, &replacement, 1), SuggestionReason::UnlistedMonth));
+        }
+        if repairs.len() >= 2 {
+            break;
+        }
+    }
+    repairs
+}
+
+fn known_roots(symbol: &str, parsed: &ParseResultRef<'_>, registry: &ProductRegistry) -> Vec<(String, SuggestionReason)> {
+    let Some((root, month, year)) = future_parts(parsed) else {
+        return Vec::new();
+    };
+    if registry.is_known_root(root) {
+        return Vec::new();
+    }
+
+    let max_distance = (root.len() / 3).max(1);
+    let mut roots: Vec<(usize, &str)> = registry
+        .known_roots()
+        .filter(|known| registry.future_product(known).is_some())
+        .map(|known| (edit_distance(root, known), known))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    roots.sort();
+
+    let contract = format!("{root}{}{}", month.unwrap_or_default(), year.unwrap_or_default());
+    roots
+        .into_iter()
+        .map(|(_, known)| {
+            let replacement = format!("{known}{}{}", month.unwrap_or_default(), year.unwrap_or_default());
+            (symbol.replacen(&contract, &replacement, 1), SuggestionReason::UnknownRoot)
+        })
+        .collect()
+}
+
+// Levenshtein distance over characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
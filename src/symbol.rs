@@ -1,43 +1,137 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
-use wasm_bindgen::prelude::wasm_bindgen;
+use tsify::Tsify;
 use rust_decimal::prelude::*;
-use crate::symbol_parser::{ParseResult, PutCall};
-const FUTURE_MONTHS: [&str; 12] =
+use crate::registry::{self, ProductRegistry};
+use crate::symbol_parser::{parse_symbol, FutureSymbol, ParseError, ParseResult, ParseResultRef, PutCall};
+pub const FUTURE_MONTHS: [&str; 12] =
     ["F", "G", "H", "J", "K", "M", "N", "Q", "U", "V", "X", "Z"];
-#[wasm_bindgen]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
 pub enum SymbolType {
     Stock,
     Future,
     FutureOption,
     StockOption,
+    #[default]
     Unknown,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
 pub enum PutOrCall {
     #[default]
     Put,
     Call,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+// Expiration cycle of an equity or index option, derived from its date and root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
+pub enum ExpirationSeries {
+    // Third Friday (rolled back for holidays)
+    Monthly,
+    // Friday (or the trading day before) of any other week
+    Weekly,
+    // Last trading day of March, June, September and December
+    Quarterly,
+    // Last trading day of any other month
+    EndOfMonth,
+    // Any other trading day, 0DTE
+    Daily,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
+pub enum ExerciseStyle {
+    American,
+    European,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
+pub enum SettlementType {
+    Physical,
+    Cash,
+}
+
+// AM-settled options settle on the opening prints of the expiration date,
+// PM-settled ones on the close
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
+pub enum SettlementTime {
+    Am,
+    Pm,
+}
+
+impl SettlementTime {
+    // When the settlement price is set on the expiration date, in exchange time: the
+    // opening for AM settlement, the close for PM. AM-settled options stop trading at
+    // the close of the day before, so this is not their last trade.
+    pub fn expiration_time(&self) -> NaiveTime {
+        match self {
+            SettlementTime::Am => NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+            SettlementTime::Pm => NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+        }
+    }
+}
+
+// What one contract delivers on exercise
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
+pub struct Deliverable {
+    pub underlying: String,
+    #[tsify(type = "string")]
+    pub multiplier: Decimal,
+    #[tsify(type = "string")]
+    pub shares: Decimal,
+    #[tsify(type = "string")]
+    pub cash: Decimal,
+}
+
+impl Deliverable {
+    pub fn standard(underlying: &str) -> Self {
+        Deliverable {
+            underlying: underlying.to_owned(),
+            multiplier: Decimal::ONE_HUNDRED,
+            shares: Decimal::ONE_HUNDRED,
+            cash: Decimal::ZERO,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Tsify)]
 pub struct OptionContract {
+    // Root the option is listed under, AAPL1 or SPXW, where underlying_symbol is what
+    // it is an option on, AAPL or SPX
+    pub root: String,
+    // Decimals cross into JS as strings so no precision is lost, dates and times in ISO 8601
+    #[tsify(type = "string")]
     pub strike_price: Decimal,
     pub put_call: PutOrCall,
+    #[tsify(type = "string | null")]
     pub date: Option<NaiveDate>,
+    pub series: Option<ExpirationSeries>,
+    // Root was adjusted after a corporate action (AAPL1)
+    pub adjusted: bool,
+    // None when the root is adjusted but its terms were never loaded into the registry
+    pub deliverable: Option<Deliverable>,
+    pub exercise_style: Option<ExerciseStyle>,
+    pub settlement_type: Option<SettlementType>,
+    pub settlement_time: Option<SettlementTime>,
+    // Time the settlement price is set, see SettlementTime::expiration_time. None for
+    // options on futures, whose exchanges set their own.
+    #[tsify(type = "string | null")]
+    pub expiration_time: Option<NaiveTime>,
+    // IANA name of the zone expiration_time is in
+    pub time_zone: Option<String>,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Tsify)]
 pub struct FutureContract {
     pub continuous: bool,
     pub month: Option<String>,
     pub year: Option<String>,
+    #[tsify(type = "string | null")]
     pub expiration: Option<NaiveDate>,
 }
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Tsify)]
 pub struct Symbol {
     pub symbol_type: SymbolType,
     pub original_symbol: String,
@@ -47,135 +141,414 @@ pub struct Symbol {
     pub future_contract: Option<FutureContract>,
 }
 
-impl Default for SymbolType {
-    fn default() -> Self {
-        Self::Unknown
+// Contracts compare by what they are, not how they were written or what the registry
+// said about them: an option by expiration, strike, put/call and root, a future by its
+// expiration, so ESU20 and ESU0 are the same contract. The month and year are only
+// compared when they do not resolve to a date.
+impl OptionContract {
+    fn key(&self) -> (Option<NaiveDate>, Decimal, PutOrCall, &str) {
+        (self.date, self.strike_price, self.put_call, &self.root)
     }
 }
 
-pub fn parse_future_expiration(year: &Option<String>, month: &Option<String>) -> Option<NaiveDate> {
-    match (month, year) {
-        (None, None) => None,
-        (None, _) => None,
-        (_, None) => None,
-        (Some(month), Some(year)) => {
-            let month_index = FUTURE_MONTHS.iter().position(|x| x == month);
-            let current_year = Local::now().year();
-            let int_year = year.parse::<i32>().ok()?;
-
-            // TODO: revisit this, might be wrong
-            let year = match year.len() {
-                1 => {
-                    (current_year / 10 * 10) + int_year
-                },
-                2 => {
-                    (current_year / 100 * 100) + int_year
-                }
-                _ => { return None; }
-            };
+impl PartialEq for OptionContract {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
 
-            if let Some(month) = month_index {
-                let m= Local::now().date_naive().with_day(1)?.with_month(month as u32 + 1)?.with_year(year)?;
-                Some(m)
-            } else {
-                None
-            }
+impl Eq for OptionContract {}
+
+impl Hash for OptionContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl PartialOrd for OptionContract {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OptionContract {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl FutureContract {
+    fn key(&self) -> (Option<NaiveDate>, bool, Option<&str>, Option<&str>) {
+        match self.expiration {
+            Some(_) => (self.expiration, self.continuous, None, None),
+            None => (None, self.continuous, self.month.as_deref(), self.year.as_deref()),
+        }
+    }
+}
+
+impl PartialEq for FutureContract {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for FutureContract {}
+
+impl Hash for FutureContract {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl PartialOrd for FutureContract {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FutureContract {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+type SymbolKey<'a> = (
+    &'a str,
+    Option<NaiveDate>,
+    Option<Decimal>,
+    Option<PutOrCall>,
+    &'a str,
+    SymbolType,
+    Option<&'a str>,
+    Option<&'a FutureContract>,
+);
+
+impl Symbol {
+    // Parses and enriches the symbol from the default registry, failing instead of
+    // panicking on dates and strikes the grammar accepts but the calendar does not
+    pub fn parse(raw_symbol: &str) -> Result<Symbol, ParseError> {
+        SymbolRef::parse(raw_symbol).map(|symbol| symbol.to_owned())
+    }
+
+    // Like parse, resolving one and two digit future years against `reference` instead
+    // of today, e.g. the trade date when loading history
+    pub fn parse_at(raw_symbol: &str, reference: NaiveDate) -> Result<Symbol, ParseError> {
+        SymbolRef::parse_at(raw_symbol, reference).map(|symbol| symbol.to_owned())
+    }
+
+    // Expiration of the option, or of the future for futures and future options
+    pub fn expiration(&self) -> Option<NaiveDate> {
+        self.option_contract
+            .as_ref()
+            .and_then(|option| option.date)
+            .or_else(|| self.future_contract.as_ref().and_then(|future| future.expiration))
+    }
+
+    // The option root, or the underlying for symbols that are not options
+    pub fn root(&self) -> &str {
+        self.option_contract.as_ref().map_or(&self.underlying_symbol, |option| &option.root)
+    }
+
+    // Encodings that predate the option root leave it empty, meaning the underlying
+    #[cfg(any(feature = "protobuf", feature = "flatbuffers"))]
+    pub(crate) fn with_option_root(mut self) -> Self {
+        if let Some(option) = self.option_contract.as_mut().filter(|option| option.root.is_empty()) {
+            option.root = self.underlying_symbol.to_owned();
         }
+        self
+    }
+
+    // Underlying, expiry, strike and put/call first, so sorting lays out an option chain
+    fn key(&self) -> SymbolKey<'_> {
+        (
+            &self.underlying_symbol,
+            self.expiration(),
+            self.option_contract.as_ref().map(|option| option.strike_price),
+            self.option_contract.as_ref().map(|option| option.put_call),
+            self.root(),
+            self.symbol_type,
+            self.symbol_modifier.as_deref(),
+            self.future_contract.as_ref(),
+        )
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
     }
 }
 
+// Several contracts traded as one, a calendar or a vertical. Positive ratios are bought,
+// negative ones sold.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Spread {
+    pub legs: Vec<SpreadLeg>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SpreadLeg {
+    pub symbol: Symbol,
+    pub ratio: i32,
+}
+
+pub fn parse_future_expiration(year: &Option<String>, month: &Option<String>) -> Option<NaiveDate> {
+    future_expiration(year.as_deref(), month.as_deref(), today())
+}
+
+// Date that one and two digit future years are resolved against unless the caller
+// passes one
+fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+// First of the contract month. A year written with one digit is the nearest year ending
+// in it, so ESH0 is March 2020 up to 2024 and March 2030 from 2025 on; two digits are
+// the nearest year ending in them the same way. Ties go forward.
+pub fn future_expiration(year: Option<&str>, month: Option<&str>, reference: NaiveDate) -> Option<NaiveDate> {
+    let month = FUTURE_MONTHS.iter().position(|m| Some(*m) == month)? as u32 + 1;
+    let year = year?;
+    let span = match year.len() {
+        1 => 10,
+        2 => 100,
+        _ => return None,
+    };
+    let digits = year.parse::<i32>().ok()?;
+    let earliest = reference.year() - (span / 2 - 1);
+    NaiveDate::from_ymd_opt(earliest + (digits - earliest).rem_euclid(span), month, 1)
+}
+
 // Strike price, as the price x 1000, front padded with 0s to 8 digits
-pub fn parse_strike_price(strike_price: String) -> Decimal {
+pub fn parse_strike_price(strike_price: &str) -> Option<Decimal> {
     if strike_price.contains('.') {
-        Decimal::from_str(&strike_price).expect("REASON")
+        Decimal::from_str(strike_price).ok()
     } else { 
-        // TODO: expect
-        let num = strike_price.parse::<i64>().expect("String");
+        let num = strike_price.parse::<i64>().ok()?;
         match strike_price.len() {
             8 => {
-                Decimal::new(num, 3)
+                Some(Decimal::new(num, 3))
             }, 
             _ => {
-                Decimal::new(num, 0)
+                Some(Decimal::new(num, 0))
+            }
+        }
+    }
+}
+
+fn put_or_call(put_call: PutCall) -> PutOrCall {
+    match put_call {
+        PutCall::Put => PutOrCall::Put,
+        PutCall::Call => PutOrCall::Call,
+    }
+}
+
+// Parse-derived fields of an option contract. Registry-derived ones (series,
+// deliverable, exercise and settlement terms) are resolved by SymbolRef::to_owned.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptionContractRef {
+    pub strike_price: Decimal,
+    pub put_call: PutOrCall,
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FutureContractRef<'a> {
+    pub continuous: bool,
+    pub month: Option<&'a str>,
+    pub year: Option<&'a str>,
+    pub expiration: Option<NaiveDate>,
+}
+
+// Symbol borrowing every string from the parsed input, so hot paths can classify
+// symbols without allocating
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SymbolRef<'a> {
+    pub symbol_type: SymbolType,
+    pub original_symbol: &'a str,
+    // The root as written; to_owned resolves an option root to its underlying
+    pub underlying_symbol: &'a str,
+    pub symbol_modifier: Option<&'a str>,
+    pub option_contract: Option<OptionContractRef>,
+    pub future_contract: Option<FutureContractRef<'a>>,
+}
+
+impl<'a> SymbolRef<'a> {
+    pub fn parse(raw_symbol: &'a str) -> Result<Self, ParseError> {
+        SymbolRef::parse_at(raw_symbol, today())
+    }
+
+    pub fn parse_at(raw_symbol: &'a str, reference: NaiveDate) -> Result<Self, ParseError> {
+        SymbolRef::from_parse_result(parse_symbol(raw_symbol)?, reference)
+    }
+
+    // The underlying to_owned resolves to: an equity option's root may be an alias or
+    // adjusted root of it, SPXW for SPX or AAPL1 for AAPL
+    pub fn resolved_underlying<'r>(&'r self, registry: &'r ProductRegistry) -> &'r str {
+        match self.symbol_type {
+            SymbolType::StockOption => registry.underlying(self.underlying_symbol),
+            _ => self.underlying_symbol,
+        }
+    }
+
+    pub fn to_owned(&self) -> Symbol {
+        let option_contract = self.option_contract.map(|option| {
+            let root = self.underlying_symbol;
+            let registry = registry::default_registry();
+            let Some(date) = option.date else {
+                // An option on a future, which expires with the contract month
+                let terms = registry.future_option_terms(root);
+                return OptionContract {
+                    root: root.to_owned(),
+                    strike_price: option.strike_price,
+                    put_call: option.put_call,
+                    exercise_style: terms.map(|terms| terms.exercise_style),
+                    settlement_type: terms.map(|terms| terms.settlement_type),
+                    settlement_time: terms.map(|terms| terms.settlement_time),
+                    ..Default::default()
+                };
+            };
+
+            let terms = registry.option_terms(root);
+            OptionContract {
+                root: root.to_owned(),
+                strike_price: option.strike_price,
+                put_call: option.put_call,
+                date: Some(date),
+                series: Some(registry.classify_expiration(root, date)),
+                adjusted: registry.is_adjusted(root),
+                deliverable: registry.deliverable(root),
+                exercise_style: Some(terms.exercise_style),
+                settlement_type: Some(terms.settlement_type),
+                settlement_time: Some(terms.settlement_time),
+                expiration_time: Some(terms.settlement_time.expiration_time()),
+                time_zone: Some(registry::EXCHANGE_TIME_ZONE.to_owned()),
             }
+        });
+
+        let underlying_symbol = self.resolved_underlying(&registry::default_registry()).to_owned();
+        Symbol {
+            symbol_type: self.symbol_type,
+            original_symbol: self.original_symbol.to_owned(),
+            underlying_symbol,
+            symbol_modifier: self.symbol_modifier.map(str::to_owned),
+            option_contract,
+            future_contract: self.future_contract.map(|future| FutureContract {
+                continuous: future.continuous,
+                month: future.month.map(str::to_owned),
+                year: future.year.map(str::to_owned),
+                expiration: future.expiration,
+            }),
         }
     }
 }
 
-impl From<ParseResult> for Symbol {
-    fn from(val: ParseResult) -> Self {
+impl<'a> FutureContractRef<'a> {
+    fn resolve(symbol: FutureSymbol<&'a str>, reference: NaiveDate) -> Self {
+        FutureContractRef {
+            continuous: symbol.continuous,
+            month: symbol.month,
+            year: symbol.year,
+            expiration: future_expiration(symbol.year, symbol.month, reference),
+        }
+    }
+}
+
+impl<'a> From<FutureSymbol<&'a str>> for FutureContractRef<'a> {
+    fn from(symbol: FutureSymbol<&'a str>) -> Self {
+        FutureContractRef::resolve(symbol, today())
+    }
+}
+
+impl<'a> TryFrom<ParseResultRef<'a>> for SymbolRef<'a> {
+    type Error = ParseError;
+
+    fn try_from(val: ParseResultRef<'a>) -> Result<Self, Self::Error> {
+        SymbolRef::from_parse_result(val, today())
+    }
+}
+
+impl<'a> SymbolRef<'a> {
+    fn from_parse_result(val: ParseResultRef<'a>, reference: NaiveDate) -> Result<Self, ParseError> {
         match val {
-            ParseResult::UnknownSym(symbol) => Symbol {
+            ParseResult::UnknownSym(symbol) => Ok(SymbolRef {
                 original_symbol: symbol.original_symbol,
                 underlying_symbol: symbol.root_symbol,
+                symbol_modifier: symbol.symbol_modifier,
                 ..Default::default()
-            },
+            }),
             ParseResult::StockOptionsSym(symbol) => {
-                let date = symbol.date;
-                // TODO: try_into for this
-                let date =
-                    NaiveDate::parse_from_str(&date, "%y%m%d").expect("undefined date format");
-
-                let option_contract = OptionContract {
-                    strike_price: parse_strike_price(symbol.strike_price),
-                    put_call: match symbol.put_call {
-                        PutCall::Put => PutOrCall::Put,
-                        PutCall::Call => PutOrCall::Call,
-                    },
-                    date: Some(date),
-                };
-                Symbol {
-                    symbol_type: SymbolType::StockOption,
-                    original_symbol: symbol.original_symbol,
-                    underlying_symbol: symbol.root_symbol,
-                    option_contract: Some(option_contract),
-                    ..Default::default()
-                }
-            }
-            ParseResult::FutureSym(symbol) => {
-                let expiration = parse_future_expiration(&symbol.year, &symbol.month);
-
-                let future_contract = FutureContract {
-                    continuous: symbol.continuous,
-                    month: symbol.month,
-                    year: symbol.year,
-                    expiration
-                };
+                let invalid = |message: &str| ParseError::new(symbol.original_symbol, message);
+                let date = NaiveDate::parse_from_str(symbol.date, "%y%m%d").map_err(|_| invalid("undefined date format"))?;
+                let strike_price = parse_strike_price(symbol.strike_price).ok_or_else(|| invalid("invalid strike price"))?;
 
-                Symbol {
-                    symbol_type: SymbolType::Future,
+                Ok(SymbolRef {
+                    symbol_type: SymbolType::StockOption,
                     original_symbol: symbol.original_symbol,
                     underlying_symbol: symbol.root_symbol,
-                    future_contract: Some(future_contract),
+                    option_contract: Some(OptionContractRef {
+                        strike_price,
+                        put_call: put_or_call(symbol.put_call),
+                        date: Some(date),
+                    }),
                     ..Default::default()
-                }
+                })
             }
+            ParseResult::FutureSym(symbol) => Ok(SymbolRef {
+                symbol_type: SymbolType::Future,
+                original_symbol: symbol.original_symbol,
+                underlying_symbol: symbol.root_symbol,
+                symbol_modifier: symbol.symbol_modifier,
+                future_contract: Some(FutureContractRef::resolve(symbol, reference)),
+                ..Default::default()
+            }),
             ParseResult::FutureOptionsSym(symbol) => {
-                let option_contract = OptionContract {
-                    strike_price: parse_strike_price(symbol.strike_code),
-                    put_call: match symbol.put_call {
-                        PutCall::Put => PutOrCall::Put,
-                        PutCall::Call => PutOrCall::Call,
-                    },
-                    date: None,
-                };
-                let expiration = parse_future_expiration(&symbol.future_symbol.year, &symbol.future_symbol.month);
-                let future_contract = FutureContract {
-                    continuous: symbol.future_symbol.continuous,
-                    month: symbol.future_symbol.month,
-                    year: symbol.future_symbol.year,
-                    expiration
-                };
-                Symbol {
+                let strike_price = parse_strike_price(symbol.strike_code)
+                    .ok_or_else(|| ParseError::new(symbol.original_symbol, "invalid strike price"))?;
+
+                Ok(SymbolRef {
                     symbol_type: SymbolType::FutureOption,
                     original_symbol: symbol.original_symbol,
                     underlying_symbol: symbol.future_symbol.root_symbol,
-                    future_contract: Some(future_contract),
-                    option_contract: Some(option_contract),
-                    ..Default::default()
-                }
+                    // The grammar only reads a modifier on the future when the option
+                    // has one of its own, so the option's is the symbol's
+                    symbol_modifier: symbol.symbol_modifier,
+                    option_contract: Some(OptionContractRef {
+                        strike_price,
+                        put_call: put_or_call(symbol.put_call),
+                        date: None,
+                    }),
+                    future_contract: Some(FutureContractRef::resolve(symbol.future_symbol, reference)),
+                })
             }
-            ParseResult::Unused => Default::default(),
+            ParseResult::Unused => Ok(Default::default()),
         }
     }
 }
+
+impl TryFrom<ParseResult> for Symbol {
+    type Error = ParseError;
+
+    fn try_from(val: ParseResult) -> Result<Self, Self::Error> {
+        SymbolRef::try_from(val.as_borrowed()).map(|symbol| symbol.to_owned())
+    }
+}
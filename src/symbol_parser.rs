@@ -1,11 +1,20 @@
 
 
+use std::fmt;
+
 use pest::{iterators::Pair, Parser};
+use serde::Serialize;
 
+use crate::fast_parser;
+use crate::js::{self, SymbolError};
+use crate::normalize::{normalize, Normalization};
+use crate::registry;
+use crate::suggest::suggest;
 use crate::symbol::Symbol;
+use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PutCall {
     Put,
     Call
@@ -15,89 +24,283 @@ pub enum PutCall {
 #[grammar = "symbols.pest"]
 pub struct SymbolParser;
 
+// Parse results own their tokens by default. The hand-written parser produces the
+// same shape over &str slices of the input, see ParseResultRef.
 #[derive(Debug)]
-pub struct UnknownSymbol {
-    pub root_symbol: String,
-    pub symbol_modifier: Option<String>,
-    pub original_symbol: String,
+pub struct UnknownSymbol<S = String> {
+    pub root_symbol: S,
+    pub symbol_modifier: Option<S>,
+    pub original_symbol: S,
 }
 
 #[derive(Debug)]
-pub struct FutureSymbol {
+pub struct FutureSymbol<S = String> {
     pub continuous: bool,
-    pub root_symbol: String,
-    pub month: Option<String>,
-    pub year: Option<String>,
-    pub symbol_modifier: Option<String>,
-    pub original_symbol: String,
+    pub root_symbol: S,
+    pub month: Option<S>,
+    pub year: Option<S>,
+    pub symbol_modifier: Option<S>,
+    pub original_symbol: S,
 }
 
 #[derive(Debug)]
-pub struct StockOptionsSymbol {
-    pub root_symbol: String,
-    pub strike_price: String,
+pub struct StockOptionsSymbol<S = String> {
+    pub root_symbol: S,
+    pub strike_price: S,
     pub put_call: PutCall,
-    pub date: String,
-    pub original_symbol: String,
+    pub date: S,
+    pub original_symbol: S,
 }
 
 
 #[derive(Debug)]
-pub struct FutureOptionsSymbol {
-    pub future_symbol: FutureSymbol,
-    pub strike_code: String,
+pub struct FutureOptionsSymbol<S = String> {
+    pub future_symbol: FutureSymbol<S>,
+    pub strike_code: S,
     pub put_call: PutCall,
-    pub symbol_modifier: Option<String>,
-    pub original_symbol: String,
+    pub symbol_modifier: Option<S>,
+    pub original_symbol: S,
 }
 
 
 #[derive(Debug)]
-pub enum ParseResult {
-    UnknownSym(UnknownSymbol),
-    StockOptionsSym(StockOptionsSymbol),
-    FutureSym(FutureSymbol),
-    FutureOptionsSym(FutureOptionsSymbol),
+pub enum ParseResult<S = String> {
+    UnknownSym(UnknownSymbol<S>),
+    StockOptionsSym(StockOptionsSymbol<S>),
+    FutureSym(FutureSymbol<S>),
+    FutureOptionsSym(FutureOptionsSymbol<S>),
     Unused,
 
 }
 
-pub fn parse_symbol(raw_symbol: &str) -> Result<ParseResult, ()> {
+pub type ParseResultRef<'a> = ParseResult<&'a str>;
+
+impl FutureSymbol<&str> {
+    pub fn into_owned(self) -> FutureSymbol {
+        FutureSymbol {
+            continuous: self.continuous,
+            root_symbol: self.root_symbol.to_owned(),
+            month: self.month.map(str::to_owned),
+            year: self.year.map(str::to_owned),
+            symbol_modifier: self.symbol_modifier.map(str::to_owned),
+            original_symbol: self.original_symbol.to_owned(),
+        }
+    }
+}
+
+impl FutureSymbol {
+    pub fn as_borrowed(&self) -> FutureSymbol<&str> {
+        FutureSymbol {
+            continuous: self.continuous,
+            root_symbol: &self.root_symbol,
+            month: self.month.as_deref(),
+            year: self.year.as_deref(),
+            symbol_modifier: self.symbol_modifier.as_deref(),
+            original_symbol: &self.original_symbol,
+        }
+    }
+}
+
+impl ParseResult {
+    pub fn as_borrowed(&self) -> ParseResultRef<'_> {
+        match self {
+            ParseResult::UnknownSym(symbol) => ParseResult::UnknownSym(UnknownSymbol {
+                root_symbol: &symbol.root_symbol,
+                symbol_modifier: symbol.symbol_modifier.as_deref(),
+                original_symbol: &symbol.original_symbol,
+            }),
+            ParseResult::StockOptionsSym(symbol) => ParseResult::StockOptionsSym(StockOptionsSymbol {
+                root_symbol: &symbol.root_symbol,
+                strike_price: &symbol.strike_price,
+                put_call: symbol.put_call,
+                date: &symbol.date,
+                original_symbol: &symbol.original_symbol,
+            }),
+            ParseResult::FutureSym(symbol) => ParseResult::FutureSym(symbol.as_borrowed()),
+            ParseResult::FutureOptionsSym(symbol) => ParseResult::FutureOptionsSym(FutureOptionsSymbol {
+                future_symbol: symbol.future_symbol.as_borrowed(),
+                strike_code: &symbol.strike_code,
+                put_call: symbol.put_call,
+                symbol_modifier: symbol.symbol_modifier.as_deref(),
+                original_symbol: &symbol.original_symbol,
+            }),
+            ParseResult::Unused => ParseResult::Unused,
+        }
+    }
+}
+
+impl ParseResult<&str> {
+    pub fn into_owned(self) -> ParseResult {
+        match self {
+            ParseResult::UnknownSym(symbol) => ParseResult::UnknownSym(UnknownSymbol {
+                root_symbol: symbol.root_symbol.to_owned(),
+                symbol_modifier: symbol.symbol_modifier.map(str::to_owned),
+                original_symbol: symbol.original_symbol.to_owned(),
+            }),
+            ParseResult::StockOptionsSym(symbol) => ParseResult::StockOptionsSym(StockOptionsSymbol {
+                root_symbol: symbol.root_symbol.to_owned(),
+                strike_price: symbol.strike_price.to_owned(),
+                put_call: symbol.put_call,
+                date: symbol.date.to_owned(),
+                original_symbol: symbol.original_symbol.to_owned(),
+            }),
+            ParseResult::FutureSym(symbol) => ParseResult::FutureSym(symbol.into_owned()),
+            ParseResult::FutureOptionsSym(symbol) => ParseResult::FutureOptionsSym(FutureOptionsSymbol {
+                future_symbol: symbol.future_symbol.into_owned(),
+                strike_code: symbol.strike_code.to_owned(),
+                put_call: symbol.put_call,
+                symbol_modifier: symbol.symbol_modifier.map(str::to_owned),
+                original_symbol: symbol.original_symbol.to_owned(),
+            }),
+            ParseResult::Unused => ParseResult::Unused,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParseError {
+    pub input: String,
+    // Character offset into the input where parsing stopped, when known
+    pub position: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(input: &str, message: &str) -> Self {
+        ParseError {
+            input: input.to_owned(),
+            position: None,
+            message: message.to_owned(),
+        }
+    }
+
+    pub(crate) fn at(input: &str, position: usize, message: &str) -> Self {
+        ParseError {
+            input: input.to_owned(),
+            position: Some(position),
+            message: message.to_owned(),
+        }
+    }
+
+    fn invalid(input: &str) -> Self {
+        ParseError::new(input, "invalid symbol")
+    }
+
+    // The grammar runs over the reversed symbol, so pest positions count from the end
+    fn from_pest(input: &str, reversed_symbol: &str, err: pest::error::Error<Rule>) -> Self {
+        let reversed_offset = match err.location {
+            pest::error::InputLocation::Pos(pos) => pos,
+            pest::error::InputLocation::Span((start, _)) => start,
+        };
+        let consumed = reversed_symbol[..reversed_offset].chars().count();
+        let position = input.chars().count().saturating_sub(consumed + 1);
+        ParseError {
+            input: input.to_owned(),
+            position: Some(position),
+            message: err.variant.message().into_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(position) => write!(f, "could not parse symbol {:?} at position {position}: {}", self.input, self.message),
+            None => write!(f, "could not parse symbol {:?}: {}", self.input, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Every token is borrowed from the input, into_owned gives a ParseResult that outlives it
+pub fn parse_symbol(raw_symbol: &str) -> Result<ParseResultRef<'_>, ParseError> {
+    fast_parser::parse(raw_symbol)
+}
+
+// Reference implementation driven by symbols.pest. parse_symbol must agree with it
+// on every input, see tests/differential.rs.
+pub fn parse_symbol_pest(raw_symbol: &str) -> Result<ParseResult, ParseError> {
     let reversed_symbol = raw_symbol.chars().rev().collect::<String>();
 
-    let tokens = SymbolParser::parse(Rule::symbol, &reversed_symbol).map_err(|err| {
-        eprintln!("Error in parsing symbol: {err}");
-    })?;
+    let tokens = SymbolParser::parse(Rule::symbol, &reversed_symbol)
+        .map_err(|err| ParseError::from_pest(raw_symbol, &reversed_symbol, err))?;
 
     for tree in tokens  {
         // Get only the root symbols
         match tree.as_rule() {
             Rule::future_options_symbol | Rule::stock_options_symbol | Rule::future_symbol | Rule::unknown_symbol => {
-               return parse_tokens(tree);
+               return parse_tokens(tree).map_err(|_| ParseError::invalid(raw_symbol));
             }, 
             _ => {}
         }
     }
-    Err(())
+    Err(ParseError::invalid(raw_symbol))
 }
 
+#[derive(Debug)]
+pub struct LenientParse {
+    pub result: ParseResult,
+    // Empty when the input was already in strict form
+    pub normalizations: Vec<Normalization>,
+}
 
-#[wasm_bindgen]
-pub fn parse_symbol_js(raw_symbol: &str) -> Result<JsValue, String> {
-    match parse_symbol(raw_symbol) {
-        Ok(result) => {
-            let symbol: Symbol = result.into();
-            serde_wasm_bindgen::to_value(&symbol).map_err(|_| "Could not convert symbol to json".into())
+// Accepts lowercase, tabs, repeated spaces, surrounding whitespace and full-width
+// characters by normalizing the input before parsing it strictly
+pub fn parse_symbol_lenient(raw_symbol: &str) -> Result<LenientParse, ParseError> {
+    let normalized = normalize(raw_symbol);
+    let result = parse_symbol(&normalized.symbol)?.into_owned();
+    Ok(LenientParse {
+        result,
+        normalizations: normalized.applied,
+    })
+}
+
+fn to_js_error(raw_symbol: &str, err: ParseError) -> JsValue {
+    js::throw(SymbolError {
+        suggestions: suggest(raw_symbol, Some(&registry::default_registry())),
+        ..err.into()
+    })
+}
+
+// Throws a SymbolError, with its code, position and suggestions, when the symbol can't
+// be parsed
+#[wasm_bindgen(unchecked_return_type = "Symbol")]
+pub fn parse_symbol_js(raw_symbol: &str) -> Result<JsValue, JsValue> {
+    match parse_symbol(raw_symbol).map(ParseResult::into_owned).and_then(Symbol::try_from) {
+        Ok(symbol) => js::to_js(&symbol),
+        Err(err) => Err(to_js_error(raw_symbol, err))
+    }
+}
+
+// "Did you mean" candidates, also useful for symbols that parse but look mistyped
+#[wasm_bindgen(unchecked_return_type = "Suggestion[]")]
+pub fn suggest_symbol_js(raw_symbol: &str) -> Result<JsValue, JsValue> {
+    js::to_js(&suggest(raw_symbol, Some(&registry::default_registry())))
+}
+
+#[derive(Serialize, Tsify)]
+struct LenientSymbol {
+    symbol: Symbol,
+    normalizations: Vec<Normalization>,
+}
+
+#[wasm_bindgen(unchecked_return_type = "LenientSymbol")]
+pub fn parse_symbol_lenient_js(raw_symbol: &str) -> Result<JsValue, JsValue> {
+    match parse_symbol_lenient(raw_symbol) {
+        Ok(LenientParse { result, normalizations }) => match Symbol::try_from(result) {
+            Ok(symbol) => js::to_js(&LenientSymbol { symbol, normalizations }),
+            Err(err) => Err(to_js_error(raw_symbol, err)),
         },
-        Err(_) => Err("Could not parse symbol".to_owned())
-    } 
+        Err(err) => Err(to_js_error(raw_symbol, err))
+    }
 }
 
 fn reverse(str: String) -> String {
     str.chars().rev().collect()
 }
 
-fn find_by_rule<'a>(symbol_tokens: &'a [Pair<'a, Rule>], rule: Rule) -> Option<&Pair<'a, Rule>> {
+fn find_by_rule<'a>(symbol_tokens: &'a [Pair<'a, Rule>], rule: Rule) -> Option<&'a Pair<'a, Rule>> {
     symbol_tokens.iter().find(|sym| sym.as_rule() == rule)
 }
 
@@ -180,13 +383,11 @@ fn parse_tokens(tokens: Pair<Rule>) -> Result<ParseResult, ()> {
             let individual_contract = find_by_rule(&symbol_tokens, Rule::individual_contract);
             let continuous_modifier = find_by_rule(&symbol_tokens, Rule::continuous_modifier);
 
-            if individual_contract.is_some() {
-                let individual_contract = individual_contract.unwrap();
-
+            if let Some(individual_contract) = individual_contract {
                 let (root_symbol, future_month, year) = parse_individual_contact(individual_contract)?;
 
 
-                return Ok(ParseResult::FutureSym(FutureSymbol {
+                Ok(ParseResult::FutureSym(FutureSymbol {
                     continuous: continuous_modifier.map_or_else( || false, |_| true),
                     root_symbol,
                     year: Some(year), 
@@ -199,7 +400,7 @@ fn parse_tokens(tokens: Pair<Rule>) -> Result<ParseResult, ()> {
                     return Err(());
                 }
 
-                return Ok(ParseResult::FutureSym(FutureSymbol {
+                Ok(ParseResult::FutureSym(FutureSymbol {
                     continuous: true,
                     root_symbol: reverse(root_symbol.unwrap().as_span().as_str().to_owned()),
                     year: None, 
@@ -211,7 +412,6 @@ fn parse_tokens(tokens: Pair<Rule>) -> Result<ParseResult, ()> {
         },
         Rule::future_options_symbol => {
             let symbol_tokens: Vec<Pair<Rule>> = tokens.into_inner().collect();
-            println!("{:?}", symbol_tokens);
             let future_symbol = find_by_rule(&symbol_tokens, Rule::future_symbol);
             let symbol_modifier = find_by_rule(&symbol_tokens, Rule::symbol_modifier);
 
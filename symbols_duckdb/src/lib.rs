@@ -0,0 +1,249 @@
+use std::borrow::Cow;
+use std::error::Error;
+
+use chrono::{NaiveDate, NaiveTime, Timelike};
+use duckdb::core::{DataChunkHandle, FlatVector, Inserter, LogicalTypeHandle, LogicalTypeId, StructVector};
+use duckdb::ffi::duckdb_string_t;
+use duckdb::types::DuckString;
+use duckdb::vscalar::{ScalarFunctionSignature, VScalar};
+use duckdb::vtab::arrow::WritableVector;
+use duckdb::Connection;
+use rust_decimal::Decimal;
+
+use symbols::bulk;
+use symbols::symbol::{OptionContract, Symbol};
+use symbols::symbol_parser::ParseError;
+
+// Scalar functions parsing a VARCHAR column into a STRUCT shaped like Symbol:
+//
+//   parse_symbol(sym)      errors on a symbol that does not parse
+//   try_parse_symbol(sym)  NULL instead
+//
+// Like a failed CAST, the parse_symbol error fails the whole query rather than the row,
+// and names the first bad symbol of the chunk it was found in.
+//
+// DuckDB hands each call a chunk of up to 2048 rows. A chunk is parsed with
+// bulk::parse_many, so a symbol repeated within it is parsed once, and the struct is
+// written straight into DuckDB's vectors.
+
+// Strikes are quoted to a tenth of a cent at most, as in OCC symbols
+const STRIKE_SCALE: u8 = 3;
+// Deliverable multipliers, shares and cash
+const AMOUNT_SCALE: u8 = 6;
+
+pub fn register(db: &Connection) -> duckdb::Result<()> {
+    db.register_scalar_function::<ParseSymbol>("parse_symbol")?;
+    db.register_scalar_function::<TryParseSymbol>("try_parse_symbol")
+}
+
+// The STRUCT both functions return. Enum fields are the variant names, as in the serde
+// form of Symbol.
+pub fn symbol_type() -> LogicalTypeHandle {
+    let text = || LogicalTypeHandle::from(LogicalTypeId::Varchar);
+    let amount = || LogicalTypeHandle::decimal(18, AMOUNT_SCALE);
+    let deliverable = LogicalTypeHandle::struct_type(&[
+        ("underlying", text()),
+        ("multiplier", amount()),
+        ("shares", amount()),
+        ("cash", amount()),
+    ]);
+    let option_contract = LogicalTypeHandle::struct_type(&[
+        ("root", text()),
+        ("strike_price", LogicalTypeHandle::decimal(18, STRIKE_SCALE)),
+        ("put_call", text()),
+        ("date", LogicalTypeId::Date.into()),
+        ("series", text()),
+        ("adjusted", LogicalTypeId::Boolean.into()),
+        ("deliverable", deliverable),
+        ("exercise_style", text()),
+        ("settlement_type", text()),
+        ("settlement_time", text()),
+        ("expiration_time", LogicalTypeId::Time.into()),
+        ("time_zone", text()),
+    ]);
+    let future_contract = LogicalTypeHandle::struct_type(&[
+        ("continuous", LogicalTypeId::Boolean.into()),
+        ("month", text()),
+        ("year", text()),
+        ("expiration", LogicalTypeId::Date.into()),
+    ]);
+    LogicalTypeHandle::struct_type(&[
+        ("symbol_type", text()),
+        ("original_symbol", text()),
+        ("underlying_symbol", text()),
+        ("symbol_modifier", text()),
+        ("option_contract", option_contract),
+        ("future_contract", future_contract),
+    ])
+}
+
+struct ParseSymbol;
+
+impl VScalar for ParseSymbol {
+    type State = ();
+
+    fn invoke(_: &(), input: &mut DataChunkHandle, output: &mut dyn WritableVector) -> Result<(), Box<dyn Error>> {
+        let parsed = parse_chunk(input)?;
+        if let Some(Some(Err(err))) = parsed.iter().find(|result| matches!(result, Some(Err(_)))) {
+            return Err(err.to_string().into());
+        }
+        write_symbols(&parsed, &mut output.struct_vector());
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        vec![ScalarFunctionSignature::exact(vec![LogicalTypeId::Varchar.into()], symbol_type())]
+    }
+}
+
+struct TryParseSymbol;
+
+impl VScalar for TryParseSymbol {
+    type State = ();
+
+    fn invoke(_: &(), input: &mut DataChunkHandle, output: &mut dyn WritableVector) -> Result<(), Box<dyn Error>> {
+        write_symbols(&parse_chunk(input)?, &mut output.struct_vector());
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        vec![ScalarFunctionSignature::exact(vec![LogicalTypeId::Varchar.into()], symbol_type())]
+    }
+}
+
+// One per row, None for NULL
+type Parsed = Option<Result<Symbol, ParseError>>;
+
+fn parse_chunk(input: &mut DataChunkHandle) -> Result<Vec<Parsed>, Box<dyn Error>> {
+    let rows = input.len();
+    let mut vector = input.flat_vector(0);
+    let nulls: Vec<bool> = (0..rows).map(|row| vector.row_is_null(row as u64)).collect();
+    // SAFETY: the only argument is VARCHAR, whose rows are duckdb_string_t, and the
+    // chunk holds `rows` of them. Short strings are stored inline in the
+    // duckdb_string_t, so the symbols borrow the chunk's slots rather than copies of them.
+    let strings = unsafe { vector.as_mut_slice_with_len::<duckdb_string_t>(rows) };
+    let raw_symbols: Vec<Option<Cow<'_, str>>> = strings
+        .iter_mut()
+        .zip(nulls)
+        .map(|(string, null)| (!null).then(|| DuckString::new(string).as_str()))
+        .collect();
+    let mut parsed = bulk::parse_many(raw_symbols.iter().flatten());
+    Ok(raw_symbols.iter().map(|raw_symbol| raw_symbol.as_ref().and_then(|_| parsed.next())).collect())
+}
+
+fn write_symbols(parsed: &[Parsed], output: &mut StructVector<'_>) {
+    let rows = parsed.len();
+    let mut option_vector = output.struct_vector_child(4);
+    let mut deliverable_vector = option_vector.struct_vector_child(6);
+    let mut future_vector = output.struct_vector_child(5);
+    let mut symbol = Columns::new(output, &[0, 1, 2, 3], rows);
+    let mut option = Columns::new(&option_vector, &[0, 1, 2, 3, 4, 5, 7, 8, 9, 10, 11], rows);
+    let mut deliverable = Columns::new(&deliverable_vector, &[0, 1, 2, 3], rows);
+    let mut future = Columns::new(&future_vector, &[0, 1, 2, 3], rows);
+    let (mut symbol_nulls, mut option_nulls, mut deliverable_nulls, mut future_nulls) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+
+    for (row, result) in parsed.iter().enumerate() {
+        let parsed = result.as_ref().and_then(|result| result.as_ref().ok());
+        if parsed.is_none() {
+            symbol_nulls.push(row);
+        }
+        symbol.text(0, row, parsed.map(|symbol| format!("{:?}", symbol.symbol_type)).as_deref());
+        symbol.text(1, row, parsed.map(|symbol| symbol.original_symbol.as_str()));
+        symbol.text(2, row, parsed.map(|symbol| symbol.underlying_symbol.as_str()));
+        symbol.text(3, row, parsed.and_then(|symbol| symbol.symbol_modifier.as_deref()));
+
+        let contract: Option<&OptionContract> = parsed.and_then(|symbol| symbol.option_contract.as_ref());
+        if contract.is_none() {
+            option_nulls.push(row);
+        }
+        option.text(0, row, contract.map(|option| option.root.as_str()));
+        option.value(1, row, contract.map(|option| scaled(option.strike_price, STRIKE_SCALE)));
+        option.text(2, row, contract.map(|option| format!("{:?}", option.put_call)).as_deref());
+        option.value(3, row, contract.and_then(|option| option.date).map(days));
+        option.text(4, row, contract.and_then(|option| option.series).map(|series| format!("{series:?}")).as_deref());
+        option.value(5, row, contract.map(|option| option.adjusted));
+        option.text(7, row, contract.and_then(|option| option.exercise_style).map(|style| format!("{style:?}")).as_deref());
+        option.text(8, row, contract.and_then(|option| option.settlement_type).map(|kind| format!("{kind:?}")).as_deref());
+        option.text(9, row, contract.and_then(|option| option.settlement_time).map(|time| format!("{time:?}")).as_deref());
+        option.value(10, row, contract.and_then(|option| option.expiration_time).map(micros));
+        option.text(11, row, contract.and_then(|option| option.time_zone.as_deref()));
+
+        let terms = contract.and_then(|option| option.deliverable.as_ref());
+        if terms.is_none() {
+            deliverable_nulls.push(row);
+        }
+        deliverable.text(0, row, terms.map(|terms| terms.underlying.as_str()));
+        deliverable.value(1, row, terms.map(|terms| scaled(terms.multiplier, AMOUNT_SCALE)));
+        deliverable.value(2, row, terms.map(|terms| scaled(terms.shares, AMOUNT_SCALE)));
+        deliverable.value(3, row, terms.map(|terms| scaled(terms.cash, AMOUNT_SCALE)));
+
+        let contract = parsed.and_then(|symbol| symbol.future_contract.as_ref());
+        if contract.is_none() {
+            future_nulls.push(row);
+        }
+        future.value(0, row, contract.map(|future| future.continuous));
+        future.text(1, row, contract.and_then(|future| future.month.as_deref()));
+        future.text(2, row, contract.and_then(|future| future.year.as_deref()));
+        future.value(3, row, contract.and_then(|future| future.expiration).map(days));
+    }
+
+    // Every field of a NULL struct is written NULL above, then the struct itself
+    option_nulls.into_iter().for_each(|row| option_vector.set_null(row));
+    deliverable_nulls.into_iter().for_each(|row| deliverable_vector.set_null(row));
+    future_nulls.into_iter().for_each(|row| future_vector.set_null(row));
+    symbol_nulls.into_iter().for_each(|row| output.set_null(row));
+}
+
+// The scalar fields of one STRUCT, by child index
+struct Columns<'a> {
+    vectors: Vec<(usize, FlatVector<'a>)>,
+}
+
+impl<'a> Columns<'a> {
+    fn new(parent: &StructVector<'a>, children: &[usize], rows: usize) -> Self {
+        Columns {
+            vectors: children.iter().map(|&child| (child, parent.child(child, rows))).collect(),
+        }
+    }
+
+    fn vector(&mut self, child: usize) -> &mut FlatVector<'a> {
+        let (_, vector) = self.vectors.iter_mut().find(|(index, _)| *index == child).expect("declared child");
+        vector
+    }
+
+    fn text(&mut self, child: usize, row: usize, value: Option<&str>) {
+        let vector = self.vector(child);
+        match value {
+            Some(value) => vector.insert(row, value),
+            None => vector.set_null(row),
+        }
+    }
+
+    // T must be the child's physical type: i64 for DECIMAL(18, s) and TIME, i32 for
+    // DATE, bool for BOOLEAN
+    fn value<T: Copy>(&mut self, child: usize, row: usize, value: Option<T>) {
+        let vector = self.vector(child);
+        match value {
+            // SAFETY: the callers in write_symbols pass the physical type of each child
+            // as declared in symbol_type(), so the vector's data is an array of T. The
+            // slice spans the vector's capacity and indexing it is bounds checked.
+            Some(value) => unsafe { vector.as_mut_slice::<T>()[row] = value },
+            None => vector.set_null(row),
+        }
+    }
+}
+
+fn scaled(value: Decimal, scale: u8) -> i64 {
+    let mut value = value.round_dp(scale.into());
+    value.rescale(scale.into());
+    value.mantissa() as i64
+}
+
+fn days(date: NaiveDate) -> i32 {
+    (date - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32
+}
+
+fn micros(time: NaiveTime) -> i64 {
+    time.num_seconds_from_midnight() as i64 * 1_000_000 + (time.nanosecond() / 1_000) as i64
+}
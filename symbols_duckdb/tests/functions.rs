@@ -0,0 +1,111 @@
+use duckdb::Connection;
+use symbols::symbol::Symbol;
+
+fn db() -> Connection {
+    let db = Connection::open_in_memory().unwrap();
+    symbols_duckdb::register(&db).unwrap();
+    db
+}
+
+fn strings(db: &Connection, sql: &str) -> Vec<Option<String>> {
+    let mut statement = db.prepare(sql).unwrap();
+    let rows = statement.query_map([], |row| row.get(0)).unwrap();
+    rows.map(Result::unwrap).collect()
+}
+
+#[test]
+fn parses_into_a_struct() {
+    let db = db();
+    let row: (String, String, String, String, String, String) = db
+        .query_row(
+            "SELECT s.symbol_type, s.underlying_symbol, s.option_contract.strike_price::VARCHAR,
+                    s.option_contract.put_call, s.option_contract.date::VARCHAR, s.option_contract.expiration_time::VARCHAR
+             FROM (SELECT parse_symbol('MSFT 110122C27.5') AS s)",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?)),
+        )
+        .unwrap();
+    assert_eq!(
+        row,
+        (
+            "StockOption".into(),
+            "MSFT".into(),
+            "27.500".into(),
+            "Call".into(),
+            "2011-01-22".into(),
+            "16:00:00".into()
+        )
+    );
+
+    let future: (String, String, bool) = db
+        .query_row(
+            "SELECT s.future_contract.month || s.future_contract.year, s.future_contract.expiration::VARCHAR,
+                    s.option_contract IS NULL
+             FROM (SELECT parse_symbol('ESU20') AS s)",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(future, ("U20".into(), "2020-09-01".into(), true));
+}
+
+// Field names and order follow the serde form of Symbol
+#[test]
+fn type_matches_symbol() {
+    let db = db();
+    let symbol = serde_json::to_value(Symbol::parse("MSFT 110122C27.5").unwrap()).unwrap();
+    let columns = |sql: &str| {
+        let mut statement = db.prepare(sql).unwrap();
+        // Result columns are only known once the statement has run
+        drop(statement.query([]).unwrap());
+        statement.column_names()
+    };
+    let keys = |value: &serde_json::Value| value.as_object().unwrap().keys().cloned().collect::<Vec<_>>();
+
+    assert_eq!(columns("SELECT unnest(parse_symbol('MSFT 110122C27.5'))"), keys(&symbol));
+    assert_eq!(
+        columns("SELECT unnest(parse_symbol('MSFT 110122C27.5').option_contract)"),
+        keys(&symbol["option_contract"])
+    );
+}
+
+#[test]
+fn bad_symbols() {
+    let db = db();
+    assert_eq!(strings(&db, "SELECT try_parse_symbol(s).underlying_symbol FROM (VALUES ('msft!'), (NULL), ('AAPL')) t(s)"), [
+        None,
+        None,
+        Some("AAPL".to_owned())
+    ]);
+    assert_eq!(strings(&db, "SELECT parse_symbol(NULL).underlying_symbol"), [None]);
+
+    let err = db.query_row("SELECT parse_symbol('msft!')", [], |_| Ok(())).unwrap_err();
+    assert!(err.to_string().contains("msft!"), "{err}");
+
+    // One bad row fails the query, not just its own row
+    let err = db
+        .prepare("SELECT parse_symbol(s) FROM (VALUES ('AAPL'), ('msft!'), ('ESU20')) t(s)")
+        .and_then(|mut stmt| stmt.query_map([], |_| Ok(()))?.collect::<duckdb::Result<Vec<()>>>())
+        .unwrap_err();
+    assert!(err.to_string().contains("msft!"), "{err}");
+}
+
+// More rows than one DuckDB chunk, with repeats and NULLs mixed in
+#[test]
+fn parses_whole_columns() {
+    let db = db();
+    let (rows, options, nulls, strikes): (i64, i64, i64, String) = db
+        .query_row(
+            "SELECT count(*), count(p.option_contract), count(*) - count(p), sum(p.option_contract.strike_price)::VARCHAR
+             FROM (
+                 SELECT try_parse_symbol(CASE i % 4 WHEN 0 THEN 'ESU20' WHEN 1 THEN 'SPY251219C00650000'
+                                                    WHEN 2 THEN 'ESU20 P2700' END) AS p
+                 FROM range(100000) r(i)
+             )",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .unwrap();
+    assert_eq!((rows, options, nulls), (100_000, 50_000, 25_000));
+    assert_eq!(strikes, "83750000.000");
+}
@@ -0,0 +1,203 @@
+/* Generated by cbindgen from src/lib.rs, do not edit by hand. */
+
+#ifndef SYMBOLS_H
+#define SYMBOLS_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Result of every fallible call. Values are stable across releases.
+typedef enum SymbolsStatus {
+  SYMBOLS_STATUS_OK = 0,
+  SYMBOLS_STATUS_NULL_POINTER = 1,
+  SYMBOLS_STATUS_INVALID_UTF8 = 2,
+  SYMBOLS_STATUS_PARSE_ERROR = 3,
+  SYMBOLS_STATUS_PANIC = 4,
+} SymbolsStatus;
+
+typedef enum SymbolsSymbolType {
+  SYMBOLS_SYMBOL_TYPE_STOCK = 0,
+  SYMBOLS_SYMBOL_TYPE_FUTURE = 1,
+  SYMBOLS_SYMBOL_TYPE_FUTURE_OPTION = 2,
+  SYMBOLS_SYMBOL_TYPE_STOCK_OPTION = 3,
+  SYMBOLS_SYMBOL_TYPE_UNKNOWN = 4,
+} SymbolsSymbolType;
+
+typedef enum SymbolsPutCall {
+  SYMBOLS_PUT_CALL_PUT = 0,
+  SYMBOLS_PUT_CALL_CALL = 1,
+} SymbolsPutCall;
+
+typedef enum SymbolsExpirationSeries {
+  SYMBOLS_EXPIRATION_SERIES_NONE = 0,
+  SYMBOLS_EXPIRATION_SERIES_MONTHLY = 1,
+  SYMBOLS_EXPIRATION_SERIES_WEEKLY = 2,
+  SYMBOLS_EXPIRATION_SERIES_QUARTERLY = 3,
+  SYMBOLS_EXPIRATION_SERIES_END_OF_MONTH = 4,
+  SYMBOLS_EXPIRATION_SERIES_DAILY = 5,
+} SymbolsExpirationSeries;
+
+typedef enum SymbolsExerciseStyle {
+  SYMBOLS_EXERCISE_STYLE_NONE = 0,
+  SYMBOLS_EXERCISE_STYLE_AMERICAN = 1,
+  SYMBOLS_EXERCISE_STYLE_EUROPEAN = 2,
+} SymbolsExerciseStyle;
+
+typedef enum SymbolsSettlementType {
+  SYMBOLS_SETTLEMENT_TYPE_NONE = 0,
+  SYMBOLS_SETTLEMENT_TYPE_PHYSICAL = 1,
+  SYMBOLS_SETTLEMENT_TYPE_CASH = 2,
+} SymbolsSettlementType;
+
+typedef enum SymbolsSettlementTime {
+  SYMBOLS_SETTLEMENT_TIME_NONE = 0,
+  SYMBOLS_SETTLEMENT_TIME_AM = 1,
+  SYMBOLS_SETTLEMENT_TIME_PM = 2,
+} SymbolsSettlementTime;
+
+// Opaque parse failure, released with symbols_error_free.
+typedef struct SymbolsError SymbolsError;
+
+// Opaque parsed symbol, released with symbols_free.
+typedef struct SymbolsSymbol SymbolsSymbol;
+
+// Calendar date, all zero when absent.
+typedef struct SymbolsDate {
+  int32_t year;
+  uint32_t month;
+  uint32_t day;
+} SymbolsDate;
+
+// Wall clock time in the contract's time_zone, all zero when absent.
+typedef struct SymbolsTime {
+  uint32_t hour;
+  uint32_t minute;
+} SymbolsTime;
+
+// Strings are NUL-terminated, NULL when absent, and owned by the SymbolsSymbol they
+// came from.
+typedef struct SymbolsOptionContract {
+  // Exact decimal text, e.g. "27.5"
+  const char *strike_price;
+  double strike_price_value;
+  enum SymbolsPutCall put_call;
+  bool has_date;
+  struct SymbolsDate date;
+  enum SymbolsExpirationSeries series;
+  bool adjusted;
+  // Deliverable, NULL underlying when the root's terms are unknown
+  const char *deliverable_underlying;
+  double multiplier;
+  double shares;
+  double cash;
+  enum SymbolsExerciseStyle exercise_style;
+  enum SymbolsSettlementType settlement_type;
+  enum SymbolsSettlementTime settlement_time;
+  bool has_expiration_time;
+  struct SymbolsTime expiration_time;
+  const char *time_zone;
+} SymbolsOptionContract;
+
+typedef struct SymbolsFutureContract {
+  bool continuous;
+  const char *month;
+  const char *year;
+  bool has_expiration;
+  struct SymbolsDate expiration;
+} SymbolsFutureContract;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parses a NUL-terminated symbol. On SYMBOLS_STATUS_OK `*out` holds a symbol to release
+// with symbols_free. On failure `*out` is NULL and, when `error` is not NULL, `*error`
+// holds the details to release with symbols_error_free.
+//
+// # Safety
+// `input` must be NULL or a NUL-terminated string, `out` a writable pointer, `error` NULL
+// or a writable pointer.
+enum SymbolsStatus symbols_parse(const char *input,
+                                 struct SymbolsSymbol **out,
+                                 struct SymbolsError **error);
+
+// # Safety
+// `symbol` must be NULL or returned by symbols_parse and not yet freed.
+void symbols_free(struct SymbolsSymbol *symbol);
+
+// # Safety
+// `error` must be NULL or returned through symbols_parse and not yet freed.
+void symbols_error_free(struct SymbolsError *error);
+
+// Full description, e.g. `could not parse symbol "ES-U0" at position 2: unexpected character`.
+//
+// # Safety
+// `error` must be a live SymbolsError.
+const char *symbols_error_message(const struct SymbolsError *error);
+
+// Reason alone, e.g. `unexpected character`.
+//
+// # Safety
+// `error` must be a live SymbolsError.
+const char *symbols_error_reason(const struct SymbolsError *error);
+
+// Character offset where parsing stopped, -1 when unknown.
+//
+// # Safety
+// `error` must be a live SymbolsError.
+int64_t symbols_error_position(const struct SymbolsError *error);
+
+// # Safety
+// `symbol` must be a live SymbolsSymbol.
+enum SymbolsSymbolType symbols_symbol_type(const struct SymbolsSymbol *symbol);
+
+// # Safety
+// `symbol` must be a live SymbolsSymbol.
+const char *symbols_original_symbol(const struct SymbolsSymbol *symbol);
+
+// # Safety
+// `symbol` must be a live SymbolsSymbol.
+const char *symbols_underlying_symbol(const struct SymbolsSymbol *symbol);
+
+// NULL when the symbol has no modifier.
+//
+// # Safety
+// `symbol` must be a live SymbolsSymbol.
+const char *symbols_symbol_modifier(const struct SymbolsSymbol *symbol);
+
+// NULL for symbols that are not options. Valid until the symbol is freed.
+//
+// # Safety
+// `symbol` must be a live SymbolsSymbol.
+const struct SymbolsOptionContract *symbols_option_contract(const struct SymbolsSymbol *symbol);
+
+// NULL for symbols that are not futures or future options. Valid until the symbol is freed.
+//
+// # Safety
+// `symbol` must be a live SymbolsSymbol.
+const struct SymbolsFutureContract *symbols_future_contract(const struct SymbolsSymbol *symbol);
+
+// Orders by underlying, expiry, strike then put/call: negative, zero or positive. Two
+// spellings of the same contract (ESU20, ESU0) compare equal.
+//
+// # Safety
+// `a` and `b` must be live SymbolsSymbols.
+int32_t symbols_compare(const struct SymbolsSymbol *a, const struct SymbolsSymbol *b);
+
+// Consistent with symbols_compare: equal symbols hash equal within one process.
+//
+// # Safety
+// `symbol` must be a live SymbolsSymbol.
+uint64_t symbols_hash(const struct SymbolsSymbol *symbol);
+
+// Version of this library, e.g. "0.1.0".
+const char *symbols_version(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SYMBOLS_H */
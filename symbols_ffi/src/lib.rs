@@ -0,0 +1,453 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::{c_char, CStr, CString};
+use std::hash::{Hash, Hasher};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use rust_decimal::prelude::ToPrimitive;
+use symbols::symbol::{
+    ExerciseStyle, ExpirationSeries, FutureContract, OptionContract, PutOrCall, SettlementTime, SettlementType, Symbol,
+    SymbolType,
+};
+use symbols::symbol_parser::ParseError;
+
+/// Result of every fallible call. Values are stable across releases.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolsStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    ParseError = 3,
+    Panic = 4,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolsSymbolType {
+    Stock = 0,
+    Future = 1,
+    FutureOption = 2,
+    StockOption = 3,
+    Unknown = 4,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolsPutCall {
+    Put = 0,
+    Call = 1,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolsExpirationSeries {
+    None = 0,
+    Monthly = 1,
+    Weekly = 2,
+    Quarterly = 3,
+    EndOfMonth = 4,
+    Daily = 5,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolsExerciseStyle {
+    None = 0,
+    American = 1,
+    European = 2,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolsSettlementType {
+    None = 0,
+    Physical = 1,
+    Cash = 2,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolsSettlementTime {
+    None = 0,
+    Am = 1,
+    Pm = 2,
+}
+
+/// Calendar date, all zero when absent.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SymbolsDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// Wall clock time in the contract's time_zone, all zero when absent.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SymbolsTime {
+    pub hour: u32,
+    pub minute: u32,
+}
+
+/// Strings are NUL-terminated, NULL when absent, and owned by the SymbolsSymbol they
+/// came from.
+#[repr(C)]
+pub struct SymbolsOptionContract {
+    /// Exact decimal text, e.g. "27.5"
+    pub strike_price: *const c_char,
+    pub strike_price_value: f64,
+    pub put_call: SymbolsPutCall,
+    pub has_date: bool,
+    pub date: SymbolsDate,
+    pub series: SymbolsExpirationSeries,
+    pub adjusted: bool,
+    /// Deliverable, NULL underlying when the root's terms are unknown
+    pub deliverable_underlying: *const c_char,
+    pub multiplier: f64,
+    pub shares: f64,
+    pub cash: f64,
+    pub exercise_style: SymbolsExerciseStyle,
+    pub settlement_type: SymbolsSettlementType,
+    pub settlement_time: SymbolsSettlementTime,
+    pub has_expiration_time: bool,
+    pub expiration_time: SymbolsTime,
+    pub time_zone: *const c_char,
+}
+
+#[repr(C)]
+pub struct SymbolsFutureContract {
+    pub continuous: bool,
+    pub month: *const c_char,
+    pub year: *const c_char,
+    pub has_expiration: bool,
+    pub expiration: SymbolsDate,
+}
+
+/// Opaque parsed symbol, released with symbols_free.
+pub struct SymbolsSymbol {
+    symbol: Symbol,
+    original_symbol: CString,
+    underlying_symbol: CString,
+    symbol_modifier: Option<CString>,
+    // Backing storage for the pointers in option and future
+    _strings: Vec<CString>,
+    option: Option<SymbolsOptionContract>,
+    future: Option<SymbolsFutureContract>,
+}
+
+/// Opaque parse failure, released with symbols_error_free.
+pub struct SymbolsError {
+    message: CString,
+    reason: CString,
+    position: i64,
+}
+
+// Parsed symbols are ASCII and never contain NUL, but the input may
+fn c_string(value: &str) -> CString {
+    CString::new(value.replace('\0', "")).unwrap_or_default()
+}
+
+fn date(date: Option<NaiveDate>) -> (bool, SymbolsDate) {
+    match date {
+        Some(date) => (true, SymbolsDate { year: date.year(), month: date.month(), day: date.day() }),
+        None => (false, SymbolsDate::default()),
+    }
+}
+
+fn time(time: Option<NaiveTime>) -> (bool, SymbolsTime) {
+    match time {
+        Some(time) => (true, SymbolsTime { hour: time.hour(), minute: time.minute() }),
+        None => (false, SymbolsTime::default()),
+    }
+}
+
+impl SymbolsSymbol {
+    fn new(symbol: Symbol) -> Box<Self> {
+        let mut strings = Vec::new();
+        let mut keep = |value: &str| {
+            let value = c_string(value);
+            // The heap buffer does not move when the CString is moved into the Vec
+            let pointer = value.as_ptr();
+            strings.push(value);
+            pointer
+        };
+
+        let option = symbol.option_contract.as_ref().map(|option| option_contract(option, &mut keep));
+        let future = symbol.future_contract.as_ref().map(|future| future_contract(future, &mut keep));
+        Box::new(SymbolsSymbol {
+            original_symbol: c_string(&symbol.original_symbol),
+            underlying_symbol: c_string(&symbol.underlying_symbol),
+            symbol_modifier: symbol.symbol_modifier.as_deref().map(c_string),
+            _strings: strings,
+            option,
+            future,
+            symbol,
+        })
+    }
+}
+
+fn option_contract(option: &OptionContract, keep: &mut impl FnMut(&str) -> *const c_char) -> SymbolsOptionContract {
+    let (has_date, date) = date(option.date);
+    let (has_expiration_time, expiration_time) = time(option.expiration_time);
+    let deliverable = option.deliverable.as_ref();
+    let number = |value: Option<rust_decimal::Decimal>| value.and_then(|value| value.to_f64()).unwrap_or(f64::NAN);
+    SymbolsOptionContract {
+        strike_price: keep(&option.strike_price.normalize().to_string()),
+        strike_price_value: option.strike_price.to_f64().unwrap_or(f64::NAN),
+        put_call: match option.put_call {
+            PutOrCall::Put => SymbolsPutCall::Put,
+            PutOrCall::Call => SymbolsPutCall::Call,
+        },
+        has_date,
+        date,
+        series: match option.series {
+            None => SymbolsExpirationSeries::None,
+            Some(ExpirationSeries::Monthly) => SymbolsExpirationSeries::Monthly,
+            Some(ExpirationSeries::Weekly) => SymbolsExpirationSeries::Weekly,
+            Some(ExpirationSeries::Quarterly) => SymbolsExpirationSeries::Quarterly,
+            Some(ExpirationSeries::EndOfMonth) => SymbolsExpirationSeries::EndOfMonth,
+            Some(ExpirationSeries::Daily) => SymbolsExpirationSeries::Daily,
+        },
+        adjusted: option.adjusted,
+        deliverable_underlying: deliverable.map_or(ptr::null(), |deliverable| keep(&deliverable.underlying)),
+        multiplier: number(deliverable.map(|deliverable| deliverable.multiplier)),
+        shares: number(deliverable.map(|deliverable| deliverable.shares)),
+        cash: number(deliverable.map(|deliverable| deliverable.cash)),
+        exercise_style: match option.exercise_style {
+            None => SymbolsExerciseStyle::None,
+            Some(ExerciseStyle::American) => SymbolsExerciseStyle::American,
+            Some(ExerciseStyle::European) => SymbolsExerciseStyle::European,
+        },
+        settlement_type: match option.settlement_type {
+            None => SymbolsSettlementType::None,
+            Some(SettlementType::Physical) => SymbolsSettlementType::Physical,
+            Some(SettlementType::Cash) => SymbolsSettlementType::Cash,
+        },
+        settlement_time: match option.settlement_time {
+            None => SymbolsSettlementTime::None,
+            Some(SettlementTime::Am) => SymbolsSettlementTime::Am,
+            Some(SettlementTime::Pm) => SymbolsSettlementTime::Pm,
+        },
+        has_expiration_time,
+        expiration_time,
+        time_zone: option.time_zone.as_deref().map_or(ptr::null(), &mut *keep),
+    }
+}
+
+fn future_contract(future: &FutureContract, keep: &mut impl FnMut(&str) -> *const c_char) -> SymbolsFutureContract {
+    let (has_expiration, expiration) = date(future.expiration);
+    SymbolsFutureContract {
+        continuous: future.continuous,
+        month: future.month.as_deref().map_or(ptr::null(), &mut *keep),
+        year: future.year.as_deref().map_or(ptr::null(), &mut *keep),
+        has_expiration,
+        expiration,
+    }
+}
+
+impl SymbolsError {
+    fn new(err: &ParseError) -> Box<Self> {
+        Box::new(SymbolsError {
+            message: c_string(&err.to_string()),
+            reason: c_string(&err.message),
+            position: err.position.map_or(-1, |position| position as i64),
+        })
+    }
+
+    fn message(message: &str) -> Box<Self> {
+        Box::new(SymbolsError { message: c_string(message), reason: c_string(message), position: -1 })
+    }
+}
+
+fn set_error(error: *mut *mut SymbolsError, value: Box<SymbolsError>) {
+    if !error.is_null() {
+        // SAFETY: the caller passed a writable SymbolsError* slot or NULL
+        unsafe { *error = Box::into_raw(value) };
+    }
+}
+
+/// Parses a NUL-terminated symbol. On SYMBOLS_STATUS_OK `*out` holds a symbol to release
+/// with symbols_free. On failure `*out` is NULL and, when `error` is not NULL, `*error`
+/// holds the details to release with symbols_error_free.
+///
+/// # Safety
+/// `input` must be NULL or a NUL-terminated string, `out` a writable pointer, `error` NULL
+/// or a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_parse(
+    input: *const c_char,
+    out: *mut *mut SymbolsSymbol,
+    error: *mut *mut SymbolsError,
+) -> SymbolsStatus {
+    if out.is_null() {
+        return SymbolsStatus::NullPointer;
+    }
+    *out = ptr::null_mut();
+    if !error.is_null() {
+        *error = ptr::null_mut();
+    }
+    if input.is_null() {
+        return SymbolsStatus::NullPointer;
+    }
+    let Ok(input) = CStr::from_ptr(input).to_str() else {
+        set_error(error, SymbolsError::message("symbol is not valid UTF-8"));
+        return SymbolsStatus::InvalidUtf8;
+    };
+
+    match catch_unwind(AssertUnwindSafe(|| Symbol::parse(input))) {
+        Ok(Ok(symbol)) => {
+            *out = Box::into_raw(SymbolsSymbol::new(symbol));
+            SymbolsStatus::Ok
+        }
+        Ok(Err(err)) => {
+            set_error(error, SymbolsError::new(&err));
+            SymbolsStatus::ParseError
+        }
+        Err(_) => {
+            set_error(error, SymbolsError::message("internal error while parsing"));
+            SymbolsStatus::Panic
+        }
+    }
+}
+
+/// # Safety
+/// `symbol` must be NULL or returned by symbols_parse and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_free(symbol: *mut SymbolsSymbol) {
+    if !symbol.is_null() {
+        drop(Box::from_raw(symbol));
+    }
+}
+
+/// # Safety
+/// `error` must be NULL or returned through symbols_parse and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_error_free(error: *mut SymbolsError) {
+    if !error.is_null() {
+        drop(Box::from_raw(error));
+    }
+}
+
+/// Full description, e.g. `could not parse symbol "ES-U0" at position 2: unexpected character`.
+///
+/// # Safety
+/// `error` must be a live SymbolsError.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_error_message(error: *const SymbolsError) -> *const c_char {
+    error.as_ref().map_or(ptr::null(), |error| error.message.as_ptr())
+}
+
+/// Reason alone, e.g. `unexpected character`.
+///
+/// # Safety
+/// `error` must be a live SymbolsError.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_error_reason(error: *const SymbolsError) -> *const c_char {
+    error.as_ref().map_or(ptr::null(), |error| error.reason.as_ptr())
+}
+
+/// Character offset where parsing stopped, -1 when unknown.
+///
+/// # Safety
+/// `error` must be a live SymbolsError.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_error_position(error: *const SymbolsError) -> i64 {
+    error.as_ref().map_or(-1, |error| error.position)
+}
+
+/// # Safety
+/// `symbol` must be a live SymbolsSymbol.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_symbol_type(symbol: *const SymbolsSymbol) -> SymbolsSymbolType {
+    match symbol.as_ref().map(|symbol| symbol.symbol.symbol_type) {
+        Some(SymbolType::Stock) => SymbolsSymbolType::Stock,
+        Some(SymbolType::Future) => SymbolsSymbolType::Future,
+        Some(SymbolType::FutureOption) => SymbolsSymbolType::FutureOption,
+        Some(SymbolType::StockOption) => SymbolsSymbolType::StockOption,
+        Some(SymbolType::Unknown) | None => SymbolsSymbolType::Unknown,
+    }
+}
+
+/// # Safety
+/// `symbol` must be a live SymbolsSymbol.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_original_symbol(symbol: *const SymbolsSymbol) -> *const c_char {
+    symbol.as_ref().map_or(ptr::null(), |symbol| symbol.original_symbol.as_ptr())
+}
+
+/// # Safety
+/// `symbol` must be a live SymbolsSymbol.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_underlying_symbol(symbol: *const SymbolsSymbol) -> *const c_char {
+    symbol.as_ref().map_or(ptr::null(), |symbol| symbol.underlying_symbol.as_ptr())
+}
+
+/// NULL when the symbol has no modifier.
+///
+/// # Safety
+/// `symbol` must be a live SymbolsSymbol.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_symbol_modifier(symbol: *const SymbolsSymbol) -> *const c_char {
+    symbol
+        .as_ref()
+        .and_then(|symbol| symbol.symbol_modifier.as_ref())
+        .map_or(ptr::null(), |modifier| modifier.as_ptr())
+}
+
+/// NULL for symbols that are not options. Valid until the symbol is freed.
+///
+/// # Safety
+/// `symbol` must be a live SymbolsSymbol.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_option_contract(symbol: *const SymbolsSymbol) -> *const SymbolsOptionContract {
+    symbol.as_ref().and_then(|symbol| symbol.option.as_ref()).map_or(ptr::null(), |option| option as *const _)
+}
+
+/// NULL for symbols that are not futures or future options. Valid until the symbol is freed.
+///
+/// # Safety
+/// `symbol` must be a live SymbolsSymbol.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_future_contract(symbol: *const SymbolsSymbol) -> *const SymbolsFutureContract {
+    symbol.as_ref().and_then(|symbol| symbol.future.as_ref()).map_or(ptr::null(), |future| future as *const _)
+}
+
+/// Orders by underlying, expiry, strike then put/call: negative, zero or positive. Two
+/// spellings of the same contract (ESU20, ESU0) compare equal.
+///
+/// # Safety
+/// `a` and `b` must be live SymbolsSymbols.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_compare(a: *const SymbolsSymbol, b: *const SymbolsSymbol) -> i32 {
+    match (a.as_ref(), b.as_ref()) {
+        (Some(a), Some(b)) => match a.symbol.cmp(&b.symbol) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        },
+        _ => 0,
+    }
+}
+
+/// Consistent with symbols_compare: equal symbols hash equal within one process.
+///
+/// # Safety
+/// `symbol` must be a live SymbolsSymbol.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_hash(symbol: *const SymbolsSymbol) -> u64 {
+    symbol.as_ref().map_or(0, |symbol| {
+        let mut hasher = DefaultHasher::new();
+        symbol.symbol.hash(&mut hasher);
+        hasher.finish()
+    })
+}
+
+/// Version of this library, e.g. "0.1.0".
+#[no_mangle]
+pub extern "C" fn symbols_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
+}
@@ -0,0 +1,136 @@
+/* Exercises the C API against the same cases as the Rust crate. Run with `make test`. */
+
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+#include <time.h>
+
+#include "symbols.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                   \
+  do {                                                                     \
+    if (!(condition)) {                                                    \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+      failures++;                                                          \
+    }                                                                      \
+  } while (0)
+
+static SymbolsSymbol *parse(const char *input) {
+  SymbolsSymbol *symbol = NULL;
+  SymbolsError *error = NULL;
+  SymbolsStatus status = symbols_parse(input, &symbol, &error);
+  if (status != SYMBOLS_STATUS_OK) {
+    fprintf(stderr, "could not parse %s: %s\n", input, symbols_error_message(error));
+    symbols_error_free(error);
+    failures++;
+  }
+  return symbol;
+}
+
+static void test_stock_option(void) {
+  SymbolsSymbol *symbol = parse("SPY251219C00650000");
+  CHECK(symbols_symbol_type(symbol) == SYMBOLS_SYMBOL_TYPE_STOCK_OPTION);
+  CHECK(strcmp(symbols_underlying_symbol(symbol), "SPY") == 0);
+  CHECK(strcmp(symbols_original_symbol(symbol), "SPY251219C00650000") == 0);
+  CHECK(symbols_symbol_modifier(symbol) == NULL);
+  CHECK(symbols_future_contract(symbol) == NULL);
+
+  const SymbolsOptionContract *option = symbols_option_contract(symbol);
+  CHECK(option != NULL);
+  CHECK(strcmp(option->strike_price, "650") == 0);
+  CHECK(option->strike_price_value == 650.0);
+  CHECK(option->put_call == SYMBOLS_PUT_CALL_CALL);
+  CHECK(option->has_date);
+  CHECK(option->date.year == 2025 && option->date.month == 12 && option->date.day == 19);
+  CHECK(option->series == SYMBOLS_EXPIRATION_SERIES_MONTHLY);
+  CHECK(strcmp(option->deliverable_underlying, "SPY") == 0);
+  CHECK(option->multiplier == 100.0);
+  CHECK(option->exercise_style == SYMBOLS_EXERCISE_STYLE_AMERICAN);
+  CHECK(option->settlement_type == SYMBOLS_SETTLEMENT_TYPE_PHYSICAL);
+  CHECK(option->has_expiration_time && option->expiration_time.hour == 16);
+  CHECK(strcmp(option->time_zone, "America/New_York") == 0);
+  symbols_free(symbol);
+}
+
+static void test_future_option(void) {
+  SymbolsSymbol *symbol = parse("ESU20 P2700");
+  CHECK(symbols_symbol_type(symbol) == SYMBOLS_SYMBOL_TYPE_FUTURE_OPTION);
+  const SymbolsFutureContract *future = symbols_future_contract(symbol);
+  CHECK(future != NULL);
+  CHECK(!future->continuous);
+  CHECK(strcmp(future->month, "U") == 0);
+  CHECK(strcmp(future->year, "20") == 0);
+  CHECK(future->has_expiration && future->expiration.year == 2020 && future->expiration.month == 9);
+
+  const SymbolsOptionContract *option = symbols_option_contract(symbol);
+  CHECK(option->put_call == SYMBOLS_PUT_CALL_PUT);
+  CHECK(!option->has_date);
+  CHECK(option->deliverable_underlying == NULL);
+  CHECK(isnan(option->multiplier));
+  symbols_free(symbol);
+}
+
+static void test_continuous_future(void) {
+  SymbolsSymbol *symbol = parse("@ASM4.ZERO");
+  CHECK(symbols_symbol_type(symbol) == SYMBOLS_SYMBOL_TYPE_FUTURE);
+  CHECK(strcmp(symbols_symbol_modifier(symbol), ".ZERO") == 0);
+  CHECK(symbols_future_contract(symbol)->continuous);
+  CHECK(symbols_option_contract(symbol) == NULL);
+  symbols_free(symbol);
+}
+
+static void test_errors(void) {
+  SymbolsSymbol *symbol = NULL;
+  SymbolsError *error = NULL;
+  CHECK(symbols_parse("ES-U0", &symbol, &error) == SYMBOLS_STATUS_PARSE_ERROR);
+  CHECK(symbol == NULL);
+  CHECK(error != NULL);
+  CHECK(symbols_error_position(error) == 2);
+  CHECK(strcmp(symbols_error_reason(error), "unexpected character") == 0);
+  CHECK(strstr(symbols_error_message(error), "ES-U0") != NULL);
+  symbols_error_free(error);
+
+  CHECK(symbols_parse("MSFT 111322C1", &symbol, NULL) == SYMBOLS_STATUS_PARSE_ERROR);
+  CHECK(symbols_parse("\xff", &symbol, NULL) == SYMBOLS_STATUS_INVALID_UTF8);
+  CHECK(symbols_parse(NULL, &symbol, NULL) == SYMBOLS_STATUS_NULL_POINTER);
+  CHECK(symbols_parse("AAPL", NULL, NULL) == SYMBOLS_STATUS_NULL_POINTER);
+  symbols_free(NULL);
+  symbols_error_free(NULL);
+}
+
+static void test_comparisons(void) {
+  /* A one digit year is the nearest year ending in it, so this year's is always the same contract */
+  time_t now = time(NULL);
+  int year = localtime(&now)->tm_year + 1900;
+  char two_digit_input[8], one_digit_input[8], december_input[8];
+  snprintf(two_digit_input, sizeof two_digit_input, "ESU%02d", year % 100);
+  snprintf(one_digit_input, sizeof one_digit_input, "ESU%d", year % 10);
+  snprintf(december_input, sizeof december_input, "ESZ%02d", year % 100);
+  SymbolsSymbol *two_digit = parse(two_digit_input);
+  SymbolsSymbol *one_digit = parse(one_digit_input);
+  SymbolsSymbol *december = parse(december_input);
+  CHECK(symbols_compare(two_digit, one_digit) == 0);
+  CHECK(symbols_hash(two_digit) == symbols_hash(one_digit));
+  CHECK(symbols_compare(two_digit, december) < 0);
+  CHECK(symbols_compare(december, two_digit) > 0);
+  symbols_free(two_digit);
+  symbols_free(one_digit);
+  symbols_free(december);
+}
+
+int main(void) {
+  CHECK(strlen(symbols_version()) > 0);
+  test_stock_option();
+  test_future_option();
+  test_continuous_future();
+  test_errors();
+  test_comparisons();
+  if (failures) {
+    fprintf(stderr, "%d checks failed\n", failures);
+    return 1;
+  }
+  printf("all checks passed\n");
+  return 0;
+}
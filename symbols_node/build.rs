@@ -0,0 +1,13 @@
+use std::fs;
+
+// The typings are shared with the WASM package, which embeds them from types/. A copy
+// lives here so the npm package is self-contained.
+fn main() {
+    napi_build::setup();
+
+    println!("cargo:rerun-if-changed=../types/symbols.d.ts");
+    let typings = fs::read_to_string("../types/symbols.d.ts").expect("read ../types/symbols.d.ts");
+    if fs::read_to_string("index.d.ts").ok().as_deref() != Some(typings.as_str()) {
+        fs::write("index.d.ts", typings).expect("write index.d.ts");
+    }
+}
@@ -0,0 +1,102 @@
+// Shared by the WASM package and the Node addon (symbols_node), which return the same
+// values. The declarations below are generated from the Rust types by Tsify;
+// tests/typings.rs fails when they drift. Decimals are strings so no precision is lost,
+// dates are YYYY-MM-DD and times HH:MM:SS. Missing values are null.
+
+export type SymbolType = "Stock" | "Future" | "FutureOption" | "StockOption" | "Unknown";
+
+export type PutOrCall = "Put" | "Call";
+
+export type ExpirationSeries = "Monthly" | "Weekly" | "Quarterly" | "EndOfMonth" | "Daily";
+
+export type ExerciseStyle = "American" | "European";
+
+export type SettlementType = "Physical" | "Cash";
+
+export type SettlementTime = "Am" | "Pm";
+
+export interface Deliverable {
+    underlying: string;
+    multiplier: string;
+    shares: string;
+    cash: string;
+}
+
+export interface OptionContract {
+    root: string;
+    strike_price: string;
+    put_call: PutOrCall;
+    date: string | null;
+    series: ExpirationSeries | null;
+    adjusted: boolean;
+    deliverable: Deliverable | null;
+    exercise_style: ExerciseStyle | null;
+    settlement_type: SettlementType | null;
+    settlement_time: SettlementTime | null;
+    expiration_time: string | null;
+    time_zone: string | null;
+}
+
+export interface FutureContract {
+    continuous: boolean;
+    month: string | null;
+    year: string | null;
+    expiration: string | null;
+}
+
+export interface Symbol {
+    symbol_type: SymbolType;
+    original_symbol: string;
+    underlying_symbol: string;
+    symbol_modifier: string | null;
+    option_contract: OptionContract | null;
+    future_contract: FutureContract | null;
+}
+
+export type SuggestionReason = "Normalized" | "MissingDigit" | "ExtraDigit" | "UnlistedMonth" | "Confusable" | "UnknownRoot";
+
+export interface Suggestion {
+    symbol: string;
+    reason: SuggestionReason;
+    distance: number;
+}
+
+export type ErrorCode = "INVALID_SYMBOL" | "UNSUPPORTED_SYMBOLOGY" | "UNKNOWN_SYMBOLOGY" | "INVALID_ARGUMENT";
+
+export interface SymbolError {
+    code: ErrorCode;
+    message: string;
+    input: string;
+    position: number | null;
+    suggestions: Suggestion[];
+}
+
+export type ParseOutcome = { symbol: Symbol } | { error: SymbolError };
+
+export interface CacheStats {
+    hits: number;
+    misses: number;
+    evictions: number;
+    len: number;
+    capacity: number;
+}
+
+export type Symbology = "native" | "occ" | "occ-compact" | "cme";
+
+// parse, translate and format throw an Error named "SymbolError" carrying these fields
+export function parse(symbol: string): Symbol;
+// One outcome per input, in order; a bad symbol does not throw
+export function parseMany(symbols: string[]): ParseOutcome[];
+export function translate(symbol: string, from: Symbology, to: Symbology): string;
+export function format(symbol: Symbol, to: Symbology): string;
+
+// Keeps up to `capacity` parsed symbols, evicting the least recently used. parse returns
+// a cached symbol or parses and inserts it; failures are cached too and throw without
+// suggestions.
+export class SymbolCache {
+    constructor(capacity: number);
+    parse(symbol: string): Symbol;
+    stats(): CacheStats;
+    // Drops every entry and resets the statistics
+    clear(): void;
+}
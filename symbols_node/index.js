@@ -0,0 +1,2 @@
+// Built by `npm run build`, which names the library after package.json's napi.name
+module.exports = require("./symbols_node.node");
@@ -0,0 +1,91 @@
+use napi::{Env, Error, JsObject, JsUnknown, Result, Status};
+use napi_derive::napi;
+use serde::Serialize;
+
+use symbols::cache::LocalSymbolCache;
+use symbols::js::{self, ErrorCode, SymbolError};
+use symbols::symbol::Symbol;
+
+// Same API and values as the WASM package: both go through symbols::js, this file only
+// converts. Typings are in index.d.ts, copied from ../types/symbols.d.ts by build.rs.
+
+fn to_js<T: Serialize>(env: &Env, value: &T) -> Result<JsUnknown> {
+    env.to_js_value(value)
+}
+
+// An Error named SymbolError with the fields of `err` set on it, like the WASM package
+// throws. A napi Error would only carry the message.
+fn throw(env: &Env, err: SymbolError) -> Error {
+    let thrown = (|| {
+        let mut error = env.create_error(Error::from_reason(err.message.to_owned()))?;
+        let fields: JsObject = to_js(env, &err)?.coerce_to_object()?;
+        error.set_named_property("name", env.create_string("SymbolError")?)?;
+        for key in ["code", "input", "position", "suggestions"] {
+            error.set_named_property(key, fields.get_named_property::<JsUnknown>(key)?)?;
+        }
+        env.throw(error)
+    })();
+    match thrown {
+        Ok(()) => Error::from_status(Status::PendingException),
+        Err(_) => Error::from_reason(err.message),
+    }
+}
+
+#[napi]
+pub fn parse(env: Env, symbol: String) -> Result<JsUnknown> {
+    match js::parse(&symbol) {
+        Ok(symbol) => to_js(&env, &symbol),
+        Err(err) => Err(throw(&env, err)),
+    }
+}
+
+#[napi(js_name = "parseMany")]
+pub fn parse_many(env: Env, symbols: Vec<String>) -> Result<JsUnknown> {
+    to_js(&env, &js::parse_many(&symbols))
+}
+
+#[napi]
+pub fn translate(env: Env, symbol: String, from: String, to: String) -> Result<String> {
+    js::translate(&symbol, &from, &to).map_err(|err| throw(&env, err))
+}
+
+#[napi]
+pub fn format(env: Env, symbol: JsUnknown, to: String) -> Result<String> {
+    let symbol: Symbol = env
+        .from_js_value(symbol)
+        .map_err(|err| throw(&env, SymbolError::new(ErrorCode::InvalidArgument, "", err.reason)))?;
+    js::format(&symbol, &to).map_err(|err| throw(&env, err))
+}
+
+#[napi]
+pub struct SymbolCache {
+    cache: LocalSymbolCache,
+}
+
+#[napi]
+impl SymbolCache {
+    #[napi(constructor)]
+    pub fn new(capacity: u32) -> Self {
+        SymbolCache {
+            cache: LocalSymbolCache::new(capacity as usize),
+        }
+    }
+
+    #[napi]
+    pub fn parse(&mut self, env: Env, symbol: String) -> Result<JsUnknown> {
+        match self.cache.parse(&symbol) {
+            Ok(symbol) => to_js(&env, &*symbol),
+            Err(err) => Err(throw(&env, err.into())),
+        }
+    }
+
+    #[napi]
+    pub fn stats(&self, env: Env) -> Result<JsUnknown> {
+        to_js(&env, &self.cache.stats())
+    }
+
+    #[napi]
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
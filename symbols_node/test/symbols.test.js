@@ -0,0 +1,73 @@
+const assert = require("node:assert/strict");
+const path = require("node:path");
+const test = require("node:test");
+
+const symbols = require("..");
+
+test("parse returns the symbol as plain data", () => {
+  const symbol = symbols.parse("MSFT 110122C27.5");
+  assert.equal(symbol.symbol_type, "StockOption");
+  assert.equal(symbol.underlying_symbol, "MSFT");
+  assert.equal(symbol.symbol_modifier, null);
+  assert.equal(symbol.option_contract.strike_price, "27.5");
+  assert.equal(symbol.option_contract.put_call, "Call");
+  assert.equal(symbol.option_contract.date, "2011-01-22");
+  assert.equal(symbol.future_contract, null);
+});
+
+test("parse throws a structured error", () => {
+  assert.throws(() => symbols.parse("msft!"), (err) => {
+    assert.ok(err instanceof Error);
+    assert.equal(err.name, "SymbolError");
+    assert.equal(err.code, "INVALID_SYMBOL");
+    assert.equal(err.input, "msft!");
+    assert.equal(err.position, 4);
+    assert.ok(Array.isArray(err.suggestions));
+    return true;
+  });
+});
+
+test("parseMany keeps failures in place", () => {
+  const results = symbols.parseMany(["ESU20", "", "ESZ20"]);
+  assert.equal(results.length, 3);
+  assert.equal(results[0].symbol.symbol_type, "Future");
+  assert.equal(results[0].symbol.future_contract.expiration, "2020-09-01");
+  assert.equal(results[1].error.input, "");
+  assert.equal(results[2].symbol.future_contract.expiration, "2020-12-01");
+});
+
+test("translate and format", () => {
+  assert.equal(symbols.translate("SPY251219C00650000", "occ-compact", "native"), "SPY 251219C650");
+  assert.equal(symbols.translate("ESU20", "native", "cme"), "ESU0");
+  assert.throws(() => symbols.translate("ESU20", "native", "bloomberg"), { name: "SymbolError", code: "UNKNOWN_SYMBOLOGY" });
+  assert.equal(symbols.format(symbols.parse("SPY 251219C650"), "occ"), "SPY   251219C00650000");
+  assert.throws(() => symbols.format(symbols.parse("ESU20"), "occ"), { code: "UNSUPPORTED_SYMBOLOGY" });
+  assert.throws(() => symbols.format({ symbol_type: "Bond" }, "occ"), { code: "INVALID_ARGUMENT" });
+});
+
+test("SymbolCache parses through the cache", () => {
+  const cache = new symbols.SymbolCache(2);
+  assert.deepEqual(cache.parse("ESU20"), symbols.parse("ESU20"));
+  cache.parse("ESU20");
+  assert.throws(() => cache.parse("msft!"), { name: "SymbolError", code: "INVALID_SYMBOL", position: 4 });
+  cache.parse("AAPL");
+  assert.deepEqual(cache.stats(), { hits: 1, misses: 3, evictions: 1, len: 2, capacity: 2 });
+  cache.clear();
+  assert.deepEqual(cache.stats(), { hits: 0, misses: 0, evictions: 0, len: 0, capacity: 2 });
+});
+
+// Set SYMBOLS_WASM_PKG to a `wasm-pack build --target nodejs` output to compare the two
+const wasmPkg = process.env.SYMBOLS_WASM_PKG;
+
+test("matches the WASM package", { skip: !wasmPkg && "SYMBOLS_WASM_PKG is not set" }, () => {
+  const wasm = require(path.resolve(wasmPkg));
+  const inputs = ["ESU20", "ESU20 C2700", "@ES", "MSFT 110122C27.5", "SPY251219C00650000", "AAPL", "BRK.B", "", "1234"];
+  assert.deepEqual(symbols.parseMany(inputs), wasm.parseMany(inputs));
+  const [cache, wasmCache] = [new symbols.SymbolCache(4), new wasm.SymbolCache(4)];
+  for (const input of inputs.filter((input) => input !== "")) {
+    assert.deepEqual(symbols.parse(input), wasm.parse(input));
+    assert.deepEqual(cache.parse(input), wasmCache.parse(input));
+    assert.equal(symbols.translate(input, "native", "cme"), wasm.translate(input, "native", "cme"));
+  }
+  assert.deepEqual(cache.stats(), wasmCache.stats());
+});
@@ -0,0 +1,35 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import polars as pl
+from polars.plugins import register_plugin_function
+
+__all__ = ["parse"]
+
+# The compiled plugin, _symbols_polars, sits next to this file
+_LIBRARY = Path(__file__).parent
+
+
+def parse(expr: pl.Expr | str) -> pl.Expr:
+    """Parse a string column of symbols into a struct column.
+
+    The struct has ``symbol_type``, ``underlying``, ``expiry`` (date), ``strike``
+    (decimal with 3 places) and ``put_call`` (``"P"`` or ``"C"``). Rows that are null
+    or do not parse are null.
+    """
+    return register_plugin_function(
+        plugin_path=_LIBRARY,
+        function_name="parse",
+        args=expr,
+        is_elementwise=True,
+    )
+
+
+@pl.api.register_expr_namespace("symbols")
+class SymbolsNamespace:
+    def __init__(self, expr: pl.Expr) -> None:
+        self._expr = expr
+
+    def parse(self) -> pl.Expr:
+        return parse(self._expr)
@@ -0,0 +1,68 @@
+use std::mem;
+
+use arrow::array::{make_array, Array, StructArray};
+use arrow::datatypes::DataType as ArrowDataType;
+use arrow::ffi::{from_ffi, to_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use polars::prelude::*;
+use pyo3::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use pyo3_polars::export::polars_arrow::ffi::{self, ArrowArray, ArrowSchema};
+use pyo3_polars::export::*;
+use pyo3_polars::PolarsAllocator;
+
+use symbols::columnar;
+
+// Polars expression plugin around symbols::columnar::parse_array. Polars and arrow-rs
+// are separate Arrow implementations, so columns cross between them through the Arrow
+// C data interface; both sides lay out its structs the same way, as the spec requires.
+
+#[global_allocator]
+static ALLOC: PolarsAllocator = PolarsAllocator::new();
+
+fn compute_err(err: impl std::fmt::Display) -> PolarsError {
+    PolarsError::ComputeError(err.to_string().into())
+}
+
+fn to_arrow_rs(array: ArrayRef, field: &ArrowField) -> PolarsResult<arrow::array::ArrayRef> {
+    let array: FFI_ArrowArray = unsafe { mem::transmute::<ArrowArray, FFI_ArrowArray>(ffi::export_array_to_c(array)) };
+    let schema: FFI_ArrowSchema = unsafe { mem::transmute::<ArrowSchema, FFI_ArrowSchema>(ffi::export_field_to_c(field)) };
+    let data = unsafe { from_ffi(array, &schema) }.map_err(compute_err)?;
+    Ok(make_array(data))
+}
+
+fn from_arrow_rs(array: &StructArray) -> PolarsResult<ArrayRef> {
+    let (array, schema) = to_ffi(&array.to_data()).map_err(compute_err)?;
+    let array = unsafe { mem::transmute::<FFI_ArrowArray, ArrowArray>(array) };
+    let schema = unsafe { mem::transmute::<FFI_ArrowSchema, ArrowSchema>(schema) };
+    let field = unsafe { ffi::import_field_from_c(&schema) }?;
+    unsafe { ffi::import_array_from_c(array, field.dtype) }
+}
+
+fn parsed_field(input_fields: &[Field]) -> PolarsResult<Field> {
+    let schema = FFI_ArrowSchema::try_from(ArrowDataType::Struct(columnar::fields())).map_err(compute_err)?;
+    let schema = unsafe { mem::transmute::<FFI_ArrowSchema, ArrowSchema>(schema) };
+    let field = unsafe { ffi::import_field_from_c(&schema) }?;
+    Ok(Field::new(input_fields[0].name().clone(), DataType::from_arrow_field(&field)))
+}
+
+// One struct per row: symbol_type, underlying, expiry, strike, put_call. Null where the
+// symbol is null or does not parse.
+#[polars_expr(output_type_func=parsed_field)]
+fn parse(inputs: &[Series]) -> PolarsResult<Series> {
+    let symbols = &inputs[0];
+    symbols.str()?;
+    // Large strings rather than views, which more Arrow versions import
+    let field = symbols.dtype().to_arrow_field(PlSmallStr::EMPTY, CompatLevel::oldest());
+    let chunks = (0..symbols.n_chunks())
+        .map(|chunk| {
+            let array = to_arrow_rs(symbols.to_arrow(chunk, CompatLevel::oldest()), &field)?;
+            from_arrow_rs(&columnar::parse_column(array.as_ref()).map_err(compute_err)?)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Series::from_arrow_chunks(symbols.name().clone(), chunks)
+}
+
+#[pymodule]
+fn _symbols_polars(_m: &Bound<'_, PyModule>) -> PyResult<()> {
+    Ok(())
+}
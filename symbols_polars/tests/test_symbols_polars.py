@@ -0,0 +1,34 @@
+import datetime
+import decimal
+
+import polars as pl
+
+import symbols_polars  # noqa: F401, registers the namespace
+from symbols_polars import parse
+
+
+def test_parse_namespace():
+    df = pl.DataFrame({"sym": ["MSFT 110122C27.5", "ESU20", "ESU20 C2700"]})
+    parsed = df.select(pl.col("sym").symbols.parse()).unnest("sym")
+    assert parsed.schema == {
+        "symbol_type": pl.String,
+        "underlying": pl.String,
+        "expiry": pl.Date,
+        "strike": pl.Decimal(38, 3),
+        "put_call": pl.String,
+    }
+    assert parsed.row(0) == ("StockOption", "MSFT", datetime.date(2011, 1, 22), decimal.Decimal("27.500"), "C")
+    assert parsed.row(1) == ("Future", "ES", datetime.date(2020, 9, 1), None, None)
+    assert parsed.row(2)[3] == decimal.Decimal("2700.000")
+
+
+def test_bad_and_null_symbols_are_null():
+    df = pl.DataFrame({"sym": ["msft!", None, "ESU20"]})
+    parsed = df.select(parse("sym"))
+    assert parsed["sym"].is_null().to_list() == [True, True, False]
+
+
+def test_lazy_schema():
+    lazy = pl.LazyFrame({"sym": ["ESU20"]}).select(pl.col("sym").symbols.parse().struct.field("underlying"))
+    assert lazy.collect_schema() == {"underlying": pl.String}
+    assert lazy.collect()["underlying"].to_list() == ["ES"]
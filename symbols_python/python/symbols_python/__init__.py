@@ -0,0 +1,21 @@
+from ._symbols import (
+    FutureContract,
+    OptionContract,
+    ParseError,
+    PutCall,
+    Symbol,
+    SymbolType,
+    parse_many,
+    parse_symbol,
+)
+
+__all__ = [
+    "FutureContract",
+    "OptionContract",
+    "ParseError",
+    "PutCall",
+    "Symbol",
+    "SymbolType",
+    "parse_many",
+    "parse_symbol",
+]
@@ -0,0 +1,79 @@
+import datetime
+import decimal
+import enum
+from typing import Dict, List, Optional, Sequence
+
+class ParseError(ValueError):
+    input: str
+    position: Optional[int]
+    reason: str
+
+class SymbolType(enum.Enum):
+    Stock = ...
+    Future = ...
+    FutureOption = ...
+    StockOption = ...
+    Unknown = ...
+
+class PutCall(enum.Enum):
+    Put = ...
+    Call = ...
+
+class FutureContract:
+    @property
+    def continuous(self) -> bool: ...
+    @property
+    def month(self) -> Optional[str]: ...
+    @property
+    def year(self) -> Optional[str]: ...
+    @property
+    def expiration(self) -> Optional[datetime.date]: ...
+
+class OptionContract:
+    @property
+    def strike_price(self) -> decimal.Decimal: ...
+    @property
+    def put_call(self) -> PutCall: ...
+    @property
+    def date(self) -> Optional[datetime.date]: ...
+    @property
+    def series(self) -> Optional[str]: ...
+    @property
+    def adjusted(self) -> bool: ...
+    @property
+    def multiplier(self) -> Optional[decimal.Decimal]: ...
+    @property
+    def exercise_style(self) -> Optional[str]: ...
+    @property
+    def settlement_type(self) -> Optional[str]: ...
+    @property
+    def settlement_time(self) -> Optional[str]: ...
+    @property
+    def expiration_time(self) -> Optional[datetime.time]: ...
+    @property
+    def time_zone(self) -> Optional[str]: ...
+
+class Symbol:
+    def __init__(self, symbol: str) -> None: ...
+    @property
+    def symbol_type(self) -> SymbolType: ...
+    @property
+    def original_symbol(self) -> str: ...
+    @property
+    def underlying_symbol(self) -> str: ...
+    @property
+    def symbol_modifier(self) -> Optional[str]: ...
+    @property
+    def expiration(self) -> Optional[datetime.date]: ...
+    @property
+    def option_contract(self) -> Optional[OptionContract]: ...
+    @property
+    def future_contract(self) -> Optional[FutureContract]: ...
+    def __lt__(self, other: Symbol) -> bool: ...
+    def __le__(self, other: Symbol) -> bool: ...
+    def __gt__(self, other: Symbol) -> bool: ...
+    def __ge__(self, other: Symbol) -> bool: ...
+    def __hash__(self) -> int: ...
+
+def parse_symbol(symbol: str) -> Symbol: ...
+def parse_many(symbols: Sequence[str]) -> Dict[str, List[object]]: ...
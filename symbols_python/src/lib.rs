@@ -0,0 +1,305 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use chrono::{NaiveDate, NaiveTime};
+use pyo3::basic::CompareOp;
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rust_decimal::Decimal;
+use symbols::symbol::{
+    ExerciseStyle, FutureContract, OptionContract, PutOrCall, SettlementTime, SettlementType, Symbol, SymbolType,
+};
+
+create_exception!(symbols_python, ParseError, PyValueError, "Raised when a symbol cannot be parsed");
+
+fn parse_error(py: Python<'_>, err: symbols::symbol_parser::ParseError) -> PyErr {
+    let exception = ParseError::new_err(err.to_string());
+    let value = exception.value(py);
+    // Failing to attach the details still leaves a useful message
+    let _ = value.setattr("input", err.input);
+    let _ = value.setattr("position", err.position);
+    let _ = value.setattr("reason", err.message);
+    exception
+}
+
+#[pyclass(name = "SymbolType", module = "symbols_python", eq, eq_int, frozen, hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PySymbolType {
+    Stock,
+    Future,
+    FutureOption,
+    StockOption,
+    Unknown,
+}
+
+impl From<SymbolType> for PySymbolType {
+    fn from(symbol_type: SymbolType) -> Self {
+        match symbol_type {
+            SymbolType::Stock => PySymbolType::Stock,
+            SymbolType::Future => PySymbolType::Future,
+            SymbolType::FutureOption => PySymbolType::FutureOption,
+            SymbolType::StockOption => PySymbolType::StockOption,
+            SymbolType::Unknown => PySymbolType::Unknown,
+        }
+    }
+}
+
+#[pyclass(name = "PutCall", module = "symbols_python", eq, eq_int, frozen, hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PyPutCall {
+    Put,
+    Call,
+}
+
+impl From<PutOrCall> for PyPutCall {
+    fn from(put_call: PutOrCall) -> Self {
+        match put_call {
+            PutOrCall::Put => PyPutCall::Put,
+            PutOrCall::Call => PyPutCall::Call,
+        }
+    }
+}
+
+#[pyclass(name = "FutureContract", module = "symbols_python", frozen)]
+pub struct PyFutureContract {
+    future_contract: FutureContract,
+}
+
+#[pymethods]
+impl PyFutureContract {
+    #[getter]
+    fn continuous(&self) -> bool {
+        self.future_contract.continuous
+    }
+
+    #[getter]
+    fn month(&self) -> Option<String> {
+        self.future_contract.month.to_owned()
+    }
+
+    #[getter]
+    fn year(&self) -> Option<String> {
+        self.future_contract.year.to_owned()
+    }
+
+    #[getter]
+    fn expiration(&self) -> Option<NaiveDate> {
+        self.future_contract.expiration
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.future_contract)
+    }
+}
+
+#[pyclass(name = "OptionContract", module = "symbols_python", frozen)]
+pub struct PyOptionContract {
+    option_contract: OptionContract,
+}
+
+#[pymethods]
+impl PyOptionContract {
+    #[getter]
+    fn strike_price(&self) -> Decimal {
+        self.option_contract.strike_price
+    }
+
+    #[getter]
+    fn put_call(&self) -> PyPutCall {
+        self.option_contract.put_call.into()
+    }
+
+    #[getter]
+    fn date(&self) -> Option<NaiveDate> {
+        self.option_contract.date
+    }
+
+    #[getter]
+    fn series(&self) -> Option<String> {
+        self.option_contract.series.map(|series| format!("{series:?}").to_lowercase())
+    }
+
+    #[getter]
+    fn adjusted(&self) -> bool {
+        self.option_contract.adjusted
+    }
+
+    #[getter]
+    fn multiplier(&self) -> Option<Decimal> {
+        self.option_contract.deliverable.as_ref().map(|deliverable| deliverable.multiplier)
+    }
+
+    #[getter]
+    fn exercise_style(&self) -> Option<&'static str> {
+        self.option_contract.exercise_style.map(|style| match style {
+            ExerciseStyle::American => "american",
+            ExerciseStyle::European => "european",
+        })
+    }
+
+    #[getter]
+    fn settlement_type(&self) -> Option<&'static str> {
+        self.option_contract.settlement_type.map(|settlement| match settlement {
+            SettlementType::Physical => "physical",
+            SettlementType::Cash => "cash",
+        })
+    }
+
+    #[getter]
+    fn settlement_time(&self) -> Option<&'static str> {
+        self.option_contract.settlement_time.map(|time| match time {
+            SettlementTime::Am => "am",
+            SettlementTime::Pm => "pm",
+        })
+    }
+
+    #[getter]
+    fn expiration_time(&self) -> Option<NaiveTime> {
+        self.option_contract.expiration_time
+    }
+
+    #[getter]
+    fn time_zone(&self) -> Option<String> {
+        self.option_contract.time_zone.to_owned()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.option_contract)
+    }
+}
+
+#[pyclass(name = "Symbol", module = "symbols_python", frozen)]
+pub struct PySymbol {
+    symbol: Symbol,
+}
+
+#[pymethods]
+impl PySymbol {
+    #[new]
+    fn new(py: Python<'_>, symbol: &str) -> PyResult<Self> {
+        Symbol::parse(symbol).map(|symbol| PySymbol { symbol }).map_err(|err| parse_error(py, err))
+    }
+
+    #[getter]
+    fn symbol_type(&self) -> PySymbolType {
+        self.symbol.symbol_type.into()
+    }
+
+    #[getter]
+    fn original_symbol(&self) -> &str {
+        &self.symbol.original_symbol
+    }
+
+    #[getter]
+    fn underlying_symbol(&self) -> &str {
+        &self.symbol.underlying_symbol
+    }
+
+    #[getter]
+    fn symbol_modifier(&self) -> Option<&str> {
+        self.symbol.symbol_modifier.as_deref()
+    }
+
+    #[getter]
+    fn expiration(&self) -> Option<NaiveDate> {
+        self.symbol.expiration()
+    }
+
+    #[getter]
+    fn option_contract(&self) -> Option<PyOptionContract> {
+        self.symbol.option_contract.as_ref().map(|option_contract| PyOptionContract {
+            option_contract: option_contract.to_owned(),
+        })
+    }
+
+    #[getter]
+    fn future_contract(&self) -> Option<PyFutureContract> {
+        self.symbol.future_contract.as_ref().map(|future_contract| PyFutureContract {
+            future_contract: future_contract.to_owned(),
+        })
+    }
+
+    // Same contract, however it was written: ESU20 == ESU0
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.symbol.cmp(&other.symbol))
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.symbol.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __str__(&self) -> &str {
+        &self.symbol.original_symbol
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Symbol({:?})", self.symbol.original_symbol)
+    }
+}
+
+#[pyfunction]
+fn parse_symbol(py: Python<'_>, symbol: &str) -> PyResult<PySymbol> {
+    PySymbol::new(py, symbol)
+}
+
+// Parses every symbol into a dict of equal-length columns, ready for
+// pandas.DataFrame(...) or polars.DataFrame(...). Failed rows keep their input and
+// error and are None everywhere else.
+#[pyfunction]
+fn parse_many<'py>(py: Python<'py>, symbols: Vec<String>) -> PyResult<Bound<'py, PyDict>> {
+    let results = py.detach(|| symbols::bulk::parse_many_parallel(&symbols));
+
+    let rows = results.len();
+    let mut valid = Vec::with_capacity(rows);
+    let mut symbol_type = Vec::with_capacity(rows);
+    let mut underlying = Vec::with_capacity(rows);
+    let mut modifier = Vec::with_capacity(rows);
+    let mut expiration = Vec::with_capacity(rows);
+    let mut strike = Vec::with_capacity(rows);
+    let mut put_call = Vec::with_capacity(rows);
+    let mut error = Vec::with_capacity(rows);
+    for result in &results {
+        let symbol = result.as_ref().ok();
+        let option = symbol.and_then(|symbol| symbol.option_contract.as_ref());
+        valid.push(symbol.is_some());
+        symbol_type.push(symbol.map(|symbol| format!("{:?}", symbol.symbol_type)));
+        underlying.push(symbol.map(|symbol| symbol.underlying_symbol.as_str()));
+        modifier.push(symbol.and_then(|symbol| symbol.symbol_modifier.as_deref()));
+        expiration.push(symbol.and_then(Symbol::expiration));
+        strike.push(option.map(|option| option.strike_price));
+        put_call.push(option.map(|option| match option.put_call {
+            PutOrCall::Put => "P",
+            PutOrCall::Call => "C",
+        }));
+        error.push(result.as_ref().err().map(|err| err.to_string()));
+    }
+
+    let columns = PyDict::new(py);
+    columns.set_item("input", &symbols)?;
+    columns.set_item("valid", valid)?;
+    columns.set_item("symbol_type", symbol_type)?;
+    columns.set_item("underlying", underlying)?;
+    columns.set_item("modifier", modifier)?;
+    columns.set_item("expiration", expiration)?;
+    columns.set_item("strike", strike)?;
+    columns.set_item("put_call", put_call)?;
+    columns.set_item("error", error)?;
+    Ok(columns)
+}
+
+#[pymodule]
+fn _symbols(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("ParseError", m.py().get_type::<ParseError>())?;
+    m.add_class::<PySymbolType>()?;
+    m.add_class::<PyPutCall>()?;
+    m.add_class::<PyFutureContract>()?;
+    m.add_class::<PyOptionContract>()?;
+    m.add_class::<PySymbol>()?;
+    m.add_function(wrap_pyfunction!(parse_symbol, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_many, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,68 @@
+import datetime
+import decimal
+
+import pytest
+
+import symbols_python
+from symbols_python import ParseError, PutCall, Symbol, SymbolType, parse_many, parse_symbol
+
+
+def test_stock_option():
+    symbol = parse_symbol("SPY251219C00650000")
+    assert symbol.symbol_type == SymbolType.StockOption
+    assert symbol.underlying_symbol == "SPY"
+    option = symbol.option_contract
+    assert option.strike_price == decimal.Decimal("650")
+    assert isinstance(option.strike_price, decimal.Decimal)
+    assert option.put_call == PutCall.Call
+    assert option.date == datetime.date(2025, 12, 19)
+    assert option.series == "monthly"
+    assert option.expiration_time == datetime.time(16, 0)
+    assert symbol.future_contract is None
+
+
+def test_future_option():
+    symbol = parse_symbol("ESU20 P2700")
+    assert symbol.symbol_type == SymbolType.FutureOption
+    assert symbol.option_contract.strike_price == decimal.Decimal("2700")
+    future = symbol.future_contract
+    assert (future.month, future.year, future.continuous) == ("U", "20", False)
+    assert future.expiration == datetime.date(2020, 9, 1)
+
+
+def test_parse_error():
+    with pytest.raises(ParseError) as raised:
+        parse_symbol("ES-U0")
+    assert raised.value.input == "ES-U0"
+    assert raised.value.position == 2
+    assert isinstance(raised.value, ValueError)
+
+    with pytest.raises(ParseError):
+        parse_symbol("MSFT 111322C1")
+
+
+def test_comparisons():
+    # A one digit year is the nearest year ending in it, so this year's is always the same contract
+    year = datetime.date.today().year
+    two_digit, one_digit = Symbol(f"ESU{year % 100:02}"), Symbol(f"ESU{year % 10}")
+    assert two_digit == one_digit
+    assert hash(two_digit) == hash(one_digit)
+    assert len({two_digit, one_digit, Symbol(f"ESZ{year % 100:02}")}) == 2
+    chain = sorted([Symbol("SPY251219C00650000"), Symbol("SPY251219P00600000"), Symbol("SPY251121C00700000")])
+    assert [str(symbol) for symbol in chain] == ["SPY251121C00700000", "SPY251219P00600000", "SPY251219C00650000"]
+
+
+def test_parse_many_columns():
+    columns = parse_many(["ESU20", "ES-U0", "MSFT 110122C27.5"])
+    assert set(columns) == {
+        "input", "valid", "symbol_type", "underlying", "modifier", "expiration", "strike", "put_call", "error",
+    }
+    assert all(len(column) == 3 for column in columns.values())
+    assert columns["valid"] == [True, False, True]
+    assert columns["strike"] == [None, None, decimal.Decimal("27.5")]
+    assert columns["expiration"][2] == datetime.date(2011, 1, 22)
+    assert columns["error"][0] is None and "ES-U0" in columns["error"][1]
+
+
+def test_exports():
+    assert set(symbols_python.__all__) >= {"parse_symbol", "parse_many", "Symbol", "ParseError"}
@@ -1,6 +1,76 @@
-use magnus::{class, define_module, function, method, prelude::*, Error, RBignum, TryConvert, Value};
-use symbols::symbol::{FutureContract, OptionContract, Symbol, SymbolType};
-use rust_decimal::prelude::ToPrimitive;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use chrono::{Datelike, NaiveDate};
+use magnus::{
+    class, define_module, exception, function, method, module, prelude::*, Error, Exception, RClass, RHash, Value,
+};
+use rust_decimal::Decimal;
+use symbols::format::{self, FormatError, Symbology};
+use symbols::symbol::{
+    ExerciseStyle, ExpirationSeries, FutureContract, OptionContract, PutOrCall, SettlementTime, SettlementType, Symbol,
+    SymbolType,
+};
+use symbols::symbol_parser::ParseError;
+
+fn sym(name: &str) -> magnus::Symbol {
+    magnus::Symbol::new(name)
+}
+
+// Dates cross as Date and decimals as BigDecimal, both loaded by lib/symbols_ruby.rb
+fn date(date: NaiveDate) -> Result<Value, Error> {
+    let class: RClass = class::object().const_get("Date")?;
+    class.new_instance((date.year(), date.month(), date.day()))
+}
+
+fn big_decimal(decimal: Decimal) -> Result<Value, Error> {
+    class::object().funcall("BigDecimal", (decimal.normalize().to_string(),))
+}
+
+fn optional<T>(value: Option<T>, convert: impl FnOnce(T) -> Result<Value, Error>) -> Result<Value, Error> {
+    value.map_or(Ok(Value::default()), convert)
+}
+
+// SymbolsRuby::ParseError (or FormatError) with the error's fields as readers, defined in
+// lib/symbols_ruby.rb
+fn raise(class_name: &str, message: String, fields: &[(&str, Value)]) -> Error {
+    let exception = define_module("SymbolsRuby")
+        .and_then(|module| module.const_get::<_, RClass>(class_name))
+        .and_then(|class| class.new_instance((message,)));
+    let exception = match exception {
+        Ok(exception) => exception,
+        Err(err) => return err,
+    };
+    for (name, value) in fields {
+        if let Err(err) = exception.funcall::<_, _, Value>("instance_variable_set", (format!("@{name}"), *value)) {
+            return err;
+        }
+    }
+    match Exception::from_value(exception) {
+        Some(exception) => exception.into(),
+        None => Error::new(exception::runtime_error(), "could not raise SymbolsRuby::Error"),
+    }
+}
+
+fn parse_error(err: ParseError) -> Error {
+    let position = err.position.map(Value::from).unwrap_or_default();
+    raise(
+        "ParseError",
+        err.to_string(),
+        &[("input", err.input.as_str().into()), ("position", position), ("reason", err.message.as_str().into())],
+    )
+}
+
+fn format_error(err: FormatError) -> Error {
+    raise("FormatError", err.to_string(), &[("input", err.input.as_str().into())])
+}
+
+// Symbologies are given as symbols or strings, :native, :occ, "occ-compact", :cme
+fn symbology(name: Value) -> Result<Symbology, Error> {
+    let name: String = name.funcall("to_s", ())?;
+    name.replace('_', "-").parse().map_err(|message: String| Error::new(exception::arg_error(), message))
+}
+
 #[derive(Debug)]
 #[magnus::wrap(class = "SymbolsRuby::FutureContract", free_immediately, size)]
 pub struct RFutureContract {
@@ -16,8 +86,25 @@ impl RFutureContract {
         self.future_contract.continuous
     }
 
-    pub fn expiration(&self) -> Option<String> {
-        self.future_contract.expiration.map(|e| e.to_string())
+    pub fn month(&self) -> Option<String> {
+        self.future_contract.month.to_owned()
+    }
+
+    pub fn year(&self) -> Option<String> {
+        self.future_contract.year.to_owned()
+    }
+
+    pub fn expiration(&self) -> Result<Value, Error> {
+        optional(self.future_contract.expiration, date)
+    }
+
+    pub fn to_h(&self) -> Result<RHash, Error> {
+        let hash = RHash::new();
+        hash.aset(sym("continuous"), self.continuous())?;
+        hash.aset(sym("month"), self.month())?;
+        hash.aset(sym("year"), self.year())?;
+        hash.aset(sym("expiration"), self.expiration()?)?;
+        Ok(hash)
     }
 }
 
@@ -33,25 +120,83 @@ impl ROptionContract {
         format!("{:?}", self.option_contract)
     }
 
-   pub fn strike_price(&self) -> f64 {
-    let a = self.option_contract.strike_price.to_f64().unwrap();
-    
-    a
-   }
+    pub fn strike_price(&self) -> Result<Value, Error> {
+        big_decimal(self.option_contract.strike_price)
+    }
+
+    pub fn put_call(&self) -> magnus::Symbol {
+        match self.option_contract.put_call {
+            PutOrCall::Put => sym("put"),
+            PutOrCall::Call => sym("call"),
+        }
+    }
 
-   pub fn put_call(&self) -> magnus::Symbol {
-    match self.option_contract.put_call {
-        symbols::symbol::PutOrCall::Put => magnus::Symbol::new("put"),
-        symbols::symbol::PutOrCall::Call => magnus::Symbol::new("call"),
+    pub fn date(&self) -> Result<Value, Error> {
+        optional(self.option_contract.date, date)
     }
-   }
 
-   pub fn date(&self) -> Option<String> {
-    self.option_contract.date.map(|e| e.to_string())
-}
+    pub fn series(&self) -> Option<magnus::Symbol> {
+        self.option_contract.series.map(|series| match series {
+            ExpirationSeries::Monthly => sym("monthly"),
+            ExpirationSeries::Weekly => sym("weekly"),
+            ExpirationSeries::Quarterly => sym("quarterly"),
+            ExpirationSeries::EndOfMonth => sym("end_of_month"),
+            ExpirationSeries::Daily => sym("daily"),
+        })
+    }
+
+    pub fn adjusted(&self) -> bool {
+        self.option_contract.adjusted
+    }
+
+    pub fn exercise_style(&self) -> Option<magnus::Symbol> {
+        self.option_contract.exercise_style.map(|style| match style {
+            ExerciseStyle::American => sym("american"),
+            ExerciseStyle::European => sym("european"),
+        })
+    }
+
+    pub fn settlement_type(&self) -> Option<magnus::Symbol> {
+        self.option_contract.settlement_type.map(|settlement| match settlement {
+            SettlementType::Physical => sym("physical"),
+            SettlementType::Cash => sym("cash"),
+        })
+    }
+
+    pub fn settlement_time(&self) -> Option<magnus::Symbol> {
+        self.option_contract.settlement_time.map(|time| match time {
+            SettlementTime::Am => sym("am"),
+            SettlementTime::Pm => sym("pm"),
+        })
+    }
+
+    // Time of day, "16:00:00", in time_zone
+    pub fn expiration_time(&self) -> Option<String> {
+        self.option_contract.expiration_time.map(|e| e.to_string())
+    }
+
+    pub fn time_zone(&self) -> Option<String> {
+        self.option_contract.time_zone.to_owned()
+    }
+
+    pub fn to_h(&self) -> Result<RHash, Error> {
+        let hash = RHash::new();
+        hash.aset(sym("strike_price"), self.strike_price()?)?;
+        hash.aset(sym("put_call"), self.put_call())?;
+        hash.aset(sym("date"), self.date()?)?;
+        hash.aset(sym("series"), self.series())?;
+        hash.aset(sym("adjusted"), self.adjusted())?;
+        hash.aset(sym("exercise_style"), self.exercise_style())?;
+        hash.aset(sym("settlement_type"), self.settlement_type())?;
+        hash.aset(sym("settlement_time"), self.settlement_time())?;
+        hash.aset(sym("expiration_time"), self.expiration_time())?;
+        hash.aset(sym("time_zone"), self.time_zone())?;
+        Ok(hash)
+    }
 }
 
 
+// Compares and hashes like the Rust Symbol: by what the contract is, so ESU20 == ESU0
 #[derive(Debug)]
 #[magnus::wrap(class = "SymbolsRuby::Symbol", free_immediately, size)]
 pub struct RSymbol {
@@ -63,6 +208,10 @@ impl RSymbol {
         format!("{:?}", self.symbol)
     }
 
+    pub fn to_s(&self) -> String {
+        self.symbol.original_symbol.to_owned()
+    }
+
     pub fn original_symbol(&self) -> String {
         self.symbol.original_symbol.to_owned()
     }
@@ -74,14 +223,14 @@ impl RSymbol {
     pub fn symbol_modifier(&self) -> Option<String> {
         self.symbol.symbol_modifier.to_owned()
     }
-    
+
     pub fn symbol_type(&self) -> magnus::Symbol {
         match self.symbol.symbol_type {
-            SymbolType::Stock => magnus::Symbol::new("stock"),
-            SymbolType::Future => magnus::Symbol::new("future"),
-            SymbolType::FutureOption => magnus::Symbol::new("future_option"),
-            SymbolType::StockOption => magnus::Symbol::new("stock_option"),
-            SymbolType::Unknown => magnus::Symbol::new("unknown"),
+            SymbolType::Stock => sym("stock"),
+            SymbolType::Future => sym("future"),
+            SymbolType::FutureOption => sym("future_option"),
+            SymbolType::StockOption => sym("stock_option"),
+            SymbolType::Unknown => sym("unknown"),
         }
     }
 
@@ -96,18 +245,63 @@ impl RSymbol {
             ROptionContract { option_contract: e.to_owned() }
         })
     }
+
+    pub fn to_h(&self) -> Result<RHash, Error> {
+        let hash = RHash::new();
+        hash.aset(sym("symbol_type"), self.symbol_type())?;
+        hash.aset(sym("original_symbol"), self.original_symbol())?;
+        hash.aset(sym("underlying_symbol"), self.underlying_symbol())?;
+        hash.aset(sym("symbol_modifier"), self.symbol_modifier())?;
+        hash.aset(sym("option_contract"), self.option_contract().map(|option| option.to_h()).transpose()?)?;
+        hash.aset(sym("future_contract"), self.future_contract().map(|future| future.to_h()).transpose()?)?;
+        Ok(hash)
+    }
+
+    // The same JSON as the HTTP service and the JS packages: decimals and dates as
+    // strings. Takes JSON.generate's state argument and ignores it.
+    pub fn to_json(&self, _args: &[Value]) -> Result<String, Error> {
+        serde_json::to_string(&self.symbol).map_err(|err| Error::new(exception::runtime_error(), err.to_string()))
+    }
+
+    pub fn format(&self, to: Value) -> Result<String, Error> {
+        symbology(to)?.format(&self.symbol).map_err(format_error)
+    }
+
+    pub fn eq(&self, other: Value) -> bool {
+        other.try_convert::<&RSymbol>().is_ok_and(|other| self.symbol == other.symbol)
+    }
+
+    pub fn cmp(&self, other: Value) -> Option<i8> {
+        let other = other.try_convert::<&RSymbol>().ok()?;
+        Some(self.symbol.cmp(&other.symbol) as i8)
+    }
+
+    pub fn hash(&self) -> i64 {
+        let mut hasher = DefaultHasher::new();
+        self.symbol.hash(&mut hasher);
+        hasher.finish() as i64
+    }
 }
 
 
-fn parse_symbol(subject: String) -> RSymbol {
-    let result = symbols::symbol_parser::parse_symbol(&subject);
-    match result {
-        Ok(result) => {
-            let symbol: symbols::symbol::Symbol = result.into();
-            RSymbol { symbol }
-        },
-        Err(_) => todo!()
-    } 
+fn parse_symbol(subject: String) -> Result<RSymbol, Error> {
+    Symbol::parse(&subject).map(|symbol| RSymbol { symbol }).map_err(parse_error)
+}
+
+// One entry per input, nil where the symbol does not parse
+fn parse_many(subjects: Vec<String>) -> Vec<Option<RSymbol>> {
+    symbols::bulk::parse_many_parallel(&subjects)
+        .into_iter()
+        .map(|result| result.ok().map(|symbol| RSymbol { symbol }))
+        .collect()
+}
+
+fn format_symbol(symbol: &RSymbol, to: Value) -> Result<String, Error> {
+    symbol.format(to)
+}
+
+fn translate(subject: String, from: Value, to: Value) -> Result<String, Error> {
+    format::translate(&subject, symbology(from)?, symbology(to)?).map_err(format_error)
 }
 
 #[magnus::init]
@@ -118,21 +312,44 @@ fn init() -> Result<(), Error> {
     option_class.define_method("strike_price", method!(ROptionContract::strike_price, 0))?;
     option_class.define_method("put_call", method!(ROptionContract::put_call, 0))?;
     option_class.define_method("date", method!(ROptionContract::date, 0))?;
+    option_class.define_method("series", method!(ROptionContract::series, 0))?;
+    option_class.define_method("adjusted?", method!(ROptionContract::adjusted, 0))?;
+    option_class.define_method("exercise_style", method!(ROptionContract::exercise_style, 0))?;
+    option_class.define_method("settlement_type", method!(ROptionContract::settlement_type, 0))?;
+    option_class.define_method("settlement_time", method!(ROptionContract::settlement_time, 0))?;
+    option_class.define_method("expiration_time", method!(ROptionContract::expiration_time, 0))?;
+    option_class.define_method("time_zone", method!(ROptionContract::time_zone, 0))?;
+    option_class.define_method("to_h", method!(ROptionContract::to_h, 0))?;
 
     let future_class = module.define_class("FutureContract", class::object())?;
     future_class.define_method("inspect", method!(RFutureContract::inspect, 0))?;
     future_class.define_method("continuous", method!(RFutureContract::continuous, 0))?;
+    future_class.define_method("month", method!(RFutureContract::month, 0))?;
+    future_class.define_method("year", method!(RFutureContract::year, 0))?;
     future_class.define_method("expiration", method!(RFutureContract::expiration, 0))?;
+    future_class.define_method("to_h", method!(RFutureContract::to_h, 0))?;
 
     let class = module.define_class("Symbol", class::object())?;
+    class.include_module(module::comparable())?;
     class.define_method("inspect", method!(RSymbol::inspect, 0))?;
+    class.define_method("to_s", method!(RSymbol::to_s, 0))?;
     class.define_method("original_symbol", method!(RSymbol::original_symbol, 0))?;
     class.define_method("underlying_symbol", method!(RSymbol::underlying_symbol, 0))?;
     class.define_method("symbol_modifier", method!(RSymbol::symbol_modifier, 0))?;
     class.define_method("symbol_type", method!(RSymbol::symbol_type, 0))?;
     class.define_method("future_contract", method!(RSymbol::future_contract, 0))?;
     class.define_method("option_contract", method!(RSymbol::option_contract, 0))?;
+    class.define_method("to_h", method!(RSymbol::to_h, 0))?;
+    class.define_method("to_json", method!(RSymbol::to_json, -1))?;
+    class.define_method("format", method!(RSymbol::format, 1))?;
+    class.define_method("==", method!(RSymbol::eq, 1))?;
+    class.define_method("eql?", method!(RSymbol::eq, 1))?;
+    class.define_method("<=>", method!(RSymbol::cmp, 1))?;
+    class.define_method("hash", method!(RSymbol::hash, 0))?;
 
     module.define_singleton_method("parse_symbol", function!(parse_symbol, 1))?;
+    module.define_singleton_method("parse_many", function!(parse_many, 1))?;
+    module.define_singleton_method("format", function!(format_symbol, 2))?;
+    module.define_singleton_method("translate", function!(translate, 3))?;
     Ok(())
 }
@@ -1,9 +1,24 @@
 # frozen_string_literal: true
 
+require "bigdecimal"
+require "date"
+require "json"
+
 require_relative "symbols_ruby/version"
 require_relative "symbols_ruby/symbols_ruby"
 
 module SymbolsRuby
   class Error < StandardError; end
-  # Your code goes here...
+
+  # Raised by parse_symbol for input that is not a symbol
+  class ParseError < Error
+    # input is the string given to parse_symbol, position the character offset parsing
+    # stopped at (nil when unknown) and reason the message without the input
+    attr_reader :input, :position, :reason
+  end
+
+  # Raised by format and translate when the symbol has no spelling in the symbology
+  class FormatError < Error
+    attr_reader :input
+  end
 end
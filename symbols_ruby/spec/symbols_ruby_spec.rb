@@ -5,7 +5,107 @@ RSpec.describe SymbolsRuby do
     expect(SymbolsRuby::VERSION).not_to be nil
   end
 
-  it "does something useful" do
-    expect(false).to eq(true)
+  describe ".parse_symbol" do
+    it "parses stock options with exact strikes and dates" do
+      symbol = SymbolsRuby.parse_symbol("MSFT 110122C27.5")
+      option = symbol.option_contract
+
+      expect(symbol.symbol_type).to eq(:stock_option)
+      expect(symbol.underlying_symbol).to eq("MSFT")
+      expect(symbol.future_contract).to be_nil
+      expect(option.strike_price).to eq(BigDecimal("27.5"))
+      expect(option.strike_price).to be_a(BigDecimal)
+      expect(option.date).to eq(Date.new(2011, 1, 22))
+      expect(option.put_call).to eq(:call)
+    end
+
+    it "parses futures with their month and year" do
+      future = SymbolsRuby.parse_symbol("ESU20").future_contract
+
+      expect(future.month).to eq("U")
+      expect(future.year).to eq("20")
+      expect(future.continuous).to be false
+      expect(future.expiration).to be_a(Date)
+    end
+
+    it "raises ParseError for input that is not a symbol" do
+      expect { SymbolsRuby.parse_symbol("msft!") }.to raise_error(SymbolsRuby::ParseError) { |error|
+        expect(error).to be_a(SymbolsRuby::Error)
+        expect(error.input).to eq("msft!")
+        expect(error.position).to eq(4)
+        expect(error.reason).to eq("unexpected character")
+      }
+    end
+  end
+
+  describe ".parse_many" do
+    it "keeps one entry per input with nil for failures" do
+      symbols = SymbolsRuby.parse_many(["ESU20", "msft!", "AAPL"])
+
+      expect(symbols.map { |symbol| symbol&.symbol_type }).to eq([:future, nil, :stock])
+    end
+  end
+
+  describe "comparisons" do
+    it "treats spellings of the same contract as equal" do
+      # A one digit year is the nearest year ending in it, so this year's is always the same contract
+      year = Time.now.year
+      a = SymbolsRuby.parse_symbol(format("ESU%02d", year % 100))
+      b = SymbolsRuby.parse_symbol("ESU#{year % 10}")
+
+      expect(a).to eq(b)
+      expect(a).to eql(b)
+      expect(a.hash).to eq(b.hash)
+      expect([a, b].uniq.size).to eq(1)
+      expect(a).not_to eq(a.original_symbol)
+    end
+
+    it "orders contracts by expiration" do
+      symbols = %w[ESZ20 ESH21 ESU20].map { |symbol| SymbolsRuby.parse_symbol(symbol) }
+
+      expect(symbols.sort.map(&:original_symbol)).to eq(%w[ESU20 ESZ20 ESH21])
+      expect(symbols[0] <=> "ESZ20").to be_nil
+    end
+  end
+
+  describe "#to_h and #to_json" do
+    it "converts to plain Ruby values" do
+      hash = SymbolsRuby.parse_symbol("ESU20 C2700").to_h
+
+      expect(hash[:symbol_type]).to eq(:future_option)
+      expect(hash[:option_contract][:strike_price]).to eq(BigDecimal("2700"))
+      expect(hash[:future_contract][:month]).to eq("U")
+    end
+
+    it "writes the same JSON as the other bindings" do
+      json = JSON.parse(SymbolsRuby.parse_symbol("MSFT 110122C27.5").to_json)
+
+      expect(json["symbol_type"]).to eq("StockOption")
+      expect(json["option_contract"]["strike_price"]).to eq("27.5")
+      expect(json["option_contract"]["date"]).to eq("2011-01-22")
+      expect(JSON.generate([SymbolsRuby.parse_symbol("AAPL")])).to include("\"underlying_symbol\":\"AAPL\"")
+    end
+  end
+
+  describe "formatting" do
+    it "translates between symbologies" do
+      expect(SymbolsRuby.translate("SPY251219C00650000", :occ_compact, :native)).to eq("SPY 251219C650")
+      expect(SymbolsRuby.translate("ESU20", "native", "cme")).to eq("ESU0")
+    end
+
+    it "formats parsed symbols" do
+      symbol = SymbolsRuby.parse_symbol("SPY 251219C650")
+
+      expect(symbol.format(:occ)).to eq("SPY   251219C00650000")
+      expect(SymbolsRuby.format(symbol, :occ_compact)).to eq("SPY251219C00650000")
+    end
+
+    it "raises FormatError when the symbology cannot write the symbol" do
+      expect { SymbolsRuby.parse_symbol("ESU20").format(:occ) }.to raise_error(SymbolsRuby::FormatError)
+    end
+
+    it "rejects unknown symbologies" do
+      expect { SymbolsRuby.translate("ESU20", :native, :bloomberg) }.to raise_error(ArgumentError)
+    end
   end
 end
@@ -0,0 +1,262 @@
+use std::borrow::Cow;
+use std::ffi::{c_int, CStr};
+use std::marker::PhantomData;
+
+use chrono::NaiveDate;
+use rusqlite::functions::{Context, FunctionFlags};
+use rusqlite::types::{Type, Value};
+use rusqlite::vtab::{self, Filters, IndexConstraintOp, IndexInfo, Module, VTab, VTabConfig, VTabConnection, VTabCursor};
+use rusqlite::{ffi, Connection, Error, Result};
+use rust_decimal::Decimal;
+
+use symbols::chain::{ChainBuilder, StrikeIncrements};
+use symbols::format::{self, Symbology};
+use symbols::registry::default_registry;
+use symbols::symbol::{PutOrCall, Symbol, SymbolRef};
+
+// SQL functions over the symbols parser. Loaded into sqlite3 with `.load`, or registered
+// on a rusqlite connection with `register`.
+//
+// Symbols that are NULL or do not parse give NULL, so one bad row does not fail a
+// query. Bad arguments, like an unknown symbology, are errors. Strikes are TEXT, exact
+// like the Decimal they come from.
+
+/// Entry point for `.load libsymbols_sqlite`.
+///
+/// # Safety
+///
+/// Called by SQLite with a valid connection and API routines.
+#[cfg(feature = "loadable_extension")]
+#[no_mangle]
+pub unsafe extern "C" fn sqlite3_symbolssqlite_init(
+    db: *mut ffi::sqlite3,
+    err: *mut *mut std::ffi::c_char,
+    api: *mut ffi::sqlite3_api_routines,
+) -> c_int {
+    Connection::extension_init2(db, err, api, |db| register(&db).map(|()| false))
+}
+
+pub fn register(db: &Connection) -> Result<()> {
+    scalar(db, c"symbol_type", flags(), |symbol| Some(format!("{:?}", symbol.symbol_type).into()))?;
+    scalar(db, c"symbol_underlying", flags(), |symbol| {
+        Some(symbol.resolved_underlying(&default_registry()).to_owned().into())
+    })?;
+    scalar(db, c"symbol_expiry", flags_depending_on_today(), |symbol| expiration(symbol).map(|date| date.to_string().into()))?;
+    scalar(db, c"symbol_strike", flags(), |symbol| symbol.option_contract.map(|option| strike(option.strike_price)))?;
+    scalar(db, c"symbol_put_call", flags(), |symbol| symbol.option_contract.map(|option| put_call(option.put_call).into()))?;
+    db.create_scalar_function(c"symbol_translate", 3, flags_depending_on_today(), |ctx| {
+        let from = symbology(ctx, 1)?;
+        let to = symbology(ctx, 2)?;
+        let Some(raw_symbol) = ctx.get_raw(0).as_str_or_null()? else {
+            return Ok(None);
+        };
+        Ok(format::translate(raw_symbol, from, to).ok())
+    })?;
+    const CHAIN: Module<ChainTab> = Module::eponymous_only_module();
+    db.create_module(c"symbol_chain", &CHAIN, None)
+}
+
+fn flags() -> FunctionFlags {
+    FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC | FunctionFlags::SQLITE_INNOCUOUS
+}
+
+// One and two digit future years resolve against today, so ESU0 is a different contract
+// from one decade to the next. SQLite must not keep these results in indexes or
+// generated columns.
+fn flags_depending_on_today() -> FunctionFlags {
+    FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_INNOCUOUS
+}
+
+fn scalar<F>(db: &Connection, name: &CStr, flags: FunctionFlags, field: F) -> Result<()>
+where
+    F: Fn(&SymbolRef<'_>) -> Option<Value> + Send + 'static,
+{
+    db.create_scalar_function(name, 1, flags, move |ctx| {
+        let symbol = ctx.get_raw(0).as_str_or_null()?.and_then(|raw_symbol| SymbolRef::parse(raw_symbol).ok());
+        Ok(symbol.and_then(|symbol| field(&symbol)))
+    })
+}
+
+fn symbology(ctx: &Context<'_>, index: usize) -> Result<Symbology> {
+    let name = ctx.get_raw(index).as_str()?;
+    name.parse().map_err(|message: String| Error::UserFunctionError(message.into()))
+}
+
+fn expiration(symbol: &SymbolRef<'_>) -> Option<NaiveDate> {
+    symbol
+        .option_contract
+        .and_then(|option| option.date)
+        .or_else(|| symbol.future_contract.and_then(|future| future.expiration))
+}
+
+fn strike(strike_price: Decimal) -> Value {
+    Value::Text(strike_price.normalize().to_string())
+}
+
+fn put_call(put_call: PutOrCall) -> String {
+    match put_call {
+        PutOrCall::Put => "P".to_owned(),
+        PutOrCall::Call => "C".to_owned(),
+    }
+}
+
+// Chains bigger than this are almost certainly a mistyped strike range
+const MAX_CHAIN_SYMBOLS: usize = 1_000_000;
+
+// symbol_chain(root, date[, low, high, increment]): one row per option listed on root
+// from date, with strikes from low to high, and the same columns as the scalar functions.
+// Strikes follow the standard price bands unless a uniform increment is given, and a
+// missing low or high comes from the increments' default window.
+const SCHEMA: &CStr = c"CREATE TABLE x(symbol, symbol_type, underlying, expiry, strike, put_call,
+    root HIDDEN, date HIDDEN, low HIDDEN, high HIDDEN, increment HIDDEN)";
+const COLUMN_ROOT: c_int = 6;
+const ARGUMENTS: [&str; 5] = ["root", "date", "low", "high", "increment"];
+const REQUIRED_ARGUMENTS: usize = 2;
+
+#[repr(C)]
+struct ChainTab {
+    // Base class, must be first
+    base: ffi::sqlite3_vtab,
+}
+
+unsafe impl<'vtab> VTab<'vtab> for ChainTab {
+    type Aux = ();
+    type Cursor = ChainCursor<'vtab>;
+
+    fn connect(
+        db: &mut VTabConnection,
+        _aux: Option<&()>,
+        _module_name: &[u8],
+        _database_name: &[u8],
+        _table_name: &[u8],
+        _args: &[&[u8]],
+    ) -> Result<(Cow<'static, CStr>, Self)> {
+        db.config(VTabConfig::Innocuous)?;
+        Ok((Cow::Borrowed(SCHEMA), ChainTab { base: ffi::sqlite3_vtab::default() }))
+    }
+
+    // The arguments are equality constraints on the hidden columns, passed to filter in
+    // ARGUMENTS order with idx_num a bitmask of the ones given; root and date are required.
+    // An argument that is not usable yet, t.root in a join, rejects this plan so SQLite
+    // looks for one where it is.
+    fn best_index(&self, info: &mut IndexInfo) -> Result<bool> {
+        let mut constrained = [false; ARGUMENTS.len()];
+        let mut arguments = [None; ARGUMENTS.len()];
+        for (index, constraint) in info.constraints().enumerate() {
+            let Some(argument) = constraint.column().checked_sub(COLUMN_ROOT).filter(|argument| *argument >= 0) else {
+                continue;
+            };
+            if constraint.operator() != IndexConstraintOp::SQLITE_INDEX_CONSTRAINT_EQ {
+                continue;
+            }
+            constrained[argument as usize] = true;
+            if constraint.is_usable() {
+                arguments[argument as usize] = Some(index);
+            }
+        }
+        if constrained[..REQUIRED_ARGUMENTS].contains(&false) {
+            return Err(Error::ModuleError("symbol_chain needs a root and a date".to_owned()));
+        }
+        if constrained.iter().zip(&arguments).any(|(constrained, argument)| *constrained && argument.is_none()) {
+            return Ok(false);
+        }
+        let given = arguments.iter().enumerate().filter(|(_, argument)| argument.is_some());
+        info.set_idx_num(given.fold(0, |mask, (argument, _)| mask | 1 << argument));
+        for (argv, index) in arguments.into_iter().flatten().enumerate() {
+            let mut usage = info.constraint_usage(index);
+            usage.set_argv_index(argv as c_int + 1);
+            usage.set_omit(true);
+        }
+        info.set_estimated_cost(1000.);
+        Ok(true)
+    }
+
+    fn open(&'vtab mut self) -> Result<ChainCursor<'vtab>> {
+        Ok(ChainCursor::default())
+    }
+}
+
+#[derive(Default)]
+#[repr(C)]
+struct ChainCursor<'vtab> {
+    // Base class, must be first
+    base: ffi::sqlite3_vtab_cursor,
+    arguments: Vec<Value>,
+    symbols: Vec<Symbol>,
+    row: usize,
+    phantom: PhantomData<&'vtab ChainTab>,
+}
+
+unsafe impl VTabCursor for ChainCursor<'_> {
+    fn filter(&mut self, idx_num: c_int, _idx_str: Option<&str>, args: &Filters<'_>) -> Result<()> {
+        self.row = 0;
+        self.symbols = Vec::new();
+        self.arguments = vec![Value::Null; ARGUMENTS.len()];
+        if args.iter().any(|arg| arg.data_type() == Type::Null) {
+            return Ok(());
+        }
+        let given = (0..ARGUMENTS.len()).filter(|argument| idx_num & 1 << argument != 0);
+        for (argv, argument) in given.enumerate() {
+            self.arguments[argument] = args.get(argv)?;
+        }
+        let [Value::Text(root), Value::Text(date), low, high, increment] = &self.arguments[..] else {
+            return Err(Error::ModuleError("symbol_chain root and date must be text".to_owned()));
+        };
+        let date: NaiveDate = date
+            .parse()
+            .map_err(|_| Error::ModuleError(format!("symbol_chain date {date:?} is not YYYY-MM-DD")))?;
+        let increments = match decimal(increment, 4)? {
+            Some(increment) => StrikeIncrements::uniform(increment),
+            None => StrikeIncrements::standard(),
+        };
+        let (default_low, default_high) = increments.window();
+        let builder = ChainBuilder::new(root, date)
+            .strikes(decimal(low, 2)?.unwrap_or(default_low), decimal(high, 3)?.unwrap_or(default_high))
+            .increments(increments)
+            .limit(MAX_CHAIN_SYMBOLS);
+        self.symbols = builder.build().map_err(|err| Error::ModuleError(err.to_string()))?;
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<()> {
+        self.row += 1;
+        Ok(())
+    }
+
+    fn eof(&self) -> bool {
+        self.row >= self.symbols.len()
+    }
+
+    fn column(&self, ctx: &mut vtab::Context, column: c_int) -> Result<()> {
+        let symbol = &self.symbols[self.row];
+        let option = symbol.option_contract.as_ref();
+        let value = match column {
+            0 => Symbology::Native.format(symbol).ok().map(Value::Text),
+            1 => Some(format!("{:?}", symbol.symbol_type).into()),
+            2 => Some(symbol.underlying_symbol.to_owned().into()),
+            3 => symbol.expiration().map(|date| date.to_string().into()),
+            4 => option.map(|option| strike(option.strike_price)),
+            5 => option.map(|option| put_call(option.put_call).into()),
+            _ => self.arguments.get((column - COLUMN_ROOT) as usize).cloned().filter(|value| *value != Value::Null),
+        };
+        ctx.set_result(&value)
+    }
+
+    fn rowid(&self) -> Result<i64> {
+        Ok(self.row as i64)
+    }
+}
+
+// A strike argument, given as an integer, a real or text, or None when not given
+fn decimal(value: &Value, argument: usize) -> Result<Option<Decimal>> {
+    let strike = match value {
+        Value::Null => return Ok(None),
+        Value::Integer(integer) => Some(Decimal::from(*integer)),
+        Value::Real(real) => Decimal::from_f64_retain(*real).map(|strike| strike.normalize()),
+        Value::Text(text) => text.parse().ok(),
+        Value::Blob(_) => None,
+    };
+    strike
+        .map(Some)
+        .ok_or_else(|| Error::ModuleError(format!("symbol_chain {} is not a number", ARGUMENTS[argument])))
+}
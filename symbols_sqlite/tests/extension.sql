@@ -0,0 +1,19 @@
+-- Run by `make test` against the built extension, which expects the output to be just `ok`
+.bail on
+.load target/debug/libsymbols_sqlite
+
+CREATE TEMP TABLE expected(actual, expected);
+INSERT INTO expected VALUES
+  (symbol_type('MSFT 110122C27.5'), 'StockOption'),
+  (symbol_underlying('BRK.B'), 'BRK'),
+  (symbol_expiry('ESU20'), '2020-09-01'),
+  (symbol_strike('ESU20 C2700'), '2700'),
+  (symbol_underlying('SPXW 250620C6000'), 'SPX'),
+  (symbol_put_call('SPY251219P00650000'), 'P'),
+  (symbol_translate('ESU20', 'native', 'cme'), 'ESU0'),
+  (symbol_type('msft!') IS NULL, 1),
+  ((SELECT group_concat(symbol, ',') FROM symbol_chain('SPX', '2025-06-02', 6000, 6005, 5)),
+   'SPX 250620P6000,SPX 250620C6000,SPX 250620P6005,SPX 250620C6005,SPX 250718P6000,SPX 250718C6000,SPX 250718P6005,SPX 250718C6005');
+
+SELECT coalesce(group_concat(quote(actual) || ' is not ' || quote(expected), char(10)), 'ok')
+FROM expected WHERE actual IS NOT expected;
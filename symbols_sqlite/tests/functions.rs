@@ -0,0 +1,115 @@
+use rusqlite::types::Value;
+use rusqlite::Connection;
+
+fn db() -> Connection {
+    let db = Connection::open_in_memory().unwrap();
+    symbols_sqlite::register(&db).unwrap();
+    db
+}
+
+fn query(db: &Connection, sql: &str) -> Vec<Value> {
+    db.query_row(sql, [], |row| (0..row.as_ref().column_count()).map(|column| row.get(column)).collect())
+        .unwrap()
+}
+
+#[test]
+fn option_fields() {
+    let values = query(
+        &db(),
+        "SELECT symbol_type(s), symbol_underlying(s), symbol_expiry(s), symbol_strike(s), symbol_put_call(s)
+         FROM (SELECT 'MSFT 110122C27.5' AS s)",
+    );
+    assert_eq!(
+        values,
+        [
+            Value::Text("StockOption".into()),
+            Value::Text("MSFT".into()),
+            Value::Text("2011-01-22".into()),
+            Value::Text("27.5".into()),
+            Value::Text("C".into()),
+        ]
+    );
+
+    // Option roots resolve to their underlying, like Symbol::parse
+    let values = query(&db(), "SELECT symbol_underlying('SPXW 250620C6000'), symbol_underlying('AAPL1 250620P27.5')");
+    assert_eq!(values, [Value::Text("SPX".into()), Value::Text("AAPL".into())]);
+}
+
+#[test]
+fn date_dependent_functions_are_not_deterministic() {
+    let db = db();
+    db.execute_batch("CREATE TABLE trades(sym TEXT); CREATE INDEX by_type ON trades(symbol_type(sym))").unwrap();
+    for sql in ["CREATE INDEX by_expiry ON trades(symbol_expiry(sym))", "CREATE INDEX by_cme ON trades(symbol_translate(sym, 'native', 'cme'))"] {
+        let err = db.execute_batch(sql).unwrap_err();
+        assert!(err.to_string().contains("non-deterministic"), "{err}");
+    }
+}
+
+#[test]
+fn future_fields() {
+    let values = query(&db(), "SELECT symbol_type('ESU20'), symbol_expiry('ESU20'), symbol_strike('ESU20'), symbol_put_call('ESU20')");
+    assert_eq!(values, [Value::Text("Future".into()), Value::Text("2020-09-01".into()), Value::Null, Value::Null]);
+}
+
+#[test]
+fn bad_symbols_are_null() {
+    let values = query(&db(), "SELECT symbol_type('msft!'), symbol_underlying(NULL), symbol_translate('msft!', 'native', 'occ')");
+    assert_eq!(values, [Value::Null, Value::Null, Value::Null]);
+}
+
+#[test]
+fn translate() {
+    let values = query(
+        &db(),
+        "SELECT symbol_translate('SPY251219C00650000', 'occ-compact', 'native'), symbol_translate('ESU20', 'native', 'cme')",
+    );
+    assert_eq!(values, [Value::Text("SPY 251219C650".into()), Value::Text("ESU0".into())]);
+
+    let err = db().query_row("SELECT symbol_translate('ESU20', 'native', 'bloomberg')", [], |_| Ok(())).unwrap_err();
+    assert!(err.to_string().contains("unknown symbology"), "{err}");
+}
+
+#[test]
+fn chain() {
+    let db = db();
+    let mut statement = db
+        .prepare("SELECT symbol, expiry, strike, put_call, increment FROM symbol_chain('SPY', '2025-06-16', 600, '601', 1)")
+        .unwrap();
+    let rows: Vec<(String, String, String, String, i64)> = statement
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)))
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(rows.len(), 2 * 2 * 11);
+    assert_eq!(rows[0], ("SPY 250620P600".into(), "2025-06-20".into(), "600".into(), "P".into(), 1));
+
+    let values = query(&db, "SELECT count(*), min(CAST(strike AS REAL)), max(CAST(strike AS REAL)) FROM symbol_chain('ES', '2025-05-15', 6000, 6100)");
+    assert_eq!(values, [Value::Integer(22), Value::Real(6000.0), Value::Real(6100.0)]);
+
+    // Arguments from another table of the join
+    let values = query(
+        &db,
+        "WITH t(root) AS (VALUES ('SPY'), ('SPX'))
+         SELECT group_concat(count, ',') FROM (
+             SELECT count(*) AS count FROM t, symbol_chain(t.root, '2025-06-16', 600, 601) GROUP BY t.root ORDER BY t.root)",
+    );
+    assert_eq!(values, [Value::Text("6,22".into())]);
+    let values = query(
+        &db,
+        "WITH t(root, low) AS (VALUES ('SPY', 600), ('QQQ', 500))
+         SELECT count(*) FROM t JOIN symbol_chain ON symbol_chain.root = t.root AND symbol_chain.low = t.low
+         WHERE symbol_chain.date = '2025-06-16' AND symbol_chain.high = t.low + 1",
+    );
+    assert_eq!(values, [Value::Integer(44)]);
+
+    // Without a strike range, the standard bands from 2.50 up to 10000
+    let values = query(&db, "SELECT count(*), min(CAST(strike AS REAL)), max(CAST(strike AS REAL)), count(low) FROM symbol_chain('SPY', '2025-06-16')");
+    assert_eq!(values, [Value::Integer(1025 * 2 * 11), Value::Real(2.5), Value::Real(10000.0), Value::Integer(0)]);
+    let values = query(&db, "SELECT count(*), min(CAST(strike AS REAL)), max(high) FROM symbol_chain('ES', '2025-05-15') WHERE low = 9990 AND increment = 5");
+    assert_eq!(values, [Value::Integer(6), Value::Real(9990.0), Value::Null]);
+
+    let err = db.prepare("SELECT * FROM symbol_chain('SPY')").unwrap_err();
+    assert!(err.to_string().contains("needs a root and a date"), "{err}");
+    let err = db.query_row("SELECT * FROM symbol_chain('SPY', '2025-06-16', 'low', 601)", [], |_| Ok(())).unwrap_err();
+    assert!(err.to_string().contains("symbol_chain low is not a number"), "{err}");
+}
@@ -0,0 +1,41 @@
+use symbols::bulk::{parse_many, parse_many_parallel};
+use symbols::symbol::Symbol;
+
+const INPUTS: [&str; 5] = ["SPY 250620C600", "ESU20", "msft!", "ESU20 C2700", "MSFT 111322C27.5"];
+
+// Enough symbols to be split across threads on any machine with more than one core
+fn feed() -> Vec<String> {
+    (0..50_000).map(|index| INPUTS[index % INPUTS.len()].to_owned()).collect()
+}
+
+#[test]
+fn parses_many_in_input_order() {
+    let results: Vec<_> = parse_many(INPUTS).collect();
+    assert_eq!(results.len(), INPUTS.len());
+    for (raw_symbol, result) in INPUTS.iter().zip(&results) {
+        assert_eq!(result, &Symbol::parse(raw_symbol), "{raw_symbol}");
+    }
+    assert_eq!(results.iter().map(Result::is_ok).collect::<Vec<_>>(), [true, true, false, true, false]);
+}
+
+#[test]
+fn parses_in_parallel_in_input_order() {
+    let feed = feed();
+    let results = parse_many_parallel(&feed);
+    assert_eq!(results.len(), feed.len());
+
+    let expected: Vec<_> = INPUTS.iter().map(|raw_symbol| Symbol::parse(raw_symbol)).collect();
+    for (index, result) in results.iter().enumerate() {
+        assert_eq!(result, &expected[index % INPUTS.len()], "symbol {index}");
+    }
+    // Errors carry their own input, so they can be told apart after the fact
+    let errors: Vec<_> = results.iter().enumerate().filter_map(|(index, result)| result.as_ref().err().map(|err| (index, err))).collect();
+    assert_eq!(errors.len(), 20_000);
+    assert!(errors.iter().all(|(index, err)| err.input == INPUTS[index % INPUTS.len()]));
+}
+
+#[test]
+fn parses_nothing() {
+    assert_eq!(parse_many(Vec::<String>::new()).count(), 0);
+    assert!(parse_many_parallel::<&str>(&[]).is_empty());
+}
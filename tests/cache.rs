@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use symbols::cache::{CacheStats, LocalSymbolCache, SymbolCache};
+
+#[test]
+fn evicts_the_least_recently_used() {
+    let cache = SymbolCache::new(2);
+    let first = cache.parse("ESU20").unwrap();
+    cache.parse("ESZ20").unwrap();
+    // Touching ESU20 leaves ESZ20 the least recently used
+    assert!(Arc::ptr_eq(&cache.parse("ESU20").unwrap(), &first));
+    cache.parse("ESH21").unwrap();
+    assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 3, evictions: 1, len: 2, capacity: 2 });
+
+    // ESU20 survived, ESZ20 has to be parsed again and pushes out ESH21
+    assert!(Arc::ptr_eq(&cache.parse("ESU20").unwrap(), &first));
+    cache.parse("ESZ20").unwrap();
+    cache.parse("ESU20").unwrap();
+    assert_eq!(cache.stats(), CacheStats { hits: 3, misses: 4, evictions: 2, len: 2, capacity: 2 });
+    cache.parse("ESH21").unwrap();
+    assert_eq!(cache.stats().misses, 5);
+}
+
+#[test]
+fn caches_failures() {
+    let cache = SymbolCache::new(10);
+    let err = cache.parse("msft!").unwrap_err();
+    assert_eq!(cache.parse("msft!").unwrap_err(), err);
+    assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1, evictions: 0, len: 1, capacity: 10 });
+}
+
+#[test]
+fn reports_hit_rate() {
+    let mut cache = LocalSymbolCache::new(0);
+    assert_eq!(cache.stats().hit_rate(), 0.0);
+    // A zero capacity still holds one entry
+    assert_eq!(cache.stats().capacity, 1);
+    for raw_symbol in ["ESU20", "ESU20", "ESU20", "ESZ20"] {
+        cache.parse(raw_symbol).unwrap();
+    }
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.evictions), (2, 2, 1));
+    assert_eq!(stats.hit_rate(), 0.5);
+
+    cache.clear();
+    assert_eq!(cache.stats(), CacheStats { capacity: 1, ..Default::default() });
+}
@@ -0,0 +1,94 @@
+use chrono::NaiveDate;
+
+use symbols::calendar;
+use symbols::registry::ProductRegistry;
+use symbols::symbol::{ExpirationSeries, Symbol};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn lists_each_holiday() {
+    let holidays = [
+        (1, 1),   // New Year's Day
+        (1, 20),  // Martin Luther King Jr. Day, third Monday
+        (2, 17),  // Washington's Birthday, third Monday
+        (4, 18),  // Good Friday
+        (5, 26),  // Memorial Day, last Monday
+        (6, 19),  // Juneteenth
+        (7, 4),   // Independence Day
+        (9, 1),   // Labor Day, first Monday
+        (11, 27), // Thanksgiving, fourth Thursday
+        (12, 25), // Christmas
+    ];
+    assert_eq!(calendar::holidays(2025), holidays.map(|(month, day)| date(2025, month, day)));
+}
+
+#[test]
+fn observes_weekend_holidays() {
+    // Saturday holidays move to the Friday before, Sunday ones to the Monday after
+    assert!(calendar::is_holiday(date(2021, 12, 24)));
+    assert!(calendar::is_holiday(date(2021, 7, 5)));
+    assert!(calendar::is_holiday(date(2022, 6, 20)));
+    // Except New Year's Day, which is not observed in the year before
+    assert!(!calendar::is_holiday(date(2021, 12, 31)));
+    assert!(!calendar::holidays(2022).iter().any(|holiday| holiday.to_string().ends_with("-01-01")));
+    // Juneteenth is only observed from 2022
+    assert!(!calendar::is_holiday(date(2021, 6, 18)));
+}
+
+#[test]
+fn finds_good_friday() {
+    for good_friday in [date(2019, 4, 19), date(2024, 3, 29), date(2025, 4, 18), date(2038, 4, 23)] {
+        assert!(calendar::is_holiday(good_friday), "{good_friday}");
+    }
+}
+
+#[test]
+fn rolls_back_to_trading_days() {
+    assert!(calendar::is_trading_day(date(2025, 6, 20)));
+    assert!(!calendar::is_trading_day(date(2025, 6, 21)));
+    assert_eq!(calendar::roll_back(date(2025, 6, 22)), date(2025, 6, 20));
+    assert_eq!(calendar::roll_back(date(2025, 7, 4)), date(2025, 7, 3));
+
+    assert_eq!(calendar::third_friday(2025, 4), Some(date(2025, 4, 18)));
+    // The April 2025 monthly falls on Good Friday and expires the Thursday before
+    assert_eq!(calendar::monthly_expiration(2025, 4), Some(date(2025, 4, 17)));
+    assert_eq!(calendar::monthly_expiration(2025, 6), Some(date(2025, 6, 20)));
+    assert_eq!(calendar::weekly_expiration(date(2025, 6, 30)), date(2025, 7, 3));
+    assert_eq!(calendar::weekly_expiration(date(2025, 6, 11)), date(2025, 6, 13));
+    assert_eq!(calendar::last_trading_day_of_month(2025, 5), Some(date(2025, 5, 30)));
+}
+
+#[test]
+fn classifies_each_series() {
+    let registry = ProductRegistry::with_defaults();
+    let cases = [
+        ("SPY", date(2025, 6, 20), ExpirationSeries::Monthly),
+        ("SPY", date(2025, 4, 17), ExpirationSeries::Monthly),
+        // Standard options expired on the Saturday after the third Friday before 2015
+        ("MSFT", date(2011, 1, 22), ExpirationSeries::Monthly),
+        ("SPY", date(2025, 6, 13), ExpirationSeries::Weekly),
+        ("SPY", date(2025, 7, 3), ExpirationSeries::Weekly),
+        ("SPY", date(2025, 6, 30), ExpirationSeries::Quarterly),
+        ("SPY", date(2025, 7, 31), ExpirationSeries::EndOfMonth),
+        ("SPY", date(2025, 5, 30), ExpirationSeries::EndOfMonth),
+        ("SPY", date(2025, 6, 17), ExpirationSeries::Daily),
+        // SPXW never lists the AM-settled monthly, so its third Friday is a weekly
+        ("SPX", date(2025, 6, 20), ExpirationSeries::Monthly),
+        ("SPXW", date(2025, 6, 20), ExpirationSeries::Weekly),
+        ("SPXW", date(2025, 6, 13), ExpirationSeries::Weekly),
+    ];
+    for (root, date, series) in cases {
+        assert_eq!(registry.classify_expiration(root, date), series, "{root} {date}");
+    }
+}
+
+#[test]
+fn parsed_options_carry_their_series() {
+    let series = |raw_symbol: &str| Symbol::parse(raw_symbol).unwrap().option_contract.unwrap().series;
+    assert_eq!(series("SPY 250620C600"), Some(ExpirationSeries::Monthly));
+    assert_eq!(series("SPXW 250620C6000"), Some(ExpirationSeries::Weekly));
+    assert_eq!(series("SPY250617P00600000"), Some(ExpirationSeries::Daily));
+}
@@ -0,0 +1,145 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use symbols::chain::{ChainBuilder, StrikeIncrements, DEFAULT_HIGHEST_STRIKE};
+use symbols::symbol::{ExpirationSeries, SymbolType};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn lists_every_expiration_in_the_window() {
+    let symbols = ChainBuilder::new("SPY", date(2025, 6, 2))
+        .strikes(500.into(), 700.into())
+        .increments(StrikeIncrements::uniform(Decimal::ONE))
+        .build()
+        .unwrap();
+    assert!(symbols.is_sorted());
+
+    let mut expirations: Vec<NaiveDate> = symbols.iter().filter_map(|symbol| symbol.expiration()).collect();
+    expirations.dedup();
+    // Jul 3 for the Jul 4 holiday, Jun 30 and Jul 31 as quarterly and end of month
+    let days = [(6, 6), (6, 13), (6, 20), (6, 27), (6, 30), (7, 3), (7, 11), (7, 18), (7, 25), (7, 31), (8, 1)];
+    assert_eq!(expirations, days.map(|(month, day)| date(2025, month, day)));
+    assert_eq!(symbols.len(), days.len() * 201 * 2);
+
+    let monthly = &symbols[2 * 201 * 2];
+    assert_eq!(monthly.original_symbol, "SPY 250620P500");
+    assert_eq!(monthly.option_contract.as_ref().unwrap().series, Some(ExpirationSeries::Monthly));
+    assert_eq!(symbols.last().unwrap().original_symbol, "SPY 250801C700");
+}
+
+#[test]
+fn strikes_follow_price_bands() {
+    let symbols = ChainBuilder::new("XYZ", date(2025, 6, 2))
+        .until(date(2025, 6, 30))
+        .series(&[ExpirationSeries::Monthly])
+        .strikes(20.into(), 220.into())
+        .canonical_symbols()
+        .unwrap();
+    let calls: Vec<&str> = symbols.iter().filter(|symbol| symbol.contains('C')).map(String::as_str).collect();
+    assert_eq!(&calls[..4], ["XYZ 250620C20", "XYZ 250620C22.5", "XYZ 250620C25", "XYZ 250620C30"]);
+    assert_eq!(&calls[calls.len() - 3..], ["XYZ 250620C200", "XYZ 250620C210", "XYZ 250620C220"]);
+    assert_eq!(calls.len(), 3 + 35 + 2);
+}
+
+#[test]
+fn index_and_future_roots() {
+    let spx = ChainBuilder::new("SPX", date(2025, 6, 2)).strikes(6000.into(), 6000.into()).canonical_symbols().unwrap();
+    assert_eq!(spx, ["SPX 250620P6000", "SPX 250620C6000", "SPX 250718P6000", "SPX 250718C6000"]);
+
+    let es = ChainBuilder::new("ES", date(2025, 5, 15))
+        .strikes(6000.into(), 6010.into())
+        .increments(StrikeIncrements::uniform(5.into()))
+        .build()
+        .unwrap();
+    assert!(es.iter().all(|symbol| symbol.symbol_type == SymbolType::FutureOption));
+    let es: Vec<&str> = es.iter().map(|symbol| symbol.original_symbol.as_str()).collect();
+    assert_eq!(es, ["ESM25 P6000", "ESM25 C6000", "ESM25 P6005", "ESM25 C6005", "ESM25 P6010", "ESM25 C6010"]);
+}
+
+#[test]
+fn skips_expired_future_months() {
+    let es = |from| {
+        ChainBuilder::new("ES", from)
+            .until(date(2025, 9, 30))
+            .strikes(6000.into(), 6000.into())
+            .canonical_symbols()
+            .unwrap()
+    };
+    // ESM25 options expired on June 20
+    assert_eq!(es(date(2025, 6, 25)), ["ESU25 P6000", "ESU25 C6000"]);
+    assert_eq!(es(date(2025, 6, 20)), ["ESM25 P6000", "ESM25 C6000", "ESU25 P6000", "ESU25 C6000"]);
+}
+
+#[test]
+fn starts_on_the_next_trading_day() {
+    let expirations = |from, until| {
+        let symbols = ChainBuilder::new("SPY", from).until(until).strikes(600.into(), 600.into()).build().unwrap();
+        let mut expirations: Vec<NaiveDate> = symbols.iter().filter_map(|symbol| symbol.expiration()).collect();
+        expirations.dedup();
+        expirations
+    };
+    // From a Saturday, and from the Jul 4 holiday
+    assert_eq!(expirations(date(2025, 6, 21), date(2025, 7, 4)), [date(2025, 6, 27), date(2025, 6, 30), date(2025, 7, 3)]);
+    assert_eq!(expirations(date(2025, 7, 4), date(2025, 7, 11)), [date(2025, 7, 11)]);
+    assert!(expirations(date(2025, 7, 5), date(2025, 7, 6)).is_empty());
+}
+
+#[test]
+fn defaults_the_strike_window() {
+    let increments = StrikeIncrements::uniform(5.into());
+    assert_eq!(increments.window(), (5.into(), DEFAULT_HIGHEST_STRIKE));
+    let es = ChainBuilder::new("ES", date(2025, 5, 15)).increments(increments).canonical_symbols().unwrap();
+    assert_eq!(es.len(), 2000 * 2);
+    assert_eq!((es[0].as_str(), es[es.len() - 1].as_str()), ("ESM25 P5", "ESM25 C10000"));
+
+    let xyz = ChainBuilder::new("XYZ", date(2025, 6, 2)).series(&[ExpirationSeries::Monthly]).canonical_symbols().unwrap();
+    assert_eq!(&xyz[..2], ["XYZ 250620P2.5", "XYZ 250620C2.5"]);
+    assert_eq!(xyz.last().unwrap(), "XYZ 250718C10000");
+}
+
+#[test]
+fn rejects_bad_requests() {
+    let builder = ChainBuilder::new("SPY", date(2025, 6, 2));
+    let err = builder.to_owned().strikes(700.into(), 500.into()).build().unwrap_err();
+    assert_eq!(err.to_string(), "could not build the option chain for \"SPY\": 700 to 500 is not a strike range");
+    let err = builder.to_owned().strikes(500.into(), 700.into()).increments(StrikeIncrements::uniform(Decimal::ZERO)).build();
+    assert_eq!(err.unwrap_err().message, "strike increment 0 is not positive");
+    // Stops counting expirations once the limit is passed
+    let err = builder.to_owned().strikes(500.into(), 700.into()).limit(100).build().unwrap_err();
+    assert_eq!(err.message, "at least 126 symbols is more than the limit of 100");
+    let err = builder.to_owned().strikes(500.into(), 700.into()).until(date(2025, 6, 6)).limit(10).build().unwrap_err();
+    assert_eq!(err.message, "at least 42 symbols is more than the limit of 10");
+    let err = builder.to_owned().days(99_999_999_999).build().unwrap_err();
+    assert_eq!(err.message, "99999999999 days from 2025-06-02 is out of range");
+}
+
+#[test]
+fn sizes_the_chain_before_building_it() {
+    // A billion strikes would not fit in memory, so this only passes if nothing is generated
+    let err = ChainBuilder::new("SPY", date(2025, 6, 2))
+        .strikes(Decimal::new(1, 6), 1_000_000_000.into())
+        .increments(StrikeIncrements::uniform(Decimal::new(1, 6)))
+        .limit(100_000)
+        .build()
+        .unwrap_err();
+    assert_eq!(err.message, "at least 2000000000000000 symbols is more than the limit of 100000");
+}
+
+#[test]
+fn future_options_expire_by_the_product_rule() {
+    let chain = |root, from, until| {
+        ChainBuilder::new(root, from).until(until).strikes(100.into(), 100.into()).canonical_symbols()
+    };
+    // ZNU25 options expire on the last Friday two trading days before September
+    assert_eq!(chain("ZN", date(2025, 8, 1), date(2025, 8, 22)).unwrap(), ["ZNU25 P100", "ZNU25 C100"]);
+    assert!(chain("ZN", date(2025, 8, 23), date(2025, 8, 31)).unwrap().is_empty());
+    // 6E options expire on the second Friday before the third Wednesday
+    assert_eq!(chain("6E", date(2025, 9, 1), date(2025, 9, 5)).unwrap(), ["6EU25 P100", "6EU25 C100"]);
+    assert!(chain("6E", date(2025, 9, 6), date(2025, 9, 30)).unwrap().is_empty());
+
+    let err = chain("CL", date(2025, 6, 2), date(2025, 9, 30)).unwrap_err();
+    assert_eq!(err.message, "the expiration of options on CL futures is not known");
+}
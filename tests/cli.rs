@@ -0,0 +1,101 @@
+use assert_cmd::Command;
+use serde_json::{json, Value};
+
+fn symbols() -> Command {
+    Command::cargo_bin("symbols").unwrap()
+}
+
+fn stdout(command: &mut Command) -> String {
+    String::from_utf8(command.output().unwrap().stdout).unwrap()
+}
+
+fn ndjson(command: &mut Command) -> Vec<Value> {
+    stdout(command.args(["--format", "ndjson"])).lines().map(|line| serde_json::from_str(line).unwrap()).collect()
+}
+
+#[test]
+fn validates_symbols() {
+    symbols().args(["validate", "ESU20", "SPY 250620C600", "AAPL", "BRK.B"]).assert().success();
+
+    let rows = ndjson(symbols().args(["validate", "ESU2O", "ESX20", "MSFT 11012C27.5", "msft!", "AAPL"]));
+    let checked: Vec<_> = rows.iter().map(|row| (row["input"].clone(), row["valid"].clone(), row["suggestion"].clone())).collect();
+    assert_eq!(checked, [
+        (json!("ESU2O"), json!(false), json!("ESU20")),
+        (json!("ESX20"), json!(false), json!("ESZ20")),
+        (json!("MSFT 11012C27.5"), json!(false), json!("MSFT 110121C27.5")),
+        (json!("msft!"), json!(false), Value::Null),
+        (json!("AAPL"), json!(true), Value::Null),
+    ]);
+    assert_eq!(rows[1]["error"], "ES does not list X contracts");
+    assert_eq!(rows[3]["position"], 4);
+
+    symbols().args(["validate", "ESU2O"]).assert().code(1);
+}
+
+#[test]
+fn fails_unknown_symbols_when_strict() {
+    symbols().args(["validate", "AAPL"]).assert().success();
+    let rows = ndjson(symbols().args(["validate", "--strict", "AAPL", "ESU20"]));
+    assert_eq!((rows[0]["valid"].clone(), rows[0]["error"].clone()), (json!(false), json!("not a recognized symbol")));
+    assert_eq!(rows[1]["valid"], true);
+    symbols().args(["validate", "--strict", "AAPL"]).assert().code(1);
+}
+
+#[test]
+fn reads_symbols_from_stdin() {
+    let output = stdout(symbols().args(["parse", "--format", "csv"]).write_stdin("ESU20\r\n\n  \nmsft!\n"));
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("input,valid,symbol_type,underlying,"));
+    assert!(lines[1].starts_with("ESU20,true,Future,ES,,2020-09-01,"));
+    assert!(lines[2].starts_with("msft!,false,"));
+}
+
+#[test]
+fn parses_leniently() {
+    let strict = ndjson(symbols().args(["parse", "esu20"]));
+    assert_eq!(strict[0]["symbol_type"], "Unknown");
+    let lenient = ndjson(symbols().args(["parse", "--lenient", "esu20", "ＥＳＵ２０"]));
+    for row in &lenient {
+        assert_eq!((row["symbol_type"].clone(), row["underlying"].clone()), (json!("Future"), json!("ES")));
+    }
+    // The input is kept as typed, with the rewrites that made it parse
+    assert_eq!((lenient[0]["input"].clone(), lenient[0]["normalizations"].clone()), (json!("esu20"), json!(["Uppercased"])));
+    assert_eq!(lenient[1]["normalizations"], json!(["FullWidth"]));
+
+    let output = stdout(symbols().args(["parse", "--lenient", "--format", "csv", " ESU20", "ESU20"]));
+    let lines: Vec<&str> = output.lines().collect();
+    assert!(lines[0].ends_with(",error,normalizations"), "{output}");
+    assert!(lines[1].ends_with(",Trimmed"), "{output}");
+    assert!(lines[2].ends_with(","), "{output}");
+}
+
+#[test]
+fn translates_symbols() {
+    let rows = ndjson(symbols().args(["translate", "--to", "occ", "SPY 250620C600", "ESU20"]));
+    assert_eq!(rows[0]["output"], "SPY   250620C00600000");
+    assert_eq!(rows[1]["output"], Value::Null);
+    assert!(rows[1]["error"].as_str().unwrap().contains("cannot be written in the occ symbology"));
+
+    let rows = ndjson(symbols().args(["translate", "--from", "occ-compact", "--to", "native", "SPY250620C00600000"]));
+    assert_eq!(rows[0]["output"], "SPY 250620C600");
+
+    let assert = symbols().args(["translate", "--to", "bogus", "ESU20"]).assert().code(2);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("expected one of native, occ, occ-compact, cme"), "{stderr}");
+}
+
+#[test]
+fn explains_tokens() {
+    let rows = ndjson(symbols().args(["explain", "ESU20"]));
+    let tokens: Vec<_> = rows.iter().map(|row| (row["token"].clone(), row["text"].clone())).collect();
+    assert_eq!(tokens, [(json!("root"), json!("ES")), (json!("month"), json!("U")), (json!("year"), json!("20"))]);
+}
+
+#[test]
+fn writes_each_format() {
+    let table = stdout(symbols().args(["validate", "ESU20"]));
+    assert_eq!(table.lines().nth(2).unwrap().split_whitespace().take(3).collect::<Vec<_>>(), ["ESU20", "true", "Future"]);
+    let json: Value = serde_json::from_str(&stdout(symbols().args(["validate", "--format", "json", "ESU20"]))).unwrap();
+    assert_eq!(json[0]["symbol_type"], "Future");
+}
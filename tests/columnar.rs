@@ -0,0 +1,58 @@
+use arrow::array::{Array, AsArray, LargeStringArray, StringArray};
+use arrow::datatypes::{Date32Type, Decimal128Type};
+use chrono::NaiveDate;
+
+use symbols::columnar::{self, parse_array, parse_column};
+
+fn days(year: i32, month: u32, day: u32) -> i32 {
+    (NaiveDate::from_ymd_opt(year, month, day).unwrap() - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32
+}
+
+#[test]
+fn parses_into_struct_columns() {
+    let symbols = StringArray::from(vec![Some("MSFT 110122C27.5"), Some("ESU20"), Some("AAPL"), None, Some("msft!")]);
+    let parsed = parse_array(&symbols);
+    assert_eq!(parsed.fields(), &columnar::fields());
+    assert_eq!(parsed.len(), 5);
+
+    let symbol_type = parsed.column_by_name("symbol_type").unwrap().as_string::<i32>();
+    let underlying = parsed.column_by_name("underlying").unwrap().as_string::<i32>();
+    let expiry = parsed.column_by_name("expiry").unwrap().as_primitive::<Date32Type>();
+    let strike = parsed.column_by_name("strike").unwrap().as_primitive::<Decimal128Type>();
+    let put_call = parsed.column_by_name("put_call").unwrap().as_string::<i32>();
+
+    assert_eq!(symbol_type.value(0), "StockOption");
+    assert_eq!(underlying.value(0), "MSFT");
+    assert_eq!(expiry.value(0), days(2011, 1, 22));
+    assert_eq!(strike.value_as_string(0), "27.500");
+    assert_eq!(put_call.value(0), "C");
+
+    assert_eq!(symbol_type.value(1), "Future");
+    assert_eq!(expiry.value(1), days(2020, 9, 1));
+    assert!(strike.is_null(1));
+    assert!(put_call.is_null(1));
+
+    assert_eq!(underlying.value(2), "AAPL");
+    assert!(expiry.is_null(2));
+
+    // Null and unparseable symbols are null rows
+    assert!(parsed.is_valid(2));
+    assert!(parsed.is_null(3));
+    assert!(parsed.is_null(4));
+}
+
+#[test]
+fn resolves_option_roots_to_their_underlying() {
+    let symbols = StringArray::from(vec!["SPXW 250620C6000", "AAPL1 250620P27.5", "SPY 250620C600"]);
+    let parsed = parse_array(&symbols);
+    let underlying = parsed.column_by_name("underlying").unwrap().as_string::<i32>();
+    assert_eq!(underlying.iter().collect::<Vec<_>>(), [Some("SPX"), Some("AAPL"), Some("SPY")]);
+}
+
+#[test]
+fn parses_other_string_types() {
+    let symbols = LargeStringArray::from(vec!["ESU20 C2700"]);
+    let parsed = parse_column(&symbols).unwrap();
+    assert_eq!(parsed.column_by_name("symbol_type").unwrap().as_string::<i32>().value(0), "FutureOption");
+    assert_eq!(parsed.column_by_name("strike").unwrap().as_primitive::<Decimal128Type>().value_as_string(0), "2700.000");
+}
@@ -0,0 +1,133 @@
+// The hand-written parser behind parse_symbol must agree with the pest grammar,
+// kept as the reference in parse_symbol_pest, on every input.
+
+use symbols::symbol_parser::{parse_symbol, parse_symbol_pest};
+
+const CORPUS: &[&str] = &[
+    // stocks and unknown symbols
+    "AAPL",
+    "BRK.B",
+    "RDS:A",
+    " X",
+    "AB CD",
+    // futures
+    "ASM4",
+    "ASM4.ZERO",
+    "@ASM4",
+    "@ASM4.ZERO",
+    "@AS",
+    "@AS.TEST",
+    "ESU20",
+    "ESU0",
+    "ESU2020",
+    "E SU2 0",
+    "ESU 2 0",
+    "@ ES",
+    "ES U0 .Z",
+    // future options
+    "ESU20 C2700",
+    "ESU20 P2700",
+    "ESU20 CALL2700",
+    "ESU20 PUT2700",
+    "ESU20 C 2700",
+    "ESU20.X C2700",
+    "ESU20 X.C2700",
+    "@ESU20 C2700",
+    "X@ESU0 C1",
+    // stock options
+    "MSFT 110122C27.5",
+    "MSFT 110122P27.5",
+    "MSFT 110122C.5",
+    "MSFT 110122C27",
+    "MSFT 11012C27.5",
+    "SPY251219C00650000",
+    "SPY   251219C00650000",
+    "SPXW 251219P6000",
+    "AAPL1 251219C150",
+    // failures
+    "",
+    " ",
+    ".",
+    "@",
+    "ES-U0",
+    "AB.",
+    ".AB",
+    "ESU20 C",
+    "MSFT 110122C27.",
+    "ÉSU20",
+    "ESU20 C2700 ",
+    "  ESU20 C2700",
+    "ESU20\tC2700",
+    "esu20 c2700",
+];
+
+// Characters that exercise every terminal of the grammar
+const ALPHABET: &[u8] = b"ACEFLMPSTUXZ0125 .:@";
+
+// xorshift64*, enough to spread inputs without pulling in a rand dependency
+struct Generator(u64);
+
+impl Generator {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn symbol(&mut self) -> String {
+        let len = (self.next() % 16) as usize;
+        (0..len)
+            .map(|_| ALPHABET[(self.next() % ALPHABET.len() as u64) as usize] as char)
+            .collect()
+    }
+
+    // A valid symbol with a few characters replaced, so the generated inputs get past
+    // the first terminals often enough to reach every rule
+    fn mutated(&mut self) -> String {
+        let mut symbol: Vec<u8> = CORPUS[(self.next() % CORPUS.len() as u64) as usize].bytes().collect();
+        if symbol.is_empty() || !symbol.is_ascii() {
+            return self.symbol();
+        }
+        for _ in 0..=(self.next() % 3) {
+            let index = (self.next() % symbol.len() as u64) as usize;
+            symbol[index] = ALPHABET[(self.next() % ALPHABET.len() as u64) as usize];
+        }
+        String::from_utf8(symbol).unwrap()
+    }
+}
+
+fn assert_same(symbol: &str) {
+    let fast = parse_symbol(symbol);
+    let reference = parse_symbol_pest(symbol);
+    assert_eq!(
+        fast.is_ok(),
+        reference.is_ok(),
+        "parsers disagree on whether {symbol:?} parses: {fast:?} vs {reference:?}"
+    );
+    match (fast, reference) {
+        (Ok(fast), Ok(reference)) => {
+            assert_eq!(format!("{fast:?}"), format!("{reference:?}"), "parsers disagree on {symbol:?}");
+        }
+        (Err(fast), Err(reference)) => {
+            assert_eq!(fast.position, reference.position, "parsers fail {symbol:?} at different positions");
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn agrees_on_corpus() {
+    for symbol in CORPUS {
+        assert_same(symbol);
+    }
+}
+
+#[test]
+fn agrees_on_generated_symbols() {
+    let mut generator = Generator(0x5EED_CAFE_F00D_D00D);
+    for _ in 0..50_000 {
+        assert_same(&generator.symbol());
+        assert_same(&generator.mutated());
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::NaiveTime;
+use rust_decimal::Decimal;
+use serde_json::Value;
+
+use symbols::symbol::{
+    Deliverable, ExerciseStyle, ExpirationSeries, SettlementTime, SettlementType, Spread, SpreadLeg, Symbol,
+};
+use symbols::{fbs, proto};
+
+const SYMBOLS: [&str; 7] = ["AAPL", "BRK.B", "ESU20", "@ES", "ESU20 C2700", "MSFT 110122C27.5", "SPY251219C00650000"];
+
+// Every optional option field set, with a negative cash amount for the decimal sign
+fn adjusted_option() -> Symbol {
+    let mut symbol = Symbol::parse("MSFT 110122C27.5").unwrap();
+    let option = symbol.option_contract.as_mut().unwrap();
+    option.series = Some(ExpirationSeries::Weekly);
+    option.adjusted = true;
+    option.deliverable = Some(Deliverable {
+        underlying: "MSFT".to_owned(),
+        multiplier: Decimal::new(100, 0),
+        shares: Decimal::new(1505, 1),
+        cash: Decimal::new(-1234567890123456789, 9),
+    });
+    option.exercise_style = Some(ExerciseStyle::American);
+    option.settlement_type = Some(SettlementType::Physical);
+    option.settlement_time = Some(SettlementTime::Pm);
+    option.expiration_time = NaiveTime::from_hms_opt(16, 0, 0);
+    option.time_zone = Some("America/New_York".to_owned());
+    symbol
+}
+
+fn symbols() -> Vec<Symbol> {
+    let mut symbols: Vec<Symbol> = SYMBOLS.iter().map(|raw| Symbol::parse(raw).unwrap()).collect();
+    symbols.push(adjusted_option());
+    symbols
+}
+
+fn calendar() -> Spread {
+    Spread {
+        legs: vec![
+            SpreadLeg { symbol: Symbol::parse("ESU20").unwrap(), ratio: 1 },
+            SpreadLeg { symbol: Symbol::parse("ESZ20").unwrap(), ratio: -1 },
+        ],
+    }
+}
+
+fn json<T: serde::Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn protobuf_round_trips() {
+    for symbol in symbols() {
+        let read = proto::decode(&proto::encode(&symbol)).unwrap();
+        assert_eq!(json(&read), json(&symbol), "{}", symbol.original_symbol);
+    }
+    let spread = calendar();
+    assert_eq!(json(&proto::decode_spread(&proto::encode_spread(&spread)).unwrap()), json(&spread));
+}
+
+#[test]
+fn flatbuffers_round_trips() {
+    for symbol in symbols() {
+        let read = fbs::decode(&fbs::encode(&symbol)).unwrap();
+        assert_eq!(json(&read), json(&symbol), "{}", symbol.original_symbol);
+    }
+    let spread = calendar();
+    assert_eq!(json(&fbs::decode_spread(&fbs::encode_spread(&spread)).unwrap()), json(&spread));
+}
+
+#[test]
+fn protobuf_rejects_options_without_put_call() {
+    let mut message = proto::v1::Symbol::from(&Symbol::parse("MSFT 110122C27.5").unwrap());
+    message.option_contract.as_mut().unwrap().put_call = proto::v1::PutCall::Unspecified as i32;
+    assert!(Symbol::try_from(message).is_err());
+}
+
+#[test]
+fn rejects_garbage() {
+    assert!(proto::decode(&[0xff, 0xff, 0xff]).is_err());
+    assert!(fbs::decode(&[0xff, 0xff, 0xff]).is_err());
+}
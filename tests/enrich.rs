@@ -0,0 +1,109 @@
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::Command;
+
+const TRADES: &str = "\
+id,symbol,qty
+1,SPY 250620C600,10
+2,\"ESU20\",5
+3,msft!,1
+4,AAPL1 250620P27.5,2
+";
+
+fn symbols() -> Command {
+    Command::cargo_bin("symbols").unwrap()
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+fn enrich(args: &[&str], input: &str) -> String {
+    let output = symbols().arg("enrich").args(args).write_stdin(input).assert().success();
+    String::from_utf8(output.get_output().stdout.to_owned()).unwrap()
+}
+
+#[test]
+fn adds_parsed_columns_to_csv() {
+    assert_eq!(enrich(&[], TRADES), "\
+id,symbol,qty,root,type,expiration,strike,put_call,parse_error
+1,SPY 250620C600,10,SPY,StockOption,2025-06-20,600,C,
+2,ESU20,5,ES,Future,2020-09-01,,,
+3,msft!,1,,,,,,\"could not parse symbol \"\"msft!\"\" at position 4: unexpected character\"
+4,AAPL1 250620P27.5,2,AAPL1,StockOption,2025-06-20,27.5,P,
+");
+}
+
+#[test]
+fn reads_the_named_column_and_prefixes_added_ones() {
+    let output = enrich(&["--column", "ticker", "--prefix", "sym_"], "ticker,side\nESZ20 P2500,buy\n");
+    assert_eq!(output, "\
+ticker,side,sym_root,sym_type,sym_expiration,sym_strike,sym_put_call,sym_parse_error
+ESZ20 P2500,buy,ES,FutureOption,2020-12-01,2500,P,
+");
+}
+
+#[test]
+fn reads_and_writes_files() {
+    let (input, output) = (temp_path("trades.csv"), temp_path("trades.enriched.csv"));
+    fs::write(&input, TRADES).unwrap();
+    symbols().arg("enrich").arg(&input).arg("--output").arg(&output).assert().success().stdout("");
+    assert_eq!(fs::read_to_string(&output).unwrap(), enrich(&[], TRADES));
+
+    // The format comes from the extension unless given
+    let renamed = temp_path("trades.txt");
+    fs::copy(&input, &renamed).unwrap();
+    let failed = symbols().arg("enrich").arg(&renamed).assert().code(2);
+    assert!(String::from_utf8_lossy(&failed.get_output().stderr).contains("cannot tell the format"));
+    symbols().arg("enrich").arg(&renamed).args(["--format", "csv"]).assert().success();
+}
+
+#[test]
+fn reports_input_errors() {
+    let failed = symbols().args(["enrich", "--column", "ticker"]).write_stdin(TRADES).assert().code(2);
+    assert_eq!(String::from_utf8_lossy(&failed.get_output().stderr), "symbols: no column named \"ticker\"\n");
+
+    // Added columns named like an input column
+    let failed = symbols().arg("enrich").write_stdin("symbol,type\nESU20,buy\n").assert().code(2);
+    assert_eq!(
+        String::from_utf8_lossy(&failed.get_output().stderr),
+        "symbols: the input already has a column named \"type\", use --prefix to rename the added columns\n"
+    );
+    symbols().args(["enrich", "--prefix", "sym_"]).write_stdin("symbol,type\nESU20,buy\n").assert().success();
+    symbols().args(["enrich", "--prefix", "sym_"]).write_stdin("symbol,sym_root\nESU20,ES\n").assert().code(2);
+
+    // Rows with a different number of fields than the header
+    symbols().arg("enrich").write_stdin("id,symbol\n1,ESU20,extra\n").assert().code(2);
+    symbols().arg("enrich").arg(temp_path("missing.csv")).assert().code(2);
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn enriches_arrow_files() {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, Date32Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::ipc::reader::FileReader;
+    use arrow::ipc::writer::FileWriter;
+    use arrow::record_batch::RecordBatch;
+
+    let schema = Arc::new(Schema::new(vec![Field::new("symbol", DataType::Utf8, true)]));
+    let symbols_column = StringArray::from(vec![Some("SPY 250620C600"), None, Some("msft!")]);
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(symbols_column)]).unwrap();
+    let (input, output) = (temp_path("trades.arrow"), temp_path("trades.enriched.arrow"));
+    let mut writer = FileWriter::try_new(fs::File::create(&input).unwrap(), &schema).unwrap();
+    writer.write(&batch).unwrap();
+    writer.finish().unwrap();
+
+    symbols().arg("enrich").arg(&input).arg("--output").arg(&output).assert().success();
+    let batches: Vec<RecordBatch> = FileReader::try_new(fs::File::open(&output).unwrap(), None).unwrap().map(Result::unwrap).collect();
+    let enriched = &batches[0];
+    let root = enriched.column_by_name("root").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(root.iter().collect::<Vec<_>>(), [Some("SPY"), None, None]);
+    let expiration = enriched.column_by_name("expiration").unwrap().as_any().downcast_ref::<Date32Array>().unwrap();
+    assert_eq!(expiration.value_as_date(0).unwrap().to_string(), "2025-06-20");
+    let errors = enriched.column_by_name("parse_error").unwrap();
+    assert_eq!((errors.is_null(0), errors.is_null(1), errors.is_null(2)), (true, true, false));
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use symbols::interner::{PackedSymbol, SymbolInterner};
+use symbols::symbol::{PutOrCall, Symbol, SymbolType};
+
+fn pack(raw_symbol: &str) -> Option<PackedSymbol> {
+    PackedSymbol::pack(&Symbol::parse(raw_symbol).unwrap())
+}
+
+#[test]
+fn hands_out_stable_ids() {
+    let interner = SymbolInterner::new();
+    let ids: Vec<_> = ["SPY 250620C600", "SPY 250620P600", "ESU20", "SPY 250620C600"]
+        .into_iter()
+        .map(|raw_symbol| interner.intern_str(raw_symbol).unwrap())
+        .collect();
+    assert_eq!(ids.iter().map(|id| id.index()).collect::<Vec<_>>(), [0, 1, 2, 0]);
+    assert_eq!(interner.len(), 3);
+
+    // The same contract written another way is the same id
+    assert_eq!(interner.intern_str("SPY250620C00600000").unwrap(), ids[0]);
+    assert_eq!(interner.get(&Symbol::parse("SPY 250620C600").unwrap()), Some(ids[0]));
+    assert_eq!(interner.get(&Symbol::parse("QQQ 250620C600").unwrap()), None);
+    assert!(interner.intern_str("!!!").is_err());
+    assert_eq!(interner.len(), 3);
+
+    let symbol = interner.resolve(ids[2]).unwrap();
+    assert_eq!(symbol.underlying_symbol, "ES");
+    assert_eq!(interner.intern(Symbol::clone(&symbol)), ids[2]);
+}
+
+#[test]
+fn shares_underlyings() {
+    let interner = SymbolInterner::new();
+    let call = interner.intern_str("SPY 250620C600").unwrap();
+    let put = interner.intern_str("SPY 250620P600").unwrap();
+    let future = interner.intern_str("ESU20").unwrap();
+    assert_eq!(interner.underlying_count(), 2);
+
+    let underlying = interner.underlying_id(call).unwrap();
+    assert_eq!(interner.underlying_id(put), Some(underlying));
+    assert_ne!(interner.underlying_id(future), Some(underlying));
+    let (a, b) = (interner.underlying(underlying).unwrap(), interner.underlying(interner.underlying_id(put).unwrap()).unwrap());
+    assert_eq!(&*a, "SPY");
+    assert!(Arc::ptr_eq(&a, &b));
+}
+
+#[test]
+fn packs_options() {
+    let packed = pack("MSFT 110122C27.5").unwrap();
+    assert_eq!(packed.symbol_type(), SymbolType::StockOption);
+    assert_eq!(packed.root(), "MSFT");
+    assert_eq!(packed.strike_price(), Some(Decimal::new(275, 1)));
+    assert_eq!(packed.put_call(), Some(PutOrCall::Call));
+    assert_eq!(packed.date(), NaiveDate::from_ymd_opt(2011, 1, 22));
+    assert_eq!((packed.month(), packed.year()), (None, None));
+
+    assert_eq!(PackedSymbol::from_bits(packed.bits()), packed);
+    assert_eq!(pack("MSFT110122C00027500"), Some(packed));
+    assert_ne!(pack("MSFT 110122P27.5"), Some(packed));
+    assert_eq!(pack("MSFT 110122P27.5").unwrap().put_call(), Some(PutOrCall::Put));
+    // The largest strike that fits, with three decimals
+    assert_eq!(pack("SPY 250620C4294967.295").unwrap().strike_price(), Some(Decimal::new(4294967295, 3)));
+}
+
+#[test]
+fn packs_futures() {
+    let packed = pack("ESU20").unwrap();
+    assert_eq!(packed.symbol_type(), SymbolType::Future);
+    assert_eq!((packed.root().as_str(), packed.month(), packed.year().as_deref()), ("ES", Some("U"), Some("20")));
+    assert_eq!((packed.strike_price(), packed.put_call(), packed.continuous()), (None, None, false));
+    // The year keeps the digits it was written with
+    assert_eq!(pack("ESU0").unwrap().year().as_deref(), Some("0"));
+    assert_ne!(pack("ESU0"), Some(packed));
+
+    let option = pack("ESU20 C2700").unwrap();
+    assert_eq!(option.symbol_type(), SymbolType::FutureOption);
+    assert_eq!((option.month(), option.strike_price(), option.date()), (Some("U"), Some(Decimal::from(2700)), None));
+
+    let unknown = pack("AAPL").unwrap();
+    assert_eq!((unknown.symbol_type(), unknown.root().as_str()), (SymbolType::Unknown, "AAPL"));
+}
+
+#[test]
+fn declines_what_does_not_fit() {
+    // A modifier
+    assert_eq!(pack("BRK.B"), None);
+    // A strike with more than three decimals or above 4294967.295
+    assert_eq!(pack("SPY 250620C600.1234"), None);
+    assert_eq!(pack("SPY 250620C4294967.296"), None);
+    // A date before 2000
+    assert_eq!(pack("MSFT 991218C27.5"), None);
+}
@@ -0,0 +1,51 @@
+use symbols::normalize::{normalize, Normalization};
+use symbols::symbol_parser::{parse_symbol, parse_symbol_lenient, ParseResult};
+
+#[test]
+fn leaves_strict_symbols_alone() {
+    for raw_symbol in ["ESU20", "MSFT 110122C27.5", "ESU20 C2700"] {
+        let normalized = normalize(raw_symbol);
+        assert_eq!(normalized.symbol, raw_symbol);
+        assert!(normalized.is_unchanged(), "{raw_symbol}");
+    }
+}
+
+#[test]
+fn reports_each_normalization_once_in_order() {
+    let cases = [
+        ("esu20", "ESU20", vec![Normalization::Uppercased]),
+        ("MSFT\t110122C27.5", "MSFT 110122C27.5", vec![Normalization::Whitespace]),
+        ("ESU20  C2700", "ESU20 C2700", vec![Normalization::CollapsedSpaces]),
+        (" ESU20\n", "ESU20", vec![Normalization::Trimmed, Normalization::Whitespace]),
+        ("ＥＳＵ２０", "ESU20", vec![Normalization::FullWidth]),
+        // The ideographic space is a full-width space
+        ("ＥＳＵ２０\u{3000}Ｃ２７００", "ESU20 C2700", vec![Normalization::FullWidth]),
+        (
+            "  msft\t\t110122c27.5 ",
+            "MSFT 110122C27.5",
+            vec![Normalization::Trimmed, Normalization::Uppercased, Normalization::Whitespace, Normalization::CollapsedSpaces],
+        ),
+    ];
+    for (raw_symbol, symbol, applied) in cases {
+        let normalized = normalize(raw_symbol);
+        assert_eq!(normalized.symbol, symbol, "{raw_symbol:?}");
+        assert_eq!(normalized.applied, applied, "{raw_symbol:?}");
+    }
+}
+
+#[test]
+fn parses_leniently() {
+    // Strictly, lowercase input is at best an unknown symbol
+    assert!(!matches!(parse_symbol("msft 110122c27.5"), Ok(ParseResult::StockOptionsSym(_))));
+    let lenient = parse_symbol_lenient("msft 110122c27.5").unwrap();
+    assert!(matches!(lenient.result, ParseResult::StockOptionsSym(_)));
+    assert_eq!(lenient.normalizations, [Normalization::Uppercased]);
+
+    let lenient = parse_symbol_lenient("ＥＳＵ２０").unwrap();
+    assert!(matches!(lenient.result, ParseResult::FutureSym(_)));
+    assert_eq!(lenient.normalizations, [Normalization::FullWidth]);
+
+    assert!(parse_symbol_lenient("ESU20").unwrap().normalizations.is_empty());
+    // Normalizing does not make up for a malformed symbol
+    assert!(parse_symbol_lenient("!!!").is_err());
+}
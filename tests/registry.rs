@@ -0,0 +1,100 @@
+use std::io::Cursor;
+
+use rust_decimal::Decimal;
+
+use symbols::format::Symbology;
+use symbols::registry::{self, DeliverablesError, FutureOptionExpiration, ProductRegistry};
+use symbols::symbol::{Deliverable, Symbol};
+
+const DELIVERABLES: &str = "\
+# root,underlying,multiplier,shares,cash
+AAPL1, AAPL, 100, 150, 0
+
+XYZ2,XYZ,100,50,1250.50
+";
+
+#[test]
+fn loads_deliverables() {
+    let mut registry = ProductRegistry::new();
+    assert_eq!(registry.load_deliverables(Cursor::new(DELIVERABLES)).unwrap(), 2);
+    assert_eq!(
+        registry.deliverable("XYZ2"),
+        Some(Deliverable {
+            underlying: "XYZ".to_owned(),
+            multiplier: Decimal::from(100),
+            shares: Decimal::from(50),
+            cash: Decimal::new(125050, 2),
+        })
+    );
+    assert_eq!(registry.underlying("AAPL1"), "AAPL");
+    assert!(registry.is_adjusted("AAPL1"));
+    // Unknown adjusted roots still resolve their underlying, but not their terms
+    assert_eq!(registry.underlying("MSFT1"), "MSFT");
+    assert_eq!(registry.deliverable("MSFT1"), None);
+    assert_eq!(registry.deliverable("MSFT"), Some(Deliverable::standard("MSFT")));
+}
+
+#[test]
+fn rejects_invalid_deliverables() {
+    let error = |csv: &str| ProductRegistry::new().load_deliverables(Cursor::new(csv)).unwrap_err().to_string();
+    assert_eq!(
+        error("# header\nAAPL1,AAPL,100,150"),
+        "invalid deliverable on line 2: expected root,underlying,multiplier,shares,cash"
+    );
+    assert_eq!(error(",AAPL,100,150,0"), "invalid deliverable on line 1: root and underlying are required");
+    assert_eq!(error("AAPL1,AAPL,100,lots,0"), "invalid deliverable on line 1: lots is not a number");
+
+    let err = ProductRegistry::new().load_deliverables_file("does/not/exist.csv").unwrap_err();
+    assert!(matches!(err, DeliverablesError::Io(_)), "{err}");
+}
+
+#[test]
+fn keeps_minis_apart_from_adjustments() {
+    let registry = ProductRegistry::with_defaults();
+    assert!(!registry.is_adjusted("AAPL7"));
+    assert_eq!(registry.underlying("AAPL7"), "AAPL");
+    assert_eq!(registry.deliverable("AAPL7").unwrap().shares, Decimal::TEN);
+    // Not every root ending in 7 is a mini
+    assert!(registry.is_adjusted("XYZ7"));
+    assert_eq!(registry.deliverable("XYZ7"), None);
+
+    let mini = Symbol::parse("AAPL7 250620C200").unwrap();
+    assert_eq!(mini.underlying_symbol, "AAPL");
+    assert!(!mini.option_contract.as_ref().unwrap().adjusted);
+}
+
+// The default registry is global, so everything that depends on loaded deliverables
+// runs in this one test
+#[test]
+fn options_on_adjusted_roots_resolve_their_underlying() {
+    let mut loaded = ProductRegistry::with_defaults();
+    loaded.load_deliverables(Cursor::new(DELIVERABLES)).unwrap();
+    registry::set_default_registry(loaded);
+
+    let adjusted = Symbol::parse("AAPL1 250620C200").unwrap();
+    let option = adjusted.option_contract.as_ref().unwrap();
+    assert_eq!(adjusted.underlying_symbol, "AAPL");
+    assert_eq!(adjusted.root(), "AAPL1");
+    assert!(option.adjusted);
+    assert_eq!(option.deliverable.as_ref().unwrap().shares, Decimal::from(150));
+    assert_eq!(Symbology::Native.format(&adjusted).unwrap(), "AAPL1 250620C200");
+    assert_eq!(Symbology::Occ.format(&adjusted).unwrap(), "AAPL1 250620C00200000");
+    assert_ne!(adjusted, Symbol::parse("AAPL 250620C200").unwrap());
+
+    let weekly = Symbol::parse("SPXW 250620C6000").unwrap();
+    assert_eq!((weekly.underlying_symbol.as_str(), weekly.root()), ("SPX", "SPXW"));
+
+    registry::set_default_registry(ProductRegistry::with_defaults());
+}
+
+#[test]
+fn future_option_expiration_rules() {
+    let date = |year, month, day| chrono::NaiveDate::from_ymd_opt(year, month, day);
+    let registry = registry::default_registry();
+    let expiration = |root| registry.future_product(root).unwrap().option_expiration.unwrap();
+    assert_eq!(expiration("ES").expiration(2025, 9), date(2025, 9, 19));
+    assert_eq!(expiration("ZN").expiration(2025, 9), date(2025, 8, 22));
+    assert_eq!(expiration("6E").expiration(2025, 9), date(2025, 9, 5));
+    assert_eq!(registry.future_product("CL").unwrap().option_expiration, None);
+    assert_eq!(FutureOptionExpiration::ThirdFriday.expiration(2025, 13), None);
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use symbols::schema::{self, SCHEMA_VERSION};
+use symbols::symbol::Symbol;
+
+const SYMBOLS: [&str; 7] = ["AAPL", "BRK.B", "ESU20", "@ES", "ESU20 C2700", "MSFT 110122C27.5", "SPY251219C00650000"];
+
+#[test]
+fn documents_round_trip() {
+    for raw in SYMBOLS {
+        let symbol = Symbol::parse(raw).unwrap();
+        let read = schema::from_json(&schema::to_json(&symbol)).unwrap();
+        assert_eq!(read, symbol, "{raw}");
+        assert_eq!(read.original_symbol, symbol.original_symbol);
+        assert_eq!(read.symbol_modifier, symbol.symbol_modifier);
+        assert_eq!(serde_json::to_value(&read).unwrap(), serde_json::to_value(&symbol).unwrap(), "{raw}");
+    }
+}
+
+#[test]
+fn version_1_uses_snake_case_tags() {
+    let document = schema::to_value(&Symbol::parse("ESU20 C2700").unwrap());
+    assert_eq!(document["schema_version"], json!(SCHEMA_VERSION));
+    assert_eq!(document["symbol_type"], json!("future_option"));
+    assert_eq!(document["option_contract"]["put_call"], json!("call"));
+    assert_eq!(document["option_contract"]["strike_price"], json!("2700"));
+    assert_eq!(document["future_contract"]["month"], json!("U"));
+    assert_eq!(document["option_contract"]["settlement_time"], json!("am"));
+}
+
+// Stored before documents were versioned
+#[test]
+fn reads_unversioned_documents() {
+    let stored = r#"{"symbol_type":"StockOption","original_symbol":"MSFT 110122C27.5","underlying_symbol":"MSFT",
+        "symbol_modifier":null,"option_contract":{"strike_price":"27.5","put_call":"Call","date":"2011-01-22",
+        "series":null,"adjusted":false,"deliverable":null,"exercise_style":null,"settlement_type":null,
+        "settlement_time":null,"expiration_time":null,"time_zone":null},"future_contract":null}"#;
+    assert_eq!(schema::from_json(stored).unwrap(), Symbol::parse("MSFT 110122C27.5").unwrap());
+}
+
+#[test]
+fn rejects_unknown_versions() {
+    let err = schema::from_value(json!({ "schema_version": SCHEMA_VERSION + 1 })).unwrap_err();
+    assert_eq!(err.version, Some(SCHEMA_VERSION as u64 + 1));
+
+    let err = schema::from_value(json!({ "schema_version": 1, "symbol_type": "FutureOption" })).unwrap_err();
+    assert_eq!(err.version, Some(1));
+}
+
+#[test]
+fn ignores_fields_added_later_in_the_version() {
+    let mut document = schema::to_value(&Symbol::parse("ESU20").unwrap());
+    document["exchange"] = json!("XCME");
+    assert_eq!(schema::from_value(document).unwrap(), Symbol::parse("ESU20").unwrap());
+}
+
+#[derive(Serialize, Deserialize)]
+struct Event {
+    sequence: u64,
+    #[serde(with = "symbols::schema")]
+    symbol: Symbol,
+}
+
+#[test]
+fn embeds_in_other_documents() {
+    let event = Event { sequence: 7, symbol: Symbol::parse("ESU20").unwrap() };
+    let json = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["symbol"]["schema_version"], json!(SCHEMA_VERSION));
+
+    let read: Event = serde_json::from_value(json).unwrap();
+    assert_eq!(read.sequence, 7);
+    assert_eq!(read.symbol, event.symbol);
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn schema_file_is_current() {
+    let generated = serde_json::to_string_pretty(&schema::json_schema()).unwrap() + "\n";
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/schema/symbol.v1.schema.json");
+    if std::env::var_os("UPDATE_SCHEMA").is_some() {
+        std::fs::write(path, &generated).unwrap();
+    }
+    let committed = std::fs::read_to_string(path).unwrap_or_default();
+    assert!(committed == generated, "schema/symbol.v1.schema.json is stale, rerun with UPDATE_SCHEMA=1");
+}
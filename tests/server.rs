@@ -0,0 +1,99 @@
+// Drives the HTTP service in-process, without binding a socket
+
+use symbols::server::Service;
+
+fn json(body: &str) -> serde_json::Value {
+    serde_json::from_str(body).unwrap()
+}
+
+#[test]
+fn parses_a_symbol() {
+    let service = Service::new();
+    let response = service.handle("POST", "/parse", br#"{"symbol": "SPY251219C00650000"}"#);
+    assert_eq!(response.status, 200);
+    let symbol = json(&response.body);
+    assert_eq!(symbol["symbol_type"], "StockOption");
+    assert_eq!(symbol["underlying_symbol"], "SPY");
+    assert_eq!(symbol["option_contract"]["date"], "2025-12-19");
+}
+
+#[test]
+fn reports_parse_failures_with_suggestions() {
+    let service = Service::new();
+    let response = service.handle("POST", "/parse", br#"{"symbol": "ES-U0"}"#);
+    assert_eq!(response.status, 422);
+    let body = json(&response.body);
+    assert_eq!(body["error"]["input"], "ES-U0");
+    assert!(body["suggestions"].is_array());
+
+    let response = service.handle("POST", "/parse", b"ESU20");
+    assert_eq!(response.status, 400);
+}
+
+#[test]
+fn parses_a_batch_in_order() {
+    let service = Service::new();
+    let response = service.handle("POST", "/parse/batch", br#"{"symbols": ["ESU20", "ES-U0", "@ES"]}"#);
+    assert_eq!(response.status, 200);
+    let results = json(&response.body)["results"].as_array().unwrap().to_owned();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["symbol"]["original_symbol"], "ESU20");
+    assert_eq!(results[1]["error"]["position"], 2);
+    assert_eq!(results[2]["symbol"]["future_contract"]["continuous"], true);
+}
+
+#[test]
+fn translates_between_symbologies() {
+    let service = Service::new();
+    let response = service.handle("GET", "/translate?symbol=SPY251219C00650000&from=occ-compact&to=occ", b"");
+    assert_eq!(response.status, 200);
+    assert_eq!(json(&response.body)["output"], "SPY   251219C00650000");
+
+    let response = service.handle("GET", "/translate?symbol=ESU20+C2700&to=cme", b"");
+    assert_eq!(json(&response.body)["output"], "ESU0 C2700");
+
+    let response = service.handle("GET", "/translate?symbol=ESU20&to=occ", b"");
+    assert_eq!(response.status, 422);
+    let response = service.handle("GET", "/translate?symbol=ESU20", b"");
+    assert_eq!(response.status, 400);
+}
+
+#[test]
+fn lists_option_chains() {
+    let service = Service::new();
+    let response = service.handle("GET", "/chain?root=SPY&date=2025-06-16&days=4&low=600&high=601&increment=1&symbology=occ", b"");
+    assert_eq!(response.status, 200);
+    assert_eq!(json(&response.body)["symbols"], serde_json::json!([
+        "SPY   250620P00600000",
+        "SPY   250620C00600000",
+        "SPY   250620P00601000",
+        "SPY   250620C00601000"
+    ]));
+
+    assert_eq!(service.handle("GET", "/chain", b"").status, 400);
+    assert_eq!(service.handle("GET", "/chain?root=SPY&date=June&low=600&high=601", b"").status, 400);
+    let response = service.handle("GET", "/chain?root=SPY&date=2025-06-16&low=0&high=100000&increment=0.5", b"");
+    assert_eq!(response.status, 422);
+    assert!(json(&response.body)["error"]["message"].as_str().unwrap().contains("more than the limit"));
+    let response = service.handle("GET", "/chain?root=SPY&date=2025-06-16&low=0.000001&high=1000000000&increment=0.000001", b"");
+    assert_eq!(response.status, 422);
+    let response = service.handle("GET", "/chain?root=SPY&date=2025-06-16&days=99999999999&low=600&high=601", b"");
+    assert_eq!(response.status, 422);
+    assert!(json(&response.body)["error"]["message"].as_str().unwrap().contains("out of range"));
+}
+
+#[test]
+fn routes_health_metrics_and_unknown_paths() {
+    let service = Service::new();
+    assert_eq!(json(&service.handle("GET", "/health", b"").body)["status"], "ok");
+    assert_eq!(service.handle("GET", "/nope", b"").status, 404);
+    assert_eq!(service.handle("GET", "/parse", b"").status, 405);
+
+    service.handle("POST", "/parse", br#"{"symbol": "ESU20"}"#);
+    service.handle("POST", "/parse", br#"{"symbol": "ESU20"}"#);
+    let metrics = service.handle("GET", "/metrics", b"");
+    assert_eq!(metrics.content_type, "text/plain; version=0.0.4");
+    assert!(metrics.body.contains("symbols_requests_total{endpoint=\"/parse\"} 3"));
+    assert!(metrics.body.contains("symbols_cache_hits_total 1"));
+    assert!(metrics.body.contains("symbols_not_found_total 1"));
+}
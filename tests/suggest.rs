@@ -0,0 +1,69 @@
+use symbols::registry::ProductRegistry;
+use symbols::suggest::{edit_distance, suggest, Suggestion, SuggestionReason};
+
+fn suggestions(raw_symbol: &str, registry: Option<&ProductRegistry>) -> Vec<(String, SuggestionReason)> {
+    suggest(raw_symbol, registry)
+        .into_iter()
+        .map(|Suggestion { symbol, reason, .. }| (symbol, reason))
+        .collect()
+}
+
+fn first(raw_symbol: &str) -> Option<(String, SuggestionReason)> {
+    suggestions(raw_symbol, Some(&ProductRegistry::with_defaults())).into_iter().next()
+}
+
+#[test]
+fn repairs_confusable_characters() {
+    assert_eq!(first("ESU2O"), Some(("ESU20".to_owned(), SuggestionReason::Confusable)));
+    // Without a registry too
+    assert_eq!(suggestions("ESU2O", None), [("ESU20".to_owned(), SuggestionReason::Confusable)]);
+}
+
+#[test]
+fn repairs_missized_dates() {
+    assert_eq!(first("SPY 25062C600"), Some(("SPY 250620C600".to_owned(), SuggestionReason::MissingDigit)));
+    let suggested = suggest("SPY 25062C600", None);
+    assert!(suggested.iter().all(|suggestion| suggestion.reason == SuggestionReason::MissingDigit && suggestion.distance == 1));
+    assert_eq!(first("SPY 2506200C600"), Some(("SPY 250620C600".to_owned(), SuggestionReason::ExtraDigit)));
+}
+
+#[test]
+fn checks_futures_against_the_registry() {
+    let registry = ProductRegistry::with_defaults();
+    // ES lists only the quarterly months
+    assert_eq!(suggestions("ESX20", Some(&registry)), [
+        ("ESZ20".to_owned(), SuggestionReason::UnlistedMonth),
+        ("ESU20".to_owned(), SuggestionReason::UnlistedMonth),
+    ]);
+    assert_eq!(first("EXZ20"), Some(("ESZ20".to_owned(), SuggestionReason::UnknownRoot)));
+    assert!(suggest("ESX20", None).is_empty());
+}
+
+#[test]
+fn suggests_normalized_input() {
+    for (raw_symbol, symbol) in [
+        ("msft 110122c27.5", "MSFT 110122C27.5"),
+        ("MSFT\t110122C27.5", "MSFT 110122C27.5"),
+        (" ESU20 ", "ESU20"),
+        ("ＥＳＵ２０", "ESU20"),
+    ] {
+        assert_eq!(first(raw_symbol), Some((symbol.to_owned(), SuggestionReason::Normalized)), "{raw_symbol:?}");
+    }
+}
+
+#[test]
+fn suggests_nothing_without_a_repair() {
+    let registry = ProductRegistry::with_defaults();
+    for raw_symbol in ["ESU20", "MSFT 110122C27.5", "AAPL", "!!!", "MSFT 111322C27.5"] {
+        assert_eq!(suggest(raw_symbol, Some(&registry)), [], "{raw_symbol}");
+    }
+}
+
+#[test]
+fn measures_edit_distance() {
+    assert_eq!(edit_distance("ESU20", "ESU20"), 0);
+    assert_eq!(edit_distance("ESU2O", "ESU20"), 1);
+    assert_eq!(edit_distance("SPY 25062C600", "SPY 250620C600"), 1);
+    assert_eq!(edit_distance("ＥＳＵ２０", "ESU20"), 5);
+    assert_eq!(edit_distance("", "ESU20"), 5);
+}
@@ -0,0 +1,143 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use symbols::symbol::{
+    future_expiration, parse_strike_price, ExerciseStyle, SettlementTime, SettlementType, Symbol, SymbolRef,
+};
+use symbols::symbol_parser::parse_symbol;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+// Every test in this file resolves future years against the same day
+fn parse(raw_symbol: &str) -> Symbol {
+    Symbol::parse_at(raw_symbol, date(2020, 6, 1)).unwrap()
+}
+
+fn hash(symbol: &Symbol) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    symbol.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn carries_symbol_modifiers() {
+    let modifier = |raw_symbol: &'static str| symbols::symbol::SymbolRef::parse(raw_symbol).unwrap().symbol_modifier;
+    assert_eq!(modifier("BRK.B"), Some(".B"));
+    assert_eq!(modifier("ESU20.ZERO"), Some(".ZERO"));
+    assert_eq!(modifier("ESU20.X C2700"), Some(".X"));
+    assert_eq!(modifier("ESU20.A.B C2700"), Some(".B"));
+    assert_eq!(modifier("ESU20 C2700"), None);
+    assert_eq!(modifier("MSFT 110122C27.5"), None);
+
+    let symbol = symbols::symbol::SymbolRef::parse("RDS:A").unwrap().to_owned();
+    assert_eq!(symbol.symbol_modifier.as_deref(), Some(":A"));
+}
+
+#[test]
+fn future_options_take_their_terms_from_the_registry() {
+    let option = |raw_symbol| parse(raw_symbol).option_contract.unwrap();
+    let es = option("ESU20 C2700");
+    assert_eq!(
+        (es.exercise_style, es.settlement_type, es.settlement_time),
+        (Some(ExerciseStyle::American), Some(SettlementType::Cash), Some(SettlementTime::Am))
+    );
+    let zn = option("ZNU20 P139");
+    assert_eq!(
+        (zn.exercise_style, zn.settlement_type, zn.settlement_time),
+        (Some(ExerciseStyle::American), Some(SettlementType::Physical), Some(SettlementTime::Pm))
+    );
+    assert_eq!(option("6EU20 C12000").settlement_time, Some(SettlementTime::Am));
+    assert_eq!((zn.date, zn.expiration_time, zn.time_zone), (None, None, None));
+
+    // Unlisted futures have unknown terms
+    let unlisted = option("XXU20 C100");
+    assert_eq!((unlisted.exercise_style, unlisted.settlement_type, unlisted.settlement_time), (None, None, None));
+}
+
+#[test]
+fn resolves_future_years_from_the_reference_date() {
+    let expiration = |year, reference| future_expiration(Some(year), Some("U"), reference);
+    assert_eq!(expiration("0", date(2020, 6, 1)), Some(date(2020, 9, 1)));
+    assert_eq!(expiration("0", date(2024, 12, 31)), Some(date(2020, 9, 1)));
+    assert_eq!(expiration("0", date(2025, 1, 1)), Some(date(2030, 9, 1)));
+    assert_eq!(expiration("9", date(2020, 6, 1)), Some(date(2019, 9, 1)));
+    assert_eq!(expiration("20", date(2026, 10, 19)), Some(date(2020, 9, 1)));
+    assert_eq!(expiration("99", date(2026, 10, 19)), Some(date(1999, 9, 1)));
+    assert_eq!(expiration("05", date(2099, 1, 1)), Some(date(2105, 9, 1)));
+    assert_eq!(expiration("202", date(2020, 6, 1)), None);
+    assert_eq!(future_expiration(Some("20"), Some("A"), date(2020, 6, 1)), None);
+    assert_eq!(future_expiration(None, Some("U"), date(2020, 6, 1)), None);
+
+    assert_eq!(parse("ESU0").future_contract.unwrap().expiration, Some(date(2020, 9, 1)));
+    let option = SymbolRef::parse_at("ESU0 C2700", date(2025, 1, 1)).unwrap();
+    assert_eq!(option.future_contract.unwrap().expiration, Some(date(2030, 9, 1)));
+}
+
+#[test]
+fn equal_symbols_hash_alike() {
+    let pairs = [
+        ("ESU20", "ESU0"),
+        ("SPY 251219C650", "SPY251219C00650000"),
+        ("ESU20 C2700", "ESU0 C2700"),
+    ];
+    for (a, b) in pairs {
+        let (a, b) = (parse(a), parse(b));
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b), "{a:?}");
+    }
+    assert_ne!(parse("ESU20"), parse("ESZ20"));
+    assert_ne!(parse("SPY 251219C650"), parse("SPY 251219P650"));
+
+    let symbols: HashSet<Symbol> = ["ESU20", "ESU0", "ESZ20"].into_iter().map(parse).collect();
+    assert_eq!(symbols.len(), 2);
+}
+
+#[test]
+fn sorts_into_chain_order() {
+    let mut symbols: Vec<Symbol> = [
+        "SPY 251219C650",
+        "SPY 251121C700",
+        "SPY 251219P600",
+        "SPY 251219P650",
+        "QQQ 251219C500",
+        "ESH21",
+        "ESZ20",
+        "ESU0",
+    ]
+    .into_iter()
+    .map(parse)
+    .collect();
+    symbols.sort();
+    let sorted: Vec<&str> = symbols.iter().map(|symbol| symbol.original_symbol.as_str()).collect();
+    // By underlying, then expiration, strike, and puts before calls
+    assert_eq!(sorted, [
+        "ESU0",
+        "ESZ20",
+        "ESH21",
+        "QQQ 251219C500",
+        "SPY 251121C700",
+        "SPY 251219P600",
+        "SPY 251219P650",
+        "SPY 251219C650",
+    ]);
+}
+
+#[test]
+fn rejects_parse_results_that_are_not_symbols() {
+    // The grammar accepts any six digits as a date
+    let err = Symbol::try_from(parse_symbol("MSFT 111322C27.5").unwrap().into_owned()).unwrap_err();
+    assert_eq!((err.input.as_str(), err.message.as_str()), ("MSFT 111322C27.5", "undefined date format"));
+    assert!(Symbol::try_from(parse_symbol("MSFT 110122C27.5").unwrap().into_owned()).is_ok());
+
+    assert_eq!(parse_strike_price("00027500"), Some(Decimal::new(275, 1)));
+    assert_eq!(parse_strike_price("27.5"), Some(Decimal::new(275, 1)));
+    assert_eq!(parse_strike_price("2700"), Some(Decimal::from(2700)));
+    assert_eq!(parse_strike_price("27.5.0"), None);
+    assert_eq!(parse_strike_price(""), None);
+}
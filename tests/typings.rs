@@ -0,0 +1,38 @@
+use tsify::Tsify;
+
+use symbols::cache::CacheStats;
+use symbols::js::{ErrorCode, ParseOutcome, SymbolError};
+use symbols::symbol::{
+    Deliverable, ExerciseStyle, ExpirationSeries, FutureContract, OptionContract, PutOrCall, SettlementTime,
+    SettlementType, Symbol, SymbolType,
+};
+use symbols::suggest::{Suggestion, SuggestionReason};
+
+// types/symbols.d.ts is shared with the Node addon, which has no Tsify of its own, so it
+// must carry every declaration the WASM package gets from the derives
+const DECLS: [&str; 16] = [
+    SymbolType::DECL,
+    PutOrCall::DECL,
+    ExpirationSeries::DECL,
+    ExerciseStyle::DECL,
+    SettlementType::DECL,
+    SettlementTime::DECL,
+    Deliverable::DECL,
+    OptionContract::DECL,
+    FutureContract::DECL,
+    Symbol::DECL,
+    SuggestionReason::DECL,
+    Suggestion::DECL,
+    ErrorCode::DECL,
+    SymbolError::DECL,
+    ParseOutcome::DECL,
+    CacheStats::DECL,
+];
+
+#[test]
+fn shared_typings_match_the_rust_types() {
+    let typings = include_str!("../types/symbols.d.ts");
+    for decl in DECLS {
+        assert!(typings.contains(decl), "types/symbols.d.ts is missing or has a stale copy of:\n{decl}");
+    }
+}
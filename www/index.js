@@ -1,5 +1,4 @@
 import * as symbols from "symbols";
-window.parse_symbol_js = symbols.parse_symbol_js;
 function parseFutureContract(future_contract) {
   if (!future_contract) {
     return null;
@@ -21,38 +20,32 @@ function parseOptionContract(option_contract) {
     strike_price: option_contract.strike_price,
     put_call: option_contract.put_call,
     date: option_contract.date,
+    exercise_style: option_contract.exercise_style,
+    settlement_type: option_contract.settlement_type,
+    settlement_time: option_contract.settlement_time,
+    expiration_time: option_contract.expiration_time,
+    time_zone: option_contract.time_zone,
   };
 }
 
 const { createApp, ref } = Vue;
 
-const SYMBOL_TYPE_MAPPING = {
-    0: 'Stocks',
-    1: 'Futures',
-    2: 'Future Options',
-    3: 'Stock Options',
-    4: 'Unknown Type'
-}
-
-const PUT_CALL_MAPPING = {
-    0: 'PUT',
-    1: 'CALL'
-}
-
 createApp({
   setup() {
     const symbolInfo = ref(null);
     const symbol = ref("");
     const error = ref(null);
+    const suggestions = ref([]);
     const onSubmit = (e) => {
       symbolInfo.value = null;
       error.value = null;
+      suggestions.value = [];
       // if (symbolInfo.value) {
       //     symbolInfo.free()
       // }
       try {
         performance.mark("startParse");
-        symbolInfo.value = symbols.parse_symbol_js(symbol.value);
+        symbolInfo.value = symbols.parse(symbol.value);
         performance.mark("endParse");
 
         console.info(
@@ -60,8 +53,14 @@ createApp({
             performance.measure("parsing", "startParse", "endParse").duration
           }ms`
         );
+        // Unknown symbols parse fine but are often typos, offer fixes for them too
+        if (symbolInfo.value.symbol_type === "Unknown") {
+          suggestions.value = symbols.suggest_symbol_js(symbol.value);
+        }
       } catch (e) {
-        error.value = e.toString();
+        // SymbolError from the parser, anything else is a bug
+        error.value = e.code ? `${e.message} (${e.code})` : e.toString();
+        suggestions.value = e.suggestions || [];
       }
     };
 
@@ -69,13 +68,18 @@ createApp({
       symbol.value = e.target.innerText;
       onSubmit()
     }
+
+    const useSuggestion = (suggestion) => {
+      symbol.value = suggestion.symbol;
+      onSubmit()
+    }
     return {
         onSubmit,
         useExample,
+        useSuggestion,
+        suggestions,
         symbolInfo,
         symbol,
-        SYMBOL_TYPE_MAPPING,
-        PUT_CALL_MAPPING,
         error,
     }
   }